@@ -5,7 +5,7 @@ use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4; // The SDK clien
 // use bitcoin_de::bitcoin_de_trading_api_sdk_v4::errors::Error; // SDK Error type
 // TODO implement IntoResponse trait for custom error responses and use it here
 // Import enums and response structs used by handlers
-use bitcoin_de::enums::TradingPair; // Import the TradingPair enum (needed for from_str)
+use bitcoin_de::enums::TradingPair; // Import the TradingPair enum (parsed from the path)
 use bitcoin_de::bitcoin_de_trading_api_sdk_v4::responses::account::ShowAccountInfoResponse; // Response struct for account info
 use bitcoin_de::bitcoin_de_trading_api_sdk_v4::responses::misc::ShowRatesResponse; // Response struct for rates
 // Import the error detail struct for potential error responses
@@ -84,7 +84,7 @@ pub async fn handle_show_rates(
     State(sdk): State<Arc<TradingApiSdkV4>>,
     Path(trading_pair_str): Path<String>,
 ) -> Result<Json<ShowRatesResponse>, StatusCode> {
-    let trading_pair = match TradingPair::from_str(&trading_pair_str) {
+    let trading_pair = match trading_pair_str.parse::<TradingPair>() {
         Ok(pair) => pair,
         Err(_) => {
             eprintln!("Invalid trading pair received in handler: {}", trading_pair_str);
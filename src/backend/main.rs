@@ -4,7 +4,7 @@
 mod handler;
 use handler::{handle_show_account_info, handle_show_rates};
 use {
-    bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4,
+    bitcoin_de::bitcoin_de_trading_api_sdk_v4::{config::ApiCredentials, TradingApiSdkV4},
     // bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::{
     //     METHOD_SHOW_ACCOUNT_INFO, METHOD_SHOW_RATES, SHOW_RATES_PARAMETER_TRADING_PAIR
     // },
@@ -41,9 +41,12 @@ async fn main() { // Async main function required for Axum and Tokio
 
     // Create an instance of the SDK client and wrap it in State to share it across handlers
     // In a real application, get credentials securely (e.g., environment variables)
-    let api_key = std::env::var("API_KEY").expect("API_KEY not set for backend"); // Get credentials securely
-    let api_secret = std::env::var("API_SECRET").expect("API_SECRET not set for backend"); // Get credentials securely
-    let sdk_client = TradingApiSdkV4::new(api_key, api_secret);
+    // API_KEY/API_SECRET, or API_KEY_FILE/API_SECRET_FILE pointing to files only readable by the owner
+    let credentials = ApiCredentials::from_env().expect("API credentials not set for backend");
+    let sdk_client = TradingApiSdkV4::builder()
+        .credentials(credentials)
+        .build()
+        .expect("valid client configuration");
     let sdk_client = Arc::new(sdk_client);
     // Wrap in State
 
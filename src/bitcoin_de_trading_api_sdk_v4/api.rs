@@ -0,0 +1,766 @@
+// bitcoin_de_trading_api_sdk_v4/api.rs
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use futures_util::stream::TryStreamExt;
+use rust_decimal::Decimal;
+
+use crate::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::pagination;
+use crate::bitcoin_de_trading_api_sdk_v4::requests::{CreateOrderRequest, ExecuteTradeRequest, MyOrdersFilter, OrderbookQuery};
+use crate::bitcoin_de_trading_api_sdk_v4::responses::*;
+use crate::bitcoin_de_trading_api_sdk_v4::trading_api_sdk_v4::TradingApiSdkV4;
+
+/// The API methods of the Bitcoin.de Trading API v4 as a trait.
+///
+/// Implemented by [`TradingApiSdkV4`]. Code that depends on `BitcoinDeApi` instead of the
+/// concrete client can be run against a fake in unit tests, a recording proxy or a
+/// paper-trading engine. The trait is object safe, so implementations can also be swapped
+/// at runtime behind an `Arc<dyn BitcoinDeApi>`.
+///
+/// Only the single requests have to be implemented. [`execute_trade_checked`](Self::execute_trade_checked)
+/// and the `*_all` methods, which collect all pages of a list, are provided on top of them.
+/// The `*_stream` methods of `TradingApiSdkV4` are not part of the trait.
+///
+/// # Example
+///
+/// ```no_run
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{BitcoinDeApi, Error, TradingApiSdkV4};
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+/// use rust_decimal::Decimal;
+///
+/// // Strategy code only depends on the trait
+/// async fn weighted_rate(api: &dyn BitcoinDeApi) -> Result<Decimal, Error> {
+///     Ok(api.show_rates(TradingPair::BTCEUR).await?.rates.rate_weighted)
+/// }
+///
+/// # async fn run() -> Result<(), Error> {
+/// let client = TradingApiSdkV4::new("your_api_key".to_string(), "your_api_secret".to_string());
+/// println!("{}", weighted_rate(&client).await?);
+/// # Ok(())
+/// # }
+/// ```
+// Futures of the wasm client are not `Send`
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait BitcoinDeApi: Send + Sync {
+    /// Retrieves account information for the authenticated user.
+    /// Corresponds to the `showAccountInfo` API method.
+    async fn show_account_info(&self) -> Result<ShowAccountInfoResponse, Error>;
+
+    /// Retrieves current trading rates for a specific trading pair.
+    /// Corresponds to the `showRates` API method.
+    async fn show_rates(&self, trading_pair: TradingPair) -> Result<ShowRatesResponse, Error>;
+
+    /// Retrieves the public order book for a specific trading pair.
+    /// Corresponds to the `showOrderbook` API method.
+    async fn show_orderbook(
+        &self,
+        trading_pair: TradingPair,
+        query: &OrderbookQuery,
+    ) -> Result<ShowOrderbookResponse, Error>;
+
+    /// Retrieves details for a specific public order.
+    /// Corresponds to the `showOrderDetails` API method.
+    async fn show_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowOrderDetailsResponse, Error>;
+
+    /// Creates a new order.
+    /// Corresponds to the `createOrder` API method.
+    async fn create_order(
+        &self,
+        trading_pair: String,
+        request: &CreateOrderRequest,
+    ) -> Result<CreateOrderResponse, Error>;
+
+    /// Deletes an existing order.
+    /// Corresponds to the `deleteOrder` API method.
+    async fn delete_order(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<DeleteOrderResponse, Error>;
+
+    /// Retrieves the user's orders.
+    /// Corresponds to the `showMyOrders` API method.
+    /// Can filter by trading pair and other criteria.
+    async fn show_my_orders(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<ShowMyOrdersResponse, Error>;
+
+    /// Retrieves details for one of your orders.
+    /// Corresponds to the `showMyOrderDetails` API method.
+    async fn show_my_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowMyOrderDetailsResponse, Error>;
+
+    /// Retrieves the user's trades.
+    /// Corresponds to the `showMyTrades` API method.
+    /// Can filter by trading pair and other criteria.
+    async fn show_my_trades(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowMyTradesResponse, Error>;
+
+    /// Retrieves details for one of your trades.
+    /// Corresponds to the `showMyTradeDetails` API method.
+    async fn show_my_trade_details(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+    ) -> Result<ShowMyTradeDetailsResponse, Error>;
+
+    /// Executes a trade against a specific order.
+    /// Corresponds to the `executeTrade` API method.
+    async fn execute_trade(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error>;
+
+    /// Marks a trade as paid.
+    /// Corresponds to the `markTradeAsPaid` API method.
+    async fn mark_trade_as_paid(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        volume_currency_to_pay_after_fee: Decimal,
+    ) -> Result<MarkTradeAsPaidResponse, Error>;
+
+    /// Marks a trade as payment received.
+    /// Corresponds to the `markTradeAsPaymentReceived` API method.
+    async fn mark_trade_as_payment_received(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        volume_currency_to_pay_after_fee: Decimal,
+        rating: String,
+        is_paid_from_correct_bank_account: bool,
+    ) -> Result<MarkTradeAsPaymentReceivedResponse, Error>;
+
+    /// Adds a rating to a trade partner.
+    /// Corresponds to the `addTradeRating` API method.
+    async fn add_trade_rating(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        rating: String,
+    ) -> Result<AddTradeRatingResponse, Error>;
+
+    /// Retrieves your account ledger entries for a specific currency.
+    /// Corresponds to the `showAccountLedger` API method.
+    /// Can filter by type, date range, or page.
+    async fn show_account_ledger(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowAccountLedgerResponse, Error>;
+
+    /// Retrieves the permissions associated with the API key.
+    /// Corresponds to the `showPermissions` API method.
+    async fn show_permissions(&self) -> Result<ShowPermissionsResponse, Error>;
+
+    /// Creates a new cryptocurrency withdrawal.
+    /// Corresponds to the `createWithdrawal` API method.
+    async fn create_withdrawal(
+        &self,
+        currency: String,
+        amount: Decimal,
+        address: String,
+        network_fee: Decimal,
+    ) -> Result<CreateWithdrawalResponse, Error>;
+
+    /// Deletes an existing withdrawal request.
+    /// Corresponds to the `deleteWithdrawal` API method.
+    async fn delete_withdrawal(
+        &self,
+        currency: String,
+        withdrawal_id: String,
+    ) -> Result<DeleteWithdrawalResponse, Error>;
+
+    /// Retrieves details for a specific withdrawal.
+    /// Corresponds to the `showWithdrawal` API method.
+    async fn show_withdrawal(
+        &self,
+        currency: String,
+        withdrawal_id: String,
+    ) -> Result<ShowWithdrawalResponse, Error>;
+
+    /// Retrieves your cryptocurrency withdrawals for a specific currency.
+    /// Corresponds to the `showWithdrawals` API method.
+    /// Can filter by address or page.
+    async fn show_withdrawals(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowWithdrawalsResponse, Error>;
+
+    /// Retrieves the minimum network fee for a withdrawal in a specific currency.
+    /// Corresponds to the `showWithdrawalMinNetworkFee` API method.
+    async fn show_withdrawal_min_network_fee(
+        &self,
+        currency: String,
+    ) -> Result<ShowWithdrawalMinNetworkFeeResponse, Error>;
+
+    /// Requests a new deposit address for a specific currency.
+    /// Corresponds to the `requestDepositAddress` API method.
+    async fn request_deposit_address(
+        &self,
+        currency: String,
+        comment: Option<String>,
+    ) -> Result<RequestDepositAddressResponse, Error>;
+
+    /// Retrieves details for a specific deposit.
+    /// Corresponds to the `showDeposit` API method.
+    async fn show_deposit(
+        &self,
+        currency: String,
+        deposit_id: String,
+    ) -> Result<ShowDepositResponse, Error>;
+
+    /// Retrieves your cryptocurrency deposits for a specific currency.
+    /// Corresponds to the `showDeposits` API method.
+    /// Can filter by address or page.
+    async fn show_deposits(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowDepositsResponse, Error>;
+
+    /// Creates a new outgoing address in the address pool.
+    /// Corresponds to the `createOutgoingAddress` API method.
+    async fn create_outgoing_address(
+        &self,
+        currency: String,
+        params: HashMap<&'static str, String>,
+    ) -> Result<BasicSuccessResponse, Error>;
+
+    /// Deletes an outgoing address from the address pool.
+    /// Corresponds to the `deleteOutgoingAddress` API method.
+    async fn delete_outgoing_address(
+        &self,
+        currency: String,
+        address_id: String,
+    ) -> Result<BasicSuccessResponse, Error>;
+
+    /// Retrieves your outgoing addresses for a specific currency.
+    /// Corresponds to the `showOutgoingAddresses` API method.
+    /// Can filter by page.
+    async fn show_outgoing_addresses(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowOutgoingAddressesResponse, Error>;
+
+    /// Retrieves the public trade history for a specific trading pair.
+    /// Corresponds to the `showPublicTradeHistory` API method.
+    /// Can filter by `since_tid`.
+    async fn show_public_trade_history(
+        &self,
+        trading_pair: String,
+        since_tid: Option<String>,
+    ) -> Result<ShowPublicTradeHistoryResponse, Error>;
+
+    /// Retrieves the compact order book for a specific trading pair.
+    /// Corresponds to the `showOrderbookCompact` API method.
+    async fn show_orderbook_compact(
+        &self,
+        trading_pair: String,
+    ) -> Result<ShowOrderbookCompactResponse, Error>;
+
+    /// Adds an address to the address pool for a specific currency.
+    /// Corresponds to the `addToAddressPool` API method.
+    async fn add_to_address_pool(
+        &self,
+        currency: String,
+        address: String,
+    ) -> Result<BasicSuccessResponse, Error>;
+
+    /// Removes an address from the address pool for a specific currency.
+    /// Corresponds to the `removeFromAddressPool` API method.
+    async fn remove_from_address_pool(
+        &self,
+        currency: String,
+        address: String,
+    ) -> Result<BasicSuccessResponse, Error>;
+
+    /// Lists addresses in the address pool for a specific currency.
+    /// Corresponds to the `listAddressPool` API method.
+    /// Shares the response type with `showOutgoingAddresses`.
+    async fn list_address_pool(
+        &self,
+        currency: String,
+        page: Option<String>,
+    ) -> Result<ShowOutgoingAddressesResponse, Error>;
+
+    /// Marks coins as transferred for a crypto-to-crypto trade.
+    /// Corresponds to the `markCoinsAsTransferred` API method.
+    async fn mark_coins_as_transferred(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        amount_currency_to_trade_after_fee: Decimal,
+    ) -> Result<BasicSuccessResponse, Error>;
+
+    /// Marks coins as received for a crypto-to-crypto trade.
+    /// Corresponds to the `markCoinsAsReceived` API method.
+    async fn mark_coins_as_received(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        amount_currency_to_trade_after_fee: Decimal,
+        rating: String,
+    ) -> Result<BasicSuccessResponse, Error>;
+
+    /// Executes a trade after checking the amount against the order's limits.
+    ///
+    /// Fetches the order via `show_order_details` first and rejects amounts outside its
+    /// limits locally, see [`TradingApiSdkV4::execute_trade_checked`].
+    async fn execute_trade_checked(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error> {
+        // Validate the request itself before spending credits on the order lookup
+        request.validate()?;
+        let order = self.show_order_details(trading_pair.clone(), order_id.clone()).await?;
+        request.validate_against_order(&order.order_details)?;
+        self.execute_trade(trading_pair, order_id, request).await
+    }
+
+    /// Retrieves your orders of all pages via `show_my_orders`; the `page` of the filter is ignored.
+    async fn show_my_orders_all(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<Vec<MyOrderDetails>, Error> {
+        pagination::paginate(move |page| {
+            let filter = MyOrdersFilter { page: Some(page as u32), ..filter.clone() };
+            let trading_pair = trading_pair.clone();
+            async move { self.show_my_orders(trading_pair, &filter).await }
+        })
+        .try_collect()
+        .await
+    }
+
+    /// Retrieves your trades of all pages via `show_my_trades`; a `page` parameter is ignored.
+    async fn show_my_trades_all(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<MyTradeDetails>, Error> {
+        let params = params.unwrap_or_default();
+        pagination::paginate(|page| self.show_my_trades(trading_pair.clone(), Some(pagination::with_page(&params, page))))
+            .try_collect()
+            .await
+    }
+
+    /// Retrieves your ledger entries of all pages via `show_account_ledger`; a `page` parameter is ignored.
+    async fn show_account_ledger_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<LedgerEntry>, Error> {
+        let params = params.unwrap_or_default();
+        pagination::paginate(|page| self.show_account_ledger(currency.clone(), Some(pagination::with_page(&params, page))))
+            .try_collect()
+            .await
+    }
+
+    /// Retrieves your withdrawals of all pages via `show_withdrawals`; a `page` parameter is ignored.
+    async fn show_withdrawals_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<WithdrawalDetails>, Error> {
+        let params = params.unwrap_or_default();
+        pagination::paginate(|page| self.show_withdrawals(currency.clone(), Some(pagination::with_page(&params, page))))
+            .try_collect()
+            .await
+    }
+
+    /// Retrieves your deposits of all pages via `show_deposits`; a `page` parameter is ignored.
+    async fn show_deposits_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<DepositDetails>, Error> {
+        let params = params.unwrap_or_default();
+        pagination::paginate(|page| self.show_deposits(currency.clone(), Some(pagination::with_page(&params, page))))
+            .try_collect()
+            .await
+    }
+
+    /// Retrieves your outgoing addresses of all pages via `show_outgoing_addresses`; a `page` parameter is ignored.
+    async fn show_outgoing_addresses_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        let params = params.unwrap_or_default();
+        pagination::paginate(|page| self.show_outgoing_addresses(currency.clone(), Some(pagination::with_page(&params, page))))
+            .try_collect()
+            .await
+    }
+
+    /// Retrieves your address pool entries of all pages via `list_address_pool`.
+    async fn list_address_pool_all(&self, currency: String) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        pagination::paginate(|page| self.list_address_pool(currency.clone(), Some(page.to_string())))
+            .try_collect()
+            .await
+    }
+}
+
+// The inherent methods take precedence, so each trait method forwards to the client method of the same name
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl BitcoinDeApi for TradingApiSdkV4 {
+    async fn show_account_info(&self) -> Result<ShowAccountInfoResponse, Error> {
+        TradingApiSdkV4::show_account_info(self).await
+    }
+
+    async fn show_rates(&self, trading_pair: TradingPair) -> Result<ShowRatesResponse, Error> {
+        TradingApiSdkV4::show_rates(self, trading_pair).await
+    }
+
+    async fn show_orderbook(
+        &self,
+        trading_pair: TradingPair,
+        query: &OrderbookQuery,
+    ) -> Result<ShowOrderbookResponse, Error> {
+        TradingApiSdkV4::show_orderbook(self, trading_pair, query).await
+    }
+
+    async fn show_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowOrderDetailsResponse, Error> {
+        TradingApiSdkV4::show_order_details(self, trading_pair, order_id).await
+    }
+
+    async fn create_order(
+        &self,
+        trading_pair: String,
+        request: &CreateOrderRequest,
+    ) -> Result<CreateOrderResponse, Error> {
+        TradingApiSdkV4::create_order(self, trading_pair, request).await
+    }
+
+    async fn delete_order(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<DeleteOrderResponse, Error> {
+        TradingApiSdkV4::delete_order(self, trading_pair, order_id).await
+    }
+
+    async fn show_my_orders(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<ShowMyOrdersResponse, Error> {
+        TradingApiSdkV4::show_my_orders(self, trading_pair, filter).await
+    }
+
+    async fn show_my_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowMyOrderDetailsResponse, Error> {
+        TradingApiSdkV4::show_my_order_details(self, trading_pair, order_id).await
+    }
+
+    async fn show_my_trades(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowMyTradesResponse, Error> {
+        TradingApiSdkV4::show_my_trades(self, trading_pair, params).await
+    }
+
+    async fn show_my_trade_details(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+    ) -> Result<ShowMyTradeDetailsResponse, Error> {
+        TradingApiSdkV4::show_my_trade_details(self, trading_pair, trade_id).await
+    }
+
+    async fn execute_trade(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error> {
+        TradingApiSdkV4::execute_trade(self, trading_pair, order_id, request).await
+    }
+
+    async fn mark_trade_as_paid(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        volume_currency_to_pay_after_fee: Decimal,
+    ) -> Result<MarkTradeAsPaidResponse, Error> {
+        TradingApiSdkV4::mark_trade_as_paid(self, trading_pair, trade_id, volume_currency_to_pay_after_fee).await
+    }
+
+    async fn mark_trade_as_payment_received(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        volume_currency_to_pay_after_fee: Decimal,
+        rating: String,
+        is_paid_from_correct_bank_account: bool,
+    ) -> Result<MarkTradeAsPaymentReceivedResponse, Error> {
+        TradingApiSdkV4::mark_trade_as_payment_received(
+            self,
+            trading_pair,
+            trade_id,
+            volume_currency_to_pay_after_fee,
+            rating,
+            is_paid_from_correct_bank_account,
+        )
+        .await
+    }
+
+    async fn add_trade_rating(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        rating: String,
+    ) -> Result<AddTradeRatingResponse, Error> {
+        TradingApiSdkV4::add_trade_rating(self, trading_pair, trade_id, rating).await
+    }
+
+    async fn show_account_ledger(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowAccountLedgerResponse, Error> {
+        TradingApiSdkV4::show_account_ledger(self, currency, params).await
+    }
+
+    async fn show_permissions(&self) -> Result<ShowPermissionsResponse, Error> {
+        TradingApiSdkV4::show_permissions(self).await
+    }
+
+    async fn create_withdrawal(
+        &self,
+        currency: String,
+        amount: Decimal,
+        address: String,
+        network_fee: Decimal,
+    ) -> Result<CreateWithdrawalResponse, Error> {
+        TradingApiSdkV4::create_withdrawal(self, currency, amount, address, network_fee).await
+    }
+
+    async fn delete_withdrawal(
+        &self,
+        currency: String,
+        withdrawal_id: String,
+    ) -> Result<DeleteWithdrawalResponse, Error> {
+        TradingApiSdkV4::delete_withdrawal(self, currency, withdrawal_id).await
+    }
+
+    async fn show_withdrawal(
+        &self,
+        currency: String,
+        withdrawal_id: String,
+    ) -> Result<ShowWithdrawalResponse, Error> {
+        TradingApiSdkV4::show_withdrawal(self, currency, withdrawal_id).await
+    }
+
+    async fn show_withdrawals(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowWithdrawalsResponse, Error> {
+        TradingApiSdkV4::show_withdrawals(self, currency, params).await
+    }
+
+    async fn show_withdrawal_min_network_fee(
+        &self,
+        currency: String,
+    ) -> Result<ShowWithdrawalMinNetworkFeeResponse, Error> {
+        TradingApiSdkV4::show_withdrawal_min_network_fee(self, currency).await
+    }
+
+    async fn request_deposit_address(
+        &self,
+        currency: String,
+        comment: Option<String>,
+    ) -> Result<RequestDepositAddressResponse, Error> {
+        TradingApiSdkV4::request_deposit_address(self, currency, comment).await
+    }
+
+    async fn show_deposit(
+        &self,
+        currency: String,
+        deposit_id: String,
+    ) -> Result<ShowDepositResponse, Error> {
+        TradingApiSdkV4::show_deposit(self, currency, deposit_id).await
+    }
+
+    async fn show_deposits(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowDepositsResponse, Error> {
+        TradingApiSdkV4::show_deposits(self, currency, params).await
+    }
+
+    async fn create_outgoing_address(
+        &self,
+        currency: String,
+        params: HashMap<&'static str, String>,
+    ) -> Result<BasicSuccessResponse, Error> {
+        TradingApiSdkV4::create_outgoing_address(self, currency, params).await
+    }
+
+    async fn delete_outgoing_address(
+        &self,
+        currency: String,
+        address_id: String,
+    ) -> Result<BasicSuccessResponse, Error> {
+        TradingApiSdkV4::delete_outgoing_address(self, currency, address_id).await
+    }
+
+    async fn show_outgoing_addresses(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowOutgoingAddressesResponse, Error> {
+        TradingApiSdkV4::show_outgoing_addresses(self, currency, params).await
+    }
+
+    async fn show_public_trade_history(
+        &self,
+        trading_pair: String,
+        since_tid: Option<String>,
+    ) -> Result<ShowPublicTradeHistoryResponse, Error> {
+        TradingApiSdkV4::show_public_trade_history(self, trading_pair, since_tid).await
+    }
+
+    async fn show_orderbook_compact(
+        &self,
+        trading_pair: String,
+    ) -> Result<ShowOrderbookCompactResponse, Error> {
+        TradingApiSdkV4::show_orderbook_compact(self, trading_pair).await
+    }
+
+    async fn add_to_address_pool(
+        &self,
+        currency: String,
+        address: String,
+    ) -> Result<BasicSuccessResponse, Error> {
+        TradingApiSdkV4::add_to_address_pool(self, currency, address).await
+    }
+
+    async fn remove_from_address_pool(
+        &self,
+        currency: String,
+        address: String,
+    ) -> Result<BasicSuccessResponse, Error> {
+        TradingApiSdkV4::remove_from_address_pool(self, currency, address).await
+    }
+
+    async fn list_address_pool(
+        &self,
+        currency: String,
+        page: Option<String>,
+    ) -> Result<ShowOutgoingAddressesResponse, Error> {
+        TradingApiSdkV4::list_address_pool(self, currency, page).await
+    }
+
+    async fn mark_coins_as_transferred(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        amount_currency_to_trade_after_fee: Decimal,
+    ) -> Result<BasicSuccessResponse, Error> {
+        TradingApiSdkV4::mark_coins_as_transferred(self, trading_pair, trade_id, amount_currency_to_trade_after_fee).await
+    }
+
+    async fn mark_coins_as_received(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        amount_currency_to_trade_after_fee: Decimal,
+        rating: String,
+    ) -> Result<BasicSuccessResponse, Error> {
+        TradingApiSdkV4::mark_coins_as_received(self, trading_pair, trade_id, amount_currency_to_trade_after_fee, rating).await
+    }
+
+    async fn execute_trade_checked(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error> {
+        TradingApiSdkV4::execute_trade_checked(self, trading_pair, order_id, request).await
+    }
+
+    async fn show_my_orders_all(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<Vec<MyOrderDetails>, Error> {
+        TradingApiSdkV4::show_my_orders_all(self, trading_pair, filter).await
+    }
+
+    async fn show_my_trades_all(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<MyTradeDetails>, Error> {
+        TradingApiSdkV4::show_my_trades_all(self, trading_pair, params).await
+    }
+
+    async fn show_account_ledger_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<LedgerEntry>, Error> {
+        TradingApiSdkV4::show_account_ledger_all(self, currency, params).await
+    }
+
+    async fn show_withdrawals_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<WithdrawalDetails>, Error> {
+        TradingApiSdkV4::show_withdrawals_all(self, currency, params).await
+    }
+
+    async fn show_deposits_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<DepositDetails>, Error> {
+        TradingApiSdkV4::show_deposits_all(self, currency, params).await
+    }
+
+    async fn show_outgoing_addresses_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        TradingApiSdkV4::show_outgoing_addresses_all(self, currency, params).await
+    }
+
+    async fn list_address_pool_all(&self, currency: String) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        TradingApiSdkV4::list_address_pool_all(self, currency).await
+    }
+}
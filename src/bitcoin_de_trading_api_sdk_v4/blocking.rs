@@ -0,0 +1,968 @@
+// bitcoin_de_trading_api_sdk_v4/blocking.rs
+//! A synchronous client for scripts, cron jobs and FFI consumers without an async runtime.
+//!
+//! [`TradingApiSdkV4`] mirrors every method of the async
+//! [`TradingApiSdkV4`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4) on top of
+//! `reqwest::blocking`. Requests are built and signed by the same code, use the same
+//! `METHOD_SETTINGS` and return the same response types. The `*_stream` methods are
+//! replaced by `*_iter` methods returning an `Iterator`.
+//!
+//! Like `reqwest::blocking`, the client must not be used from within an async runtime.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::blocking::TradingApiSdkV4;
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+//!
+//! # fn main() -> Result<(), bitcoin_de::bitcoin_de_trading_api_sdk_v4::Error> {
+//! let client = TradingApiSdkV4::new("your_api_key".to_string(), "your_api_secret".to_string());
+//! let rates = client.show_rates(TradingPair::BTCEUR)?;
+//! println!("{}", rates.rates.rate_weighted);
+//! # Ok(())
+//! # }
+//! ```
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+
+use crate::bitcoin_de_trading_api_sdk_v4::builder::TradingApiSdkV4Builder;
+use crate::bitcoin_de_trading_api_sdk_v4::cassette::Cassette;
+use crate::bitcoin_de_trading_api_sdk_v4::config::ApiCredentials;
+use crate::bitcoin_de_trading_api_sdk_v4::constants::API_BASE_URI;
+use crate::bitcoin_de_trading_api_sdk_v4::credits::{self, CreditTracker};
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::{MethodSetting, METHOD_SETTINGS};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{Currency, TradingPair};
+use crate::bitcoin_de_trading_api_sdk_v4::market_info::Settlement;
+use crate::bitcoin_de_trading_api_sdk_v4::nonce::{AtomicNonceProvider, NonceProvider};
+use crate::bitcoin_de_trading_api_sdk_v4::pagination;
+use crate::bitcoin_de_trading_api_sdk_v4::retry::RetryPolicy;
+use crate::bitcoin_de_trading_api_sdk_v4::requests::{
+    bool_param, positive_decimal_param, request_parameters, CreateOrderRequest, ExecuteTradeRequest, MyOrdersFilter,
+    OrderbookQuery, RequestParameters,
+};
+use crate::bitcoin_de_trading_api_sdk_v4::responses::*;
+use crate::bitcoin_de_trading_api_sdk_v4::trading_api_sdk_v4::{handle_response, PreparedRequest};
+
+// Import constants for method names and parameter names
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
+
+
+/// A blocking client for the Bitcoin.de Trading API v4.
+///
+/// Has the same fields and configuration as the async client; use [`TradingApiSdkV4::new`]
+/// for the defaults, or [`TradingApiSdkV4::builder`] and
+/// [`build_blocking`](TradingApiSdkV4Builder::build_blocking) to customize it.
+pub struct TradingApiSdkV4 {
+    pub(crate) api_credentials: ApiCredentials,
+    pub(crate) method_settings: &'static HashMap<&'static str, MethodSetting>,
+    pub(crate) client: Arc<Client>, // Re-use the client
+    pub(crate) base_url: String, // e.g. "https://api.bitcoin.de/v4", no trailing slash
+    pub(crate) retry_policy: Option<RetryPolicy>, // None: every request is sent once
+    pub(crate) nonce_provider: Arc<dyn NonceProvider>, // Shared by all requests of this client
+    pub(crate) credit_tracker: CreditTracker, // Updated from the `credits` of every response
+    pub(crate) cassette: Option<Cassette>, // None: no recording, every request is sent
+}
+
+impl TradingApiSdkV4 {
+    /// Creates a new blocking client with the given credentials and the default settings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::blocking::TradingApiSdkV4;
+    ///
+    /// let client = TradingApiSdkV4::new("your_api_key".to_string(), "your_api_secret".to_string());
+    /// ```
+    pub fn new(api_key: String, api_secret: String) -> Self {
+        TradingApiSdkV4 {
+            api_credentials: ApiCredentials::new(api_key, api_secret),
+            method_settings: &METHOD_SETTINGS,
+            client: Client::new().into(), // Create client once
+            base_url: API_BASE_URI.trim_end_matches('/').to_string(),
+            retry_policy: None,
+            nonce_provider: Arc::new(AtomicNonceProvider::new()),
+            credit_tracker: CreditTracker::default(),
+            cassette: None,
+        }
+    }
+
+    /// Returns a builder for a customized client, finished with
+    /// [`build_blocking`](TradingApiSdkV4Builder::build_blocking).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::blocking::TradingApiSdkV4;
+    ///
+    /// let client = TradingApiSdkV4::builder()
+    ///     .api_key("your_api_key")
+    ///     .api_secret("your_api_secret")
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build_blocking()
+    ///     .expect("valid configuration");
+    /// assert_eq!(client.base_url(), "https://api.bitcoin.de/v4");
+    /// ```
+    pub fn builder() -> TradingApiSdkV4Builder {
+        TradingApiSdkV4Builder::new()
+    }
+
+    /// Returns the base URL this client sends its requests to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the credit balance reported with the last response, successful or not.
+    ///
+    /// `None` until the first response has been received.
+    pub fn credits(&self) -> Option<i32> {
+        self.credit_tracker.reported()
+    }
+
+    /// Returns the estimated current credit balance (see `CreditFloor`).
+    pub fn estimated_credits(&self) -> Option<i32> {
+        self.credit_tracker.estimated()
+    }
+
+    /// Executes an API request, blocking until the response has been received.
+    ///
+    /// Takes the same parameters as the async
+    /// [`do_request`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::do_request) and is traced
+    /// the same way. Retry backoff and credit floor waits block the current thread.
+    pub fn do_request<T: DeserializeOwned>(
+        &self,
+        method_name: &'static str,
+        parameters: Option<HashMap<&'static str, String>>,
+    ) -> Result<T, Error> {
+        self.do_request_with(method_name, request_parameters(parameters))
+    }
+
+    /// Like [`do_request`](Self::do_request), for the parameters of the typed requests.
+    pub(crate) fn do_request_with<T: DeserializeOwned>(&self, method_name: &'static str, parameters: RequestParameters) -> Result<T, Error> {
+        let request = PreparedRequest::new(self.method_settings, &self.base_url, method_name, parameters)?;
+        let _entered = request.span.enter();
+
+        // Every attempt is charged, so each one has to pass the credit floor
+        let mut attempt = 1;
+        loop {
+            self.credit_tracker.acquire_blocking(request.credits)?;
+            let started = credits::now();
+            let result = self.send_signed_request(&request);
+            request.trace_attempt(&result, attempt, started);
+            match (result, &self.retry_policy) {
+                (Err(error), Some(policy)) if policy.should_retry(&error, request.http_method, attempt) => {
+                    let backoff = policy.backoff(attempt);
+                    tracing::warn!(attempt, backoff_ms = backoff.as_millis() as u64, %error, "retrying request");
+                    std::thread::sleep(backoff);
+                    attempt += 1;
+                }
+                (result, _) => return result,
+            }
+        }
+    }
+
+    /// Signs and sends a single attempt of a request with a fresh nonce.
+    fn send_signed_request<T: DeserializeOwned>(&self, request: &PreparedRequest) -> Result<T, Error> {
+        if let Some(cassette) = &self.cassette {
+            if let Some((status_code, response_text)) = cassette.replay(request)? {
+                return handle_response(status_code, &response_text, &self.credit_tracker, &request.span);
+            }
+        }
+
+        let headers = request.signed_headers(&self.api_credentials, self.nonce_provider.as_ref())?;
+
+        let mut request_builder = match request.http_method {
+            "GET" => self.client.get(request.url_for_request.clone()),
+            "POST" => self.client.post(request.url_for_request.clone()),
+            "DELETE" => self.client.delete(request.url_for_request.clone()),
+            _ => return Err(Error::Other(format!("Unsupported HTTP method: {}", request.http_method))),
+        };
+
+        request_builder = request_builder.headers(headers);
+
+        // Add body for POST requests
+        if let Some(body_params) = &request.body {
+            // In the order the body was signed in
+            request_builder = request_builder.form(body_params);
+        }
+
+        let response = request_builder.send()?;
+        let status_code = response.status();
+        let response_text = response.text()?; // Read body regardless of status for potential error details
+        if let Some(cassette) = &self.cassette {
+            cassette.record(request, status_code, &response_text);
+        }
+        handle_response(status_code, &response_text, &self.credit_tracker, &request.span)
+    }
+
+    // --- Public API Methods (Wrappers around do_request) ---
+
+    /// Retrieves account information for the authenticated user.
+    /// Corresponds to the `showAccountInfo` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_account_info`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_account_info).
+    pub fn show_account_info(&self) -> Result<ShowAccountInfoResponse, Error> {
+        self.do_request(METHOD_SHOW_ACCOUNT_INFO, None)
+    }
+
+    /// Retrieves current trading rates for a specific trading pair.
+    /// Corresponds to the `showRates` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_rates`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_rates).
+    pub fn show_rates(&self, trading_pair: TradingPair) -> Result<ShowRatesResponse, Error> {
+        let mut params = HashMap::new();
+        params.insert(SHOW_RATES_PARAMETER_TRADING_PAIR, trading_pair.to_string().to_ascii_lowercase());
+        self.do_request(METHOD_SHOW_RATES, Some(params))
+    }
+
+    /// Retrieves the public order book for a specific trading pair.
+    /// Corresponds to the `showOrderbook` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_orderbook`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_orderbook).
+    pub fn show_orderbook(
+        &self,
+        trading_pair: TradingPair,
+        query: &OrderbookQuery,
+    ) -> Result<ShowOrderbookResponse, Error> {
+        let mut all_params = query.to_parameters(trading_pair)?;
+        // Add the trading_pair as a path parameter for do_request
+        all_params.insert(SHOW_ORDERBOOK_PARAMETER_TRADING_PAIR.into(), trading_pair.to_string().to_ascii_lowercase());
+        self.do_request_with(METHOD_SHOW_ORDERBOOK, all_params)
+    }
+
+    /// Retrieves details for a specific public order.
+    /// Corresponds to the `showOrderDetails` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_order_details`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_order_details).
+    pub fn show_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowOrderDetailsResponse, Error> {
+        let mut params = HashMap::new();
+        params.insert(SHOW_ORDER_DETAILS_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(SHOW_ORDER_DETAILS_PARAMETER_ORDER_ID, order_id);
+        self.do_request(METHOD_SHOW_ORDER_DETAILS, Some(params))
+    }
+
+    /// Creates a new order.
+    /// Corresponds to the `createOrder` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::create_order`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::create_order).
+    pub fn create_order(
+        &self,
+        trading_pair: String,
+        request: &CreateOrderRequest,
+    ) -> Result<CreateOrderResponse, Error> {
+        let mut params = request.to_parameters(trading_pair.parse()?)?;
+        // Add the trading_pair as a path parameter for do_request
+        params.insert(CREATE_ORDER_PARAMETER_TRADING_PAIR.into(), trading_pair);
+        self.do_request_with(METHOD_CREATE_ORDER, params)
+    }
+
+    /// Deletes an existing order.
+    /// Corresponds to the `deleteOrder` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::delete_order`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::delete_order).
+    pub fn delete_order(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<DeleteOrderResponse, Error> {
+        let mut params = HashMap::new();
+        params.insert(DELETE_ORDER_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(DELETE_ORDER_PARAMETER_ORDER_ID, order_id);
+        self.do_request(METHOD_DELETE_ORDER, Some(params))
+    }
+
+    /// Retrieves the user's orders.
+    /// Corresponds to the `showMyOrders` API method.
+    /// Can filter by trading pair and other criteria.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_orders`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_orders).
+    pub fn show_my_orders(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<ShowMyOrdersResponse, Error> {
+        let mut all_params = filter.to_parameters()?;
+        // Add trading_pair as a path parameter if provided
+        if let Some(pair) = trading_pair {
+            // This parameter name needs to match the one used as the placeholder in method_settings.rs
+            all_params.insert(SHOW_MY_ORDERS_PARAMETER_TRADING_PAIR.into(), pair);
+        }
+        // Without a trading pair, the optional path segment is left out: GET /v4/orders
+        self.do_request_with(METHOD_SHOW_MY_ORDERS, all_params)
+    }
+
+    /// Iterates over your orders of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_orders_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_orders_stream).
+    pub fn show_my_orders_iter(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> impl Iterator<Item = Result<MyOrderDetails, Error>> + '_ {
+        let filter = filter.clone();
+        pagination::paginate_blocking(move |page| {
+            let filter = MyOrdersFilter { page: Some(page as u32), ..filter.clone() };
+            self.show_my_orders(trading_pair.clone(), &filter)
+        })
+    }
+
+    /// Retrieves your orders of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_orders_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_orders_all).
+    pub fn show_my_orders_all(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<Vec<MyOrderDetails>, Error> {
+        self.show_my_orders_iter(trading_pair, filter).collect()
+    }
+
+    /// Retrieves details for one of your orders.
+    /// Corresponds to the `showMyOrderDetails` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_order_details`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_order_details).
+    pub fn show_my_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowMyOrderDetailsResponse, Error> {
+        let mut params = HashMap::new();
+        // Add the trading_pair and order_id as path parameters for do_request
+        params.insert(SHOW_MY_ORDER_DETAILS_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(SHOW_MY_ORDER_DETAILS_PARAMETER_ORDER_ID, order_id);
+        self.do_request(METHOD_SHOW_MY_ORDER_DETAILS, Some(params))
+    }
+
+    /// Retrieves the user's trades.
+    /// Corresponds to the `showMyTrades` API method.
+    /// Can filter by trading pair and other criteria.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_trades`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_trades).
+    pub fn show_my_trades(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowMyTradesResponse, Error> {
+        let mut all_params = params.unwrap_or_default();
+        // Add trading_pair as a path parameter if provided
+        if let Some(pair) = trading_pair {
+            all_params.insert(SHOW_MY_TRADES_PARAMETER_TRADING_PAIR, pair);
+        }
+        // Without a trading pair, the optional path segment is left out: GET /v4/trades
+        self.do_request(METHOD_SHOW_MY_TRADES, Some(all_params))
+    }
+
+    /// Iterates over your trades of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_trades_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_trades_stream).
+    pub fn show_my_trades_iter(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Iterator<Item = Result<MyTradeDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate_blocking(move |page| self.show_my_trades(trading_pair.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your trades of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_trades_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_trades_all).
+    pub fn show_my_trades_all(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<MyTradeDetails>, Error> {
+        self.show_my_trades_iter(trading_pair, params).collect()
+    }
+
+    /// Retrieves details for one of your trades.
+    /// Corresponds to the `showMyTradeDetails` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_my_trade_details`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_my_trade_details).
+    pub fn show_my_trade_details(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+    ) -> Result<ShowMyTradeDetailsResponse, Error> {
+        let mut params = HashMap::new();
+        // These parameter names need to match the ones used as placeholders in method_settings.rs
+        params.insert(SHOW_MY_TRADE_DETAILS_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(SHOW_MY_TRADE_DETAILS_PARAMETER_TRADE_ID, trade_id);
+        self.do_request(METHOD_SHOW_MY_TRADE_DETAILS, Some(params))
+    }
+
+    /// Executes a trade against a specific order.
+    /// Corresponds to the `executeTrade` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::execute_trade`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::execute_trade).
+    pub fn execute_trade(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error> {
+        let mut params = request.to_parameters(trading_pair.parse()?)?;
+        // Add the trading_pair and order_id as path parameters for do_request
+        params.insert(EXECUTE_TRADE_PARAMETER_TRADING_PAIR.into(), trading_pair);
+        params.insert(EXECUTE_TRADE_PARAMETER_ORDER_ID.into(), order_id);
+        self.do_request_with(METHOD_EXECUTE_TRADE, params)
+    }
+
+    /// Executes a trade after checking the amount against the order's limits.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::execute_trade_checked`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::execute_trade_checked).
+    pub fn execute_trade_checked(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error> {
+        // Validate the request itself before spending credits on the order lookup
+        request.validate()?;
+        let order = self.show_order_details(trading_pair.clone(), order_id.clone())?;
+        request.validate_against_order(&order.order_details)?;
+        self.execute_trade(trading_pair, order_id, request)
+    }
+
+    /// Marks a trade as paid.
+    /// Corresponds to the `markTradeAsPaid` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::mark_trade_as_paid`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::mark_trade_as_paid).
+    pub fn mark_trade_as_paid(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        volume_currency_to_pay_after_fee: Decimal,
+    ) -> Result<MarkTradeAsPaidResponse, Error> {
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::BankTransfer, MARK_TRADE_AS_PAID_PARAMETER_TRADING_PAIR)?;
+        let mut params = HashMap::new();
+        // Add body parameter, in the currency to pay (e.g. EUR for btceur)
+        params.insert(
+            MARK_TRADE_AS_PAID_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE,
+            positive_decimal_param(MARK_TRADE_AS_PAID_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE, volume_currency_to_pay_after_fee, pair.quote)?,
+        );
+        // Add path parameters
+        params.insert(MARK_TRADE_AS_PAID_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(MARK_TRADE_AS_PAID_PARAMETER_TRADE_ID, trade_id);
+        self.do_request(METHOD_MARK_TRADE_AS_PAID, Some(params))
+    }
+
+    /// Marks a trade as payment received.
+    /// Corresponds to the `markTradeAsPaymentReceived` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::mark_trade_as_payment_received`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::mark_trade_as_payment_received).
+    pub fn mark_trade_as_payment_received(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        volume_currency_to_pay_after_fee: Decimal,
+        rating: String,
+        is_paid_from_correct_bank_account: bool,
+    ) -> Result<MarkTradeAsPaymentReceivedResponse, Error> {
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::BankTransfer, MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_TRADING_PAIR)?;
+        let mut params = HashMap::new();
+        // Add body parameters
+        params.insert(
+            MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE,
+            positive_decimal_param(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE, volume_currency_to_pay_after_fee, pair.quote)?,
+        );
+        params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_RATING, rating);
+        params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_IS_PAID_FROM_CORRECT_BANK_ACCOUNT, bool_param(is_paid_from_correct_bank_account));
+        // Add path parameters
+        params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_TRADE_ID, trade_id);
+        // Call do_request with combined params
+        self.do_request(METHOD_MARK_TRADE_AS_PAYMENT_RECEIVED, Some(params))
+    }
+
+    /// Adds a rating to a trade partner.
+    /// Corresponds to the `addTradeRating` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::add_trade_rating`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::add_trade_rating).
+    pub fn add_trade_rating(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        rating: String, // Use String or an appropriate Enum
+    ) -> Result<AddTradeRatingResponse, Error> {
+        let mut params = HashMap::new();
+        // Add path parameters
+        params.insert(ADD_TRADE_RATING_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(ADD_TRADE_RATING_PARAMETER_TRADE_ID, trade_id);
+        // Add body parameter
+        params.insert(ADD_TRADE_RATING_PARAMETER_RATING, rating);
+        self.do_request(METHOD_ADD_TRADE_RATING, Some(params))
+    }
+
+    /// Retrieves your account ledger entries for a specific currency.
+    /// Corresponds to the `showAccountLedger` API method.
+    /// Can filter by type, date range, or page.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_account_ledger`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_account_ledger).
+    pub fn show_account_ledger(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowAccountLedgerResponse, Error> {
+        let mut all_params = params.unwrap_or_default();
+        // Add the currency as a path parameter for do_request
+        all_params.insert(SHOW_ACCOUNT_LEDGER_PARAMETER_CURRENCY, currency);
+        self.do_request(METHOD_SHOW_ACCOUNT_LEDGER, Some(all_params))
+    }
+
+    /// Iterates over your ledger entries of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_account_ledger_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_account_ledger_stream).
+    pub fn show_account_ledger_iter(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Iterator<Item = Result<LedgerEntry, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate_blocking(move |page| self.show_account_ledger(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your ledger entries of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_account_ledger_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_account_ledger_all).
+    pub fn show_account_ledger_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<LedgerEntry>, Error> {
+        self.show_account_ledger_iter(currency, params).collect()
+    }
+
+    /// Retrieves the permissions associated with the API key.
+    /// Corresponds to the `showPermissions` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_permissions`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_permissions).
+    pub fn show_permissions(&self) -> Result<ShowPermissionsResponse, Error> {
+        self.do_request(METHOD_SHOW_PERMISSIONS, None)
+    }
+
+    /// Creates a new cryptocurrency withdrawal.
+    /// Corresponds to the `createWithdrawal` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::create_withdrawal`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::create_withdrawal).
+    pub fn create_withdrawal(
+        &self,
+        currency: String,
+        amount: Decimal,
+        address: String,
+        network_fee: Decimal,
+    ) -> Result<CreateWithdrawalResponse, Error> {
+        let coin: Currency = currency.parse()?;
+        let mut params = HashMap::new();
+        // Add body parameters
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_AMOUNT, positive_decimal_param(CREATE_WITHDRAWAL_PARAMETER_AMOUNT, amount, coin)?);
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_ADDRESS, address);
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_NETWORK_FEE, positive_decimal_param(CREATE_WITHDRAWAL_PARAMETER_NETWORK_FEE, network_fee, coin)?);
+        // Add the currency as a path parameter for do_request
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_CURRENCY, currency);
+        self.do_request(METHOD_CREATE_WITHDRAWAL, Some(params))
+    }
+
+    /// Deletes an existing withdrawal request.
+    /// Corresponds to the `deleteWithdrawal` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::delete_withdrawal`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::delete_withdrawal).
+    pub fn delete_withdrawal(
+        &self,
+        currency: String,
+        withdrawal_id: String,
+    ) -> Result<DeleteWithdrawalResponse, Error> {
+        let mut params = HashMap::new();
+        // Add path parameters
+        params.insert(DELETE_WITHDRAWAL_PARAMETER_CURRENCY, currency);
+        params.insert(DELETE_WITHDRAWAL_PARAMETER_WITHDRAWAL_ID, withdrawal_id);
+        self.do_request(METHOD_DELETE_WITHDRAWAL, Some(params))
+    }
+
+    /// Retrieves details for a specific withdrawal.
+    /// Corresponds to the `showWithdrawal` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_withdrawal`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_withdrawal).
+    pub fn show_withdrawal(
+        &self,
+        currency: String,
+        withdrawal_id: String,
+    ) -> Result<ShowWithdrawalResponse, Error> {
+        let mut params = HashMap::new();
+        // Add path parameters
+        params.insert(SHOW_WITHDRAWAL_PARAMETER_CURRENCY, currency);
+        params.insert(SHOW_WITHDRAWAL_PARAMETER_WITHDRAWAL_ID, withdrawal_id);
+        self.do_request(METHOD_SHOW_WITHDRAWAL, Some(params))
+    }
+
+    /// Retrieves your cryptocurrency withdrawals for a specific currency.
+    /// Corresponds to the `showWithdrawals` API method.
+    /// Can filter by address or page.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_withdrawals`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_withdrawals).
+    pub fn show_withdrawals(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowWithdrawalsResponse, Error> {
+        let mut all_params = params.unwrap_or_default();
+        // Add the currency as a path parameter for do_request
+        all_params.insert(SHOW_WITHDRAWALS_PARAMETER_CURRENCY, currency);
+        self.do_request(METHOD_SHOW_WITHDRAWALS, Some(all_params))
+    }
+
+    /// Iterates over your withdrawals of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_withdrawals_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_withdrawals_stream).
+    pub fn show_withdrawals_iter(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Iterator<Item = Result<WithdrawalDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate_blocking(move |page| self.show_withdrawals(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your withdrawals of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_withdrawals_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_withdrawals_all).
+    pub fn show_withdrawals_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<WithdrawalDetails>, Error> {
+        self.show_withdrawals_iter(currency, params).collect()
+    }
+
+    /// Retrieves the minimum network fee for a withdrawal in a specific currency.
+    /// Corresponds to the `showWithdrawalMinNetworkFee` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_withdrawal_min_network_fee`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_withdrawal_min_network_fee).
+    pub fn show_withdrawal_min_network_fee(
+        &self,
+        currency: String,
+    ) -> Result<ShowWithdrawalMinNetworkFeeResponse, Error> {
+        let mut params = HashMap::new();
+        // Add the currency as a path parameter for do_request
+        params.insert(SHOW_WITHDRAWAL_PARAMETER_MIN_NETWORK_FEE_CURRENCY, currency);
+        self.do_request(METHOD_SHOW_WITHDRAWAL_MIN_NETWORK_FEE, Some(params))
+    }
+
+    /// Requests a new deposit address for a specific currency.
+    /// Corresponds to the `requestDepositAddress` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::request_deposit_address`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::request_deposit_address).
+    pub fn request_deposit_address(
+        &self,
+        currency: String,
+        comment: Option<String>,
+    ) -> Result<RequestDepositAddressResponse, Error> {
+        let mut params = HashMap::new();
+        // Add the currency as a path parameter for do_request
+        params.insert(REQUEST_DEPOSIT_ADDRESS_PARAMETER_CURRENCY, currency);
+        // Add comment as a body parameter if provided
+        if let Some(c) = comment {
+            params.insert("comment", c); // Use literal "comment" parameter name
+        }
+        self.do_request(METHOD_REQUEST_DEPOSIT_ADDRESS, Some(params))
+    }
+
+    /// Retrieves details for a specific deposit.
+    /// Corresponds to the `showDeposit` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_deposit`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_deposit).
+    pub fn show_deposit(
+        &self,
+        currency: String,
+        deposit_id: String,
+    ) -> Result<ShowDepositResponse, Error> {
+        let mut params = HashMap::new();
+        // Add path parameters
+        params.insert(SHOW_DEPOSIT_PARAMETER_CURRENCY, currency);
+        params.insert(SHOW_DEPOSIT_PARAMETER_DEPOSIT_ID, deposit_id);
+        self.do_request(METHOD_SHOW_DEPOSIT, Some(params))
+    }
+
+    /// Retrieves your cryptocurrency deposits for a specific currency.
+    /// Corresponds to the `showDeposits` API method.
+    /// Can filter by address or page.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_deposits`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_deposits).
+    pub fn show_deposits(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowDepositsResponse, Error> {
+        let mut all_params = params.unwrap_or_default();
+        // Add the currency as a path parameter for do_request
+        all_params.insert(SHOW_DEPOSITS_PARAMETER_CURRENCY, currency);
+        self.do_request(METHOD_SHOW_DEPOSITS, Some(all_params))
+    }
+
+    /// Iterates over your deposits of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_deposits_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_deposits_stream).
+    pub fn show_deposits_iter(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Iterator<Item = Result<DepositDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate_blocking(move |page| self.show_deposits(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your deposits of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_deposits_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_deposits_all).
+    pub fn show_deposits_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<DepositDetails>, Error> {
+        self.show_deposits_iter(currency, params).collect()
+    }
+
+    /// Creates a new outgoing address in the address pool.
+    /// Corresponds to the `createOutgoingAddress` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::create_outgoing_address`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::create_outgoing_address).
+    pub fn create_outgoing_address(
+        &self,
+        currency: String,
+        mut params: HashMap<&'static str, String>, // Expect required params here
+    ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        // Add the currency as a path parameter for do_request
+        params.insert(CREATE_OUTGOING_ADDRESS_PARAMETER_CURRENCY, currency);
+        self.do_request(METHOD_CREATE_OUTGOING_ADDRESS, Some(params))
+    }
+
+    /// Deletes an outgoing address from the address pool.
+    /// Corresponds to the `deleteOutgoingAddress` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::delete_outgoing_address`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::delete_outgoing_address).
+    pub fn delete_outgoing_address(
+        &self,
+        currency: String,
+        address_id: String,
+    ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let mut params = HashMap::new();
+        // Add path parameters
+        params.insert(DELETE_OUTGOING_ADDRESS_PARAMETER_CURRENCY, currency);
+        params.insert(DELETE_OUTGOING_ADDRESS_PARAMETER_ADDRESS_ID, address_id);
+        self.do_request(METHOD_DELETE_OUTGOING_ADDRESS, Some(params))
+    }
+
+    /// Retrieves your outgoing addresses for a specific currency.
+    /// Corresponds to the `showOutgoingAddresses` API method.
+    /// Can filter by page.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_outgoing_addresses`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_outgoing_addresses).
+    pub fn show_outgoing_addresses(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<ShowOutgoingAddressesResponse, Error> {
+        let mut all_params = params.unwrap_or_default();
+        // Add the currency as a path parameter for do_request
+        all_params.insert(SHOW_OUTGOING_ADDRESS_PARAMETER_CURRENCY, currency);
+        self.do_request(METHOD_SHOW_OUTGOING_ADDRESSES, Some(all_params))
+    }
+
+    /// Iterates over your outgoing addresses of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_outgoing_addresses_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_outgoing_addresses_stream).
+    pub fn show_outgoing_addresses_iter(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Iterator<Item = Result<OutgoingAddressDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate_blocking(move |page| self.show_outgoing_addresses(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your outgoing addresses of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_outgoing_addresses_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_outgoing_addresses_all).
+    pub fn show_outgoing_addresses_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        self.show_outgoing_addresses_iter(currency, params).collect()
+    }
+
+    /// Retrieves the public trade history for a specific trading pair.
+    /// Corresponds to the `showPublicTradeHistory` API method.
+    /// Can filter by `since_tid`.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_public_trade_history`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_public_trade_history).
+    pub fn show_public_trade_history(
+        &self,
+        trading_pair: String,
+        since_tid: Option<String>, // Use String as query param value
+    ) -> Result<ShowPublicTradeHistoryResponse, Error> {
+        let mut params = HashMap::new();
+        // Add the trading_pair as a path parameter for do_request
+        params.insert(SHOW_PUBLIC_TRADE_HISTORY_PARAMETER_TRADING_PAIR, trading_pair);
+        // Add since_tid as a query parameter if provided
+        if let Some(tid) = since_tid {
+            params.insert("since_tid", tid); // Use literal "since_tid" parameter name
+        }
+        self.do_request(METHOD_SHOW_PUBLIC_TRADE_HISTORY, Some(params))
+    }
+
+    /// Retrieves the compact order book for a specific trading pair.
+    /// Corresponds to the `showOrderbookCompact` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::show_orderbook_compact`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::show_orderbook_compact).
+    pub fn show_orderbook_compact(
+        &self,
+        trading_pair: String,
+    ) -> Result<ShowOrderbookCompactResponse, Error> {
+        let mut params = HashMap::new();
+        // Add the trading_pair as a path parameter for do_request
+        params.insert(SHOW_ORDER_BOOK_COMPACT_PARAMETER_TRADING_PAIR, trading_pair);
+        self.do_request(METHOD_SHOW_ORDERBOOK_COMPACT, Some(params))
+    }
+
+    /// Adds an address to the address pool for a specific currency.
+    /// Corresponds to the `addToAddressPool` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::add_to_address_pool`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::add_to_address_pool).
+    pub fn add_to_address_pool(
+        &self,
+        currency: String,
+        address: String,
+    ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let mut params = HashMap::new();
+        // Add the currency as a path parameter for do_request
+        params.insert(ADD_TO_ADDRESS_POOL_PARAMETER_CURRENCY, currency);
+        // Add address as a body parameter
+        params.insert(ADD_TO_ADDRESS_POOL_PARAMETER_ADDRESS, address);
+        self.do_request(METHOD_ADD_TO_ADDRESS_POOL, Some(params))
+    }
+
+    /// Removes an address from the address pool for a specific currency.
+    /// Corresponds to the `removeFromAddressPool` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::remove_from_address_pool`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::remove_from_address_pool).
+    pub fn remove_from_address_pool(
+        &self,
+        currency: String,
+        address: String,
+    ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let mut params = HashMap::new();
+        // Add path parameters (address is a path parameter here)
+        params.insert(REMOVE_FROM_ADDRESS_POOL_PARAMETER_CURRENCY, currency);
+        params.insert(REMOVE_FROM_ADDRESS_POOL_PARAMETER_ADDRESS, address);
+        self.do_request(METHOD_REMOVE_FROM_ADDRESS_POOL, Some(params))
+    }
+
+    /// Lists addresses in the address pool for a specific currency.
+    /// Corresponds to the `listAddressPool` API method.
+    /// Shares the response type with `showOutgoingAddresses`.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::list_address_pool`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::list_address_pool).
+    pub fn list_address_pool(
+        &self,
+        currency: String,
+        page: Option<String>, // Use String as query param value
+    ) -> Result<ShowOutgoingAddressesResponse, Error> { // Shares response struct
+        let mut params = HashMap::new();
+        // Add the currency as a path parameter for do_request
+        params.insert(LIST_ADDRESS_POOL_PARAMETER_CURRENCY, currency);
+        // Add page as a query parameter if provided
+        if let Some(p) = page {
+            params.insert(PARAMETER_PAGE, p);
+        }
+        self.do_request(METHOD_LIST_ADDRESS_POOL, Some(params))
+    }
+
+    /// Iterates over your address pool entries of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::list_address_pool_stream`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::list_address_pool_stream).
+    pub fn list_address_pool_iter(
+        &self,
+        currency: String,
+    ) -> impl Iterator<Item = Result<OutgoingAddressDetails, Error>> + '_ {
+        pagination::paginate_blocking(move |page| self.list_address_pool(currency.clone(), Some(page.to_string())))
+    }
+
+    /// Retrieves your address pool entries of all pages.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::list_address_pool_all`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::list_address_pool_all).
+    pub fn list_address_pool_all(
+        &self,
+        currency: String,
+    ) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        self.list_address_pool_iter(currency).collect()
+    }
+
+    /// Marks coins as transferred for a crypto-to-crypto trade.
+    /// Corresponds to the `markCoinsAsTransferred` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::mark_coins_as_transferred`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::mark_coins_as_transferred).
+    pub fn mark_coins_as_transferred(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        amount_currency_to_trade_after_fee: Decimal,
+    ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::CoinTransfer, MARK_COINS_AS_TRANSFERRED_PARAMETER_TRADING_PAIR)?;
+        let mut params = HashMap::new();
+        // Add body parameter, in the currency to trade (e.g. ETH for ethbtc)
+        params.insert(
+            MARK_COINS_AS_TRANSFERRED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE,
+            positive_decimal_param(MARK_COINS_AS_TRANSFERRED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE, amount_currency_to_trade_after_fee, pair.base)?,
+        );
+        // Add path parameters
+        params.insert(MARK_COINS_AS_TRANSFERRED_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(MARK_COINS_AS_TRANSFERRED_PARAMETER_TRADE_ID, trade_id);
+        self.do_request(METHOD_MARK_COINS_AS_TRANSFERRED, Some(params))
+    }
+
+    /// Marks coins as received for a crypto-to-crypto trade.
+    /// Corresponds to the `markCoinsAsReceived` API method.
+    ///
+    /// Blocking version of [`TradingApiSdkV4::mark_coins_as_received`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4::mark_coins_as_received).
+    pub fn mark_coins_as_received(
+        &self,
+        trading_pair: String,
+        trade_id: String,
+        amount_currency_to_trade_after_fee: Decimal,
+        rating: String,
+    ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::CoinTransfer, MARK_COINS_AS_RECEIVED_PARAMETER_TRADING_PAIR)?;
+        let mut params = HashMap::new();
+        // Add body parameters
+        params.insert(
+            MARK_COINS_AS_RECEIVED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE,
+            positive_decimal_param(MARK_COINS_AS_RECEIVED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE, amount_currency_to_trade_after_fee, pair.base)?,
+        );
+        params.insert(MARK_COINS_AS_RECEIVED_PARAMETER_RATING, rating);
+        // Add path parameters
+        params.insert(MARK_COINS_AS_RECEIVED_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(MARK_COINS_AS_RECEIVED_PARAMETER_TRADE_ID, trade_id);
+        // Call do_request with combined params
+        self.do_request(METHOD_MARK_COINS_AS_RECEIVED, Some(params))
+    }
+}
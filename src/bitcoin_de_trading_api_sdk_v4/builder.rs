@@ -0,0 +1,292 @@
+// bitcoin_de_trading_api_sdk_v4/builder.rs
+use std::path::PathBuf;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use url::Url;
+
+use crate::bitcoin_de_trading_api_sdk_v4::cassette::{Cassette, CassetteSource};
+use crate::bitcoin_de_trading_api_sdk_v4::config::{ApiCredentials, ApiSecret};
+use crate::bitcoin_de_trading_api_sdk_v4::constants::API_BASE_URI;
+use crate::bitcoin_de_trading_api_sdk_v4::credits::{CreditFloor, CreditTracker};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::METHOD_SETTINGS;
+use crate::bitcoin_de_trading_api_sdk_v4::nonce::{AtomicNonceProvider, NonceProvider};
+use crate::bitcoin_de_trading_api_sdk_v4::retry::RetryPolicy;
+use crate::bitcoin_de_trading_api_sdk_v4::trading_api_sdk_v4::TradingApiSdkV4;
+#[cfg(feature = "blocking")]
+use crate::bitcoin_de_trading_api_sdk_v4::blocking::TradingApiSdkV4 as BlockingTradingApiSdkV4;
+
+/// Builder for a customized [`TradingApiSdkV4`] client.
+///
+/// Obtained via [`TradingApiSdkV4::builder`]. Only the API key and secret are required;
+/// everything else falls back to the same defaults as [`TradingApiSdkV4::new`].
+/// With the `blocking` feature, [`build_blocking`](Self::build_blocking) creates the
+/// synchronous client from the same settings.
+///
+/// Either pass a fully pre-configured `reqwest::Client` via [`client`](Self::client), or let
+/// the builder create one from the `timeout`, `connect_timeout`, `user_agent` and `proxy`
+/// settings. Combining both is rejected by [`build`](Self::build), as the HTTP settings
+/// could not be applied to an already built client.
+#[derive(Default)]
+pub struct TradingApiSdkV4Builder {
+    api_key: Option<String>,
+    api_secret: Option<ApiSecret>,
+    base_url: Option<String>,
+    client: Option<Client>,
+    user_agent: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy: Option<reqwest::Proxy>,
+    retry_policy: Option<RetryPolicy>,
+    nonce_provider: Option<Arc<dyn NonceProvider>>,
+    credit_floor: Option<CreditFloor>,
+    cassette: Option<CassetteSource>,
+}
+
+impl TradingApiSdkV4Builder {
+    /// Creates an empty builder. Prefer [`TradingApiSdkV4::builder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the API key used for the `X-API-KEY` header and the request signature.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the API secret used to sign requests.
+    pub fn api_secret(mut self, api_secret: impl Into<ApiSecret>) -> Self {
+        self.api_secret = Some(api_secret.into());
+        self
+    }
+
+    /// Sets the API key and secret at once, e.g. from [`ApiCredentials::from_env`]
+    /// or [`ApiCredentials::from_file`].
+    pub fn credentials(mut self, credentials: ApiCredentials) -> Self {
+        self.api_key = Some(credentials.api_key);
+        self.api_secret = Some(credentials.api_secret);
+        self
+    }
+
+    /// Overrides the base URL (default: `https://api.bitcoin.de/v4`).
+    ///
+    /// The URL must include the version path, e.g. `http://127.0.0.1:8080/v4`.
+    /// A trailing slash is stripped.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Uses a pre-configured `reqwest::Client` instead of creating a new one.
+    ///
+    /// Cannot be combined with `timeout`, `connect_timeout`, `user_agent` or `proxy`.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the total timeout for each request (connect, send and read the response).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing a connection.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Routes all requests through the given proxy, e.g. a corporate egress proxy:
+    /// `reqwest::Proxy::all("http://proxy.internal:3128")?`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Enables automatic retries of transient failures with the given policy.
+    ///
+    /// Without a policy, every request is sent exactly once. The async client only retries
+    /// with the `tokio` feature, which provides the timer to wait between attempts (see
+    /// [`RetryPolicy`]).
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Replaces the default in-memory [`AtomicNonceProvider`], e.g. with a
+    /// [`FileNonceProvider`](crate::bitcoin_de_trading_api_sdk_v4::nonce::FileNonceProvider)
+    /// that survives restarts, or an `Arc` of a provider shared with other clients.
+    pub fn nonce_provider(mut self, nonce_provider: impl NonceProvider + 'static) -> Self {
+        self.nonce_provider = Some(Arc::new(nonce_provider));
+        self
+    }
+
+    /// Holds back requests that would bring the estimated credit balance below the floor,
+    /// instead of letting the API reject them with `ERROR_CODE_INSUFFICIENT_CREDITS`.
+    ///
+    /// Without a floor, requests are sent regardless of the balance.
+    pub fn credit_floor(mut self, credit_floor: CreditFloor) -> Self {
+        self.credit_floor = Some(credit_floor);
+        self
+    }
+
+    /// Appends every response (method name, redacted parameters, status, body, timestamp and
+    /// credits) as a JSON line to the cassette at `path`, creating it if needed.
+    ///
+    /// Replaces an earlier [`replay_cassette`](Self::replay_cassette). See
+    /// [`cassette`](crate::bitcoin_de_trading_api_sdk_v4::cassette) for the format.
+    pub fn record_cassette(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cassette = Some(CassetteSource::Record(path.into()));
+        self
+    }
+
+    /// Serves responses from a cassette written by [`record_cassette`](Self::record_cassette)
+    /// instead of sending requests.
+    ///
+    /// Each request is answered by the first unused entry with the same method name and
+    /// parameters; `Error::Cassette` is returned if there is none left. Retries, pagination
+    /// and the credit tracking play out as recorded. Replaces an earlier `record_cassette`.
+    pub fn replay_cassette(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cassette = Some(CassetteSource::Replay(path.into()));
+        self
+    }
+
+    /// Returns true if any setting was given that only applies to a client created by the builder.
+    fn has_http_settings(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        let native = self.timeout.is_some() || self.connect_timeout.is_some() || self.proxy.is_some();
+        #[cfg(target_arch = "wasm32")]
+        let native = false;
+        native || self.user_agent.is_some()
+    }
+
+    /// Validates the configuration and creates the client.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Configuration` - if the API key or secret is missing, or a custom `client`
+    ///   was combined with settings that only apply to a builder-created client.
+    /// * `Error::Url` - if the base URL cannot be parsed.
+    /// * `Error::InvalidHeaderValue` - if the user agent is not a valid header value.
+    /// * `Error::Reqwest` - if the HTTP client cannot be created.
+    /// * `Error::Io` or `Error::Cassette` - if the cassette cannot be opened or read.
+    pub fn build(mut self) -> Result<TradingApiSdkV4, Error> {
+        if self.client.is_some() && self.has_http_settings() {
+            return Err(Error::Configuration(
+                "timeout, connect_timeout, user_agent and proxy cannot be combined with a custom client".to_string(),
+            ));
+        }
+
+        let (api_credentials, base_url) = self.take_credentials_and_base_url()?;
+
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut client_builder = Client::builder();
+                if let Some(user_agent) = self.user_agent {
+                    let mut headers = HeaderMap::new();
+                    headers.insert(USER_AGENT, HeaderValue::from_str(&user_agent)?);
+                    client_builder = client_builder.default_headers(headers);
+                }
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    if let Some(timeout) = self.timeout {
+                        client_builder = client_builder.timeout(timeout);
+                    }
+                    if let Some(connect_timeout) = self.connect_timeout {
+                        client_builder = client_builder.connect_timeout(connect_timeout);
+                    }
+                    if let Some(proxy) = self.proxy {
+                        client_builder = client_builder.proxy(proxy);
+                    }
+                }
+                client_builder.build()?
+            }
+        };
+
+        Ok(TradingApiSdkV4 {
+            api_credentials,
+            method_settings: &METHOD_SETTINGS,
+            client: Arc::new(client),
+            base_url,
+            retry_policy: self.retry_policy,
+            nonce_provider: self.nonce_provider.unwrap_or_else(|| Arc::new(AtomicNonceProvider::new())),
+            credit_tracker: CreditTracker::new(self.credit_floor),
+            cassette: self.cassette.map(Cassette::open).transpose()?,
+        })
+    }
+
+    /// Validates the configuration and creates a blocking client.
+    ///
+    /// Takes the same settings as [`build`](Self::build), except that a custom async
+    /// `reqwest::Client` cannot be used. Without a `timeout`, the `reqwest::blocking`
+    /// default of 30 seconds applies.
+    ///
+    /// # Errors
+    ///
+    /// The same as [`build`](Self::build); `Error::Configuration` if a custom `client` was set.
+    #[cfg(feature = "blocking")]
+    pub fn build_blocking(mut self) -> Result<BlockingTradingApiSdkV4, Error> {
+        if self.client.is_some() {
+            return Err(Error::Configuration("a custom async client cannot be used by the blocking client".to_string()));
+        }
+
+        let (api_credentials, base_url) = self.take_credentials_and_base_url()?;
+
+        let mut client_builder = reqwest::blocking::Client::builder();
+        if let Some(user_agent) = self.user_agent {
+            let mut headers = HeaderMap::new();
+            headers.insert(USER_AGENT, HeaderValue::from_str(&user_agent)?);
+            client_builder = client_builder.default_headers(headers);
+        }
+        if let Some(timeout) = self.timeout {
+            client_builder = client_builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        Ok(BlockingTradingApiSdkV4 {
+            api_credentials,
+            method_settings: &METHOD_SETTINGS,
+            client: Arc::new(client_builder.build()?),
+            base_url,
+            retry_policy: self.retry_policy,
+            nonce_provider: self.nonce_provider.unwrap_or_else(|| Arc::new(AtomicNonceProvider::new())),
+            credit_tracker: CreditTracker::new(self.credit_floor),
+            cassette: self.cassette.map(Cassette::open).transpose()?,
+        })
+    }
+
+    /// Takes the credentials and validates the base URL, shared by both clients.
+    fn take_credentials_and_base_url(&mut self) -> Result<(ApiCredentials, String), Error> {
+        let api_key = self.api_key.take().ok_or_else(|| Error::Configuration("API key is missing".to_string()))?;
+        let api_secret = self.api_secret.take().ok_or_else(|| Error::Configuration("API secret is missing".to_string()))?;
+
+        // Validate the base URL early, so misconfiguration does not surface on the first request
+        let base_url = self.base_url.as_deref().unwrap_or(API_BASE_URI).trim_end_matches('/').to_string();
+        Url::parse(&base_url)?;
+
+        Ok((ApiCredentials::new(api_key, api_secret), base_url))
+    }
+}
@@ -0,0 +1,226 @@
+// bitcoin_de_trading_api_sdk_v4/cassette.rs
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::bitcoin_de_trading_api_sdk_v4::credits;
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::{
+    CREATE_OUTGOING_ADDRESS_PARAMETER_COMMENT, CREATE_OUTGOING_ADDRESS_PARAMETER_RECIPIENT_ADDRESS,
+    CREATE_OUTGOING_ADDRESS_PARAMETER_RECIPIENT_PURPOSE, CREATE_WITHDRAWAL_PARAMETER_ADDRESS,
+};
+use crate::bitcoin_de_trading_api_sdk_v4::trading_api_sdk_v4::PreparedRequest;
+
+/// Parameters whose values are replaced by `[REDACTED]` in a cassette, as they identify
+/// wallets or bank transfers of the account.
+pub const REDACTED_PARAMETERS: &[&str] = &[
+    CREATE_WITHDRAWAL_PARAMETER_ADDRESS, // Also the pool address of `addToAddressPool`/`removeFromAddressPool`
+    CREATE_OUTGOING_ADDRESS_PARAMETER_RECIPIENT_ADDRESS,
+    CREATE_OUTGOING_ADDRESS_PARAMETER_RECIPIENT_PURPOSE,
+    CREATE_OUTGOING_ADDRESS_PARAMETER_COMMENT,
+];
+
+/// Response fields whose string values are replaced by `[REDACTED]` in a cassette, as they
+/// hold bank details and names of trading partners or of the account itself.
+///
+/// Only string values are replaced, so replayed bodies still deserialize.
+pub const REDACTED_BODY_FIELDS: &[&str] = &["trading_partner_information", "encrypted_information"];
+
+const REDACTED: &str = "[REDACTED]";
+
+/// A single recorded attempt of an API call, stored as one line of a JSONL cassette.
+///
+/// Credentials, nonces and signatures are never recorded, and the values of
+/// [`REDACTED_PARAMETERS`] and the strings within [`REDACTED_BODY_FIELDS`] are replaced by
+/// a placeholder.
+///
+/// # Examples
+///
+/// Turning a recorded session into regression fixtures for the response types:
+///
+/// ```no_run
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::cassette::read_cassette;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::responses::misc::ShowRatesResponse;
+///
+/// for entry in read_cassette("session.jsonl").unwrap() {
+///     if entry.method_name == "showRates" && entry.status == 200 {
+///         let rates: ShowRatesResponse = entry.parse().unwrap();
+///         println!("{}", rates.rates.rate_weighted);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteEntry {
+    /// The API method name, e.g. `showOrderbook`.
+    pub method_name: String,
+    /// Path, query and body parameters, with [`REDACTED_PARAMETERS`] redacted.
+    pub params: BTreeMap<String, String>,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The response body, with [`REDACTED_BODY_FIELDS`] redacted.
+    pub body: String,
+    /// When the response was received.
+    pub timestamp: DateTime<Utc>,
+    /// The remaining credits reported with the response, if any.
+    pub credits: Option<i32>,
+}
+
+impl CassetteEntry {
+    /// Deserializes the recorded body, e.g. into a type from `responses`.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        Ok(serde_json::from_str(&self.body)?)
+    }
+}
+
+/// Reads all entries of a JSONL cassette written by a recording client.
+///
+/// Empty lines are skipped.
+///
+/// # Errors
+///
+/// * `Error::Io` - if the file cannot be read.
+/// * `Error::Cassette` - if a line is not a valid entry.
+pub fn read_cassette(path: impl AsRef<Path>) -> Result<Vec<CassetteEntry>, Error> {
+    let path = path.as_ref();
+    let mut entries = Vec::new();
+    for (index, line) in BufReader::new(File::open(path)?).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line)
+            .map_err(|e| Error::Cassette(format!("{}:{}: {}", path.display(), index + 1, e)))?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Where a client records to or replays from, as configured on the builder.
+#[derive(Debug)]
+pub(crate) enum CassetteSource {
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+/// The recording or replaying cassette of a client.
+#[derive(Debug)]
+pub(crate) enum Cassette {
+    /// Appends every response to the file.
+    Record(Mutex<File>),
+    /// Serves the recorded responses, each at most once.
+    Replay(Mutex<Vec<Option<CassetteEntry>>>),
+}
+
+impl Cassette {
+    /// Opens the file for appending, or reads all entries to replay.
+    pub(crate) fn open(source: CassetteSource) -> Result<Self, Error> {
+        match source {
+            CassetteSource::Record(path) => {
+                let file = OpenOptions::new().create(true).append(true).open(path)?;
+                Ok(Cassette::Record(Mutex::new(file)))
+            }
+            CassetteSource::Replay(path) => {
+                let entries = read_cassette(path)?.into_iter().map(Some).collect();
+                Ok(Cassette::Replay(Mutex::new(entries)))
+            }
+        }
+    }
+
+    /// Appends a response to a recording cassette, a no-op when replaying.
+    ///
+    /// A failed write is logged instead of failing the request, which already went through.
+    pub(crate) fn record(&self, request: &PreparedRequest, status_code: StatusCode, response_text: &str) {
+        let Cassette::Record(file) = self else {
+            return;
+        };
+        let entry = CassetteEntry {
+            method_name: request.method_name.to_string(),
+            params: redacted_params(request),
+            status: status_code.as_u16(),
+            body: redacted_body(response_text),
+            timestamp: Utc::now(),
+            credits: credits::reported_credits(response_text),
+        };
+        let result = serde_json::to_string(&entry).map_err(Error::from).and_then(|line| {
+            let mut file = file.lock().map_err(|_| Error::Other("Cassette lock poisoned".to_string()))?;
+            writeln!(file, "{}", line)?;
+            Ok(file.flush()?)
+        });
+        if let Err(error) = result {
+            tracing::warn!(parent: &request.span, %error, "failed to record response");
+        }
+    }
+
+    /// Takes the first unused entry with the method name and parameters of the request.
+    ///
+    /// Returns `None` when recording, so the request is sent.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Cassette` - if no matching entry is left.
+    pub(crate) fn replay(&self, request: &PreparedRequest) -> Result<Option<(StatusCode, String)>, Error> {
+        let Cassette::Replay(entries) = self else {
+            return Ok(None);
+        };
+        let params = redacted_params(request);
+        let mut entries = entries.lock().map_err(|_| Error::Other("Cassette lock poisoned".to_string()))?;
+        let entry = entries
+            .iter_mut()
+            .find(|entry| entry.as_ref().is_some_and(|e| e.method_name == request.method_name && e.params == params))
+            .and_then(Option::take)
+            .ok_or_else(|| Error::Cassette(format!("no recorded response left for {} {:?}", request.method_name, params)))?;
+        let status_code = StatusCode::from_u16(entry.status)
+            .map_err(|_| Error::Cassette(format!("invalid status {} recorded for {}", entry.status, entry.method_name)))?;
+        Ok(Some((status_code, entry.body)))
+    }
+}
+
+/// Returns the parameters of a request with the values of `REDACTED_PARAMETERS` replaced.
+fn redacted_params(request: &PreparedRequest) -> BTreeMap<String, String> {
+    request
+        .parameters
+        .iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_PARAMETERS.contains(&name.as_ref()) { REDACTED.to_string() } else { value.clone() };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Returns the response body with the strings within `REDACTED_BODY_FIELDS` replaced.
+///
+/// Bodies that are not JSON or contain none of the fields are kept as received.
+fn redacted_body(response_text: &str) -> String {
+    let Ok(mut body) = serde_json::from_str::<serde_json::Value>(response_text) else {
+        return response_text.to_string();
+    };
+    if redact_fields(&mut body, false) {
+        body.to_string()
+    } else {
+        response_text.to_string()
+    }
+}
+
+/// Replaces strings within `REDACTED_BODY_FIELDS`, or all strings if `inside` is set.
+///
+/// Returns whether anything was replaced.
+fn redact_fields(value: &mut serde_json::Value, inside: bool) -> bool {
+    match value {
+        serde_json::Value::String(text) if inside => {
+            *text = REDACTED.to_string();
+            true
+        }
+        serde_json::Value::Array(items) => items.iter_mut().fold(false, |redacted, item| redact_fields(item, inside) | redacted),
+        serde_json::Value::Object(fields) => fields.iter_mut().fold(false, |redacted, (name, field)| {
+            redact_fields(field, inside || REDACTED_BODY_FIELDS.contains(&name.as_str())) | redacted
+        }),
+        _ => false,
+    }
+}
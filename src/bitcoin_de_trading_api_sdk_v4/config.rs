@@ -1,12 +1,83 @@
 // bitcoin_de_trading_api_sdk_v4/config.rs
+use std::fmt;
+use std::path::Path;
+
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+/// Placeholder printed instead of credentials by the `Debug` implementations.
+const REDACTED: &str = "[REDACTED]";
+
+/// An API secret that is wiped from memory when dropped.
+///
+/// `Debug` prints a placeholder instead of the value, so a secret never ends up in logs
+/// or error messages by accident. Use [`expose_secret`](Self::expose_secret) to access it.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::config::ApiSecret;
+///
+/// let secret = ApiSecret::from("my_api_secret");
+/// assert_eq!(secret.expose_secret(), "my_api_secret");
+/// assert_eq!(format!("{:?}", secret), "ApiSecret([REDACTED])");
+/// ```
+#[derive(Clone)]
+pub struct ApiSecret(String);
+
+impl ApiSecret {
+    /// Wraps a secret.
+    pub fn new(secret: impl Into<String>) -> Self {
+        ApiSecret(secret.into())
+    }
+
+    /// Returns the secret, e.g. to sign a request.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ApiSecret {
+    fn from(secret: String) -> Self {
+        ApiSecret(secret)
+    }
+}
+
+impl From<&str> for ApiSecret {
+    fn from(secret: &str) -> Self {
+        ApiSecret(secret.to_string())
+    }
+}
+
+impl From<Zeroizing<String>> for ApiSecret {
+    /// Takes over the buffer, so the secret is not copied into memory that is never wiped.
+    fn from(mut secret: Zeroizing<String>) -> Self {
+        ApiSecret(std::mem::take(&mut *secret))
+    }
+}
+
+impl Drop for ApiSecret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl fmt::Debug for ApiSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ApiSecret").field(&format_args!("{}", REDACTED)).finish()
+    }
+}
+
 /// Represents the API credentials required for authentication with the Bitcoin.de API.
 ///
 /// This struct holds the API key and secret that are used to authenticate requests
-/// to the Bitcoin.de trading platform.
-///
-/// TODO Refactor to use a more secure and efficient data structure for storing API credentials.
-/// E.g. SecretBox
+/// to the Bitcoin.de trading platform. The secret is zeroized on drop and `Debug`
+/// redacts both values.
 ///
+/// Besides [`new`](Self::new), credentials can be loaded from the environment
+/// ([`from_env`](Self::from_env)) or from a file only readable by its owner
+/// ([`from_file`](Self::from_file)).
 pub struct ApiCredentials {
     /// The API key provided by Bitcoin.de for authentication.
     /// This key identifies the user account making the API requests.
@@ -14,5 +85,148 @@ pub struct ApiCredentials {
 
     /// The API secret provided by Bitcoin.de for authentication.
     /// This secret is used to sign API requests and should be kept secure.
-    pub api_secret: String,
-}
\ No newline at end of file
+    pub api_secret: ApiSecret,
+}
+
+impl ApiCredentials {
+    /// Creates credentials from an API key and secret.
+    pub fn new(api_key: impl Into<String>, api_secret: impl Into<ApiSecret>) -> Self {
+        ApiCredentials { api_key: api_key.into(), api_secret: api_secret.into() }
+    }
+
+    /// Loads the credentials from the `API_KEY` and `API_SECRET` environment variables.
+    ///
+    /// Instead of the value itself, each variable can name a file containing it via
+    /// `API_KEY_FILE` and `API_SECRET_FILE` (e.g. for Docker or Kubernetes secrets),
+    /// see [`env_credential`].
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Configuration` - if a credential is missing, set both directly and via
+    ///   `*_FILE`, or its file is accessible by other users.
+    /// * `Error::Io` - if a `*_FILE` cannot be read.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::from_env_vars("API_KEY", "API_SECRET")
+    }
+
+    /// Like [`from_env`](Self::from_env), with custom variable names (e.g. `BITCOIN_DE_API_KEY`).
+    pub fn from_env_vars(api_key_var: &str, api_secret_var: &str) -> Result<Self, Error> {
+        let missing = |var: &str| Error::Configuration(format!("{} or {}_FILE must be set", var, var));
+        let api_key = env_credential(api_key_var)?.ok_or_else(|| missing(api_key_var))?;
+        let api_secret = env_credential(api_secret_var)?.ok_or_else(|| missing(api_secret_var))?;
+        Ok(ApiCredentials::new(api_key.as_str(), api_secret))
+    }
+
+    /// Loads the credentials from a file with `API_KEY=...` and `API_SECRET=...` lines,
+    /// the format of `.env.sample`.
+    ///
+    /// Empty lines and lines starting with `#` are ignored, values may be quoted. On Unix,
+    /// the file must not be accessible by group or others (e.g. `chmod 600`).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Configuration` - if the file is accessible by other users, or a
+    ///   credential is missing.
+    /// * `Error::Io` - if the file cannot be read.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = read_credential_file(path)?;
+        let mut api_key = None;
+        let mut api_secret = None;
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = unquote(value.trim());
+            match name.trim() {
+                "API_KEY" => api_key = Some(value.to_string()),
+                "API_SECRET" => api_secret = Some(ApiSecret::from(value)),
+                _ => {}
+            }
+        }
+        let missing = |name: &str| Error::Configuration(format!("{} is missing in {}", name, path.display()));
+        Ok(ApiCredentials {
+            api_key: api_key.ok_or_else(|| missing("API_KEY"))?,
+            api_secret: api_secret.ok_or_else(|| missing("API_SECRET"))?,
+        })
+    }
+}
+
+impl fmt::Debug for ApiCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ApiCredentials")
+            .field("api_key", &format_args!("{}", REDACTED))
+            .field("api_secret", &format_args!("{}", REDACTED))
+            .finish()
+    }
+}
+
+/// Reads a credential from the environment variable `var`, or from the file named by
+/// `{var}_FILE`.
+///
+/// Returns `None` if neither is set. File contents are trimmed, so a trailing newline
+/// does not become part of the credential. The value is wiped from memory when dropped;
+/// convert it into an [`ApiSecret`] to keep it without a copy.
+///
+/// # Errors
+///
+/// * `Error::Configuration` - if both variables are set, or the file is accessible by
+///   group or others (Unix only).
+/// * `Error::Io` - if the file cannot be read.
+pub fn env_credential(var: &str) -> Result<Option<Zeroizing<String>>, Error> {
+    let file_var = format!("{}_FILE", var);
+    match (std::env::var(var).ok(), std::env::var_os(&file_var)) {
+        (Some(_), Some(_)) => Err(Error::Configuration(format!("only one of {} and {} may be set", var, file_var))),
+        (Some(value), None) => Ok(Some(Zeroizing::new(value))),
+        (None, Some(path)) => {
+            let mut value = read_credential_file(Path::new(&path))?;
+            // Trimmed in place, as a trimmed copy would not be wiped
+            let len = value.trim_end().len();
+            value.truncate(len);
+            let leading = value.len() - value.trim_start().len();
+            value.drain(..leading);
+            Ok(Some(value))
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Reads a file containing credentials after checking that only its owner can access it.
+fn read_credential_file(path: &Path) -> Result<Zeroizing<String>, Error> {
+    check_permissions(path)?;
+    Ok(Zeroizing::new(std::fs::read_to_string(path)?))
+}
+
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Result<(), Error> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = std::fs::metadata(path)?.permissions().mode();
+    if mode & 0o077 != 0 {
+        return Err(Error::Configuration(format!(
+            "{} is accessible by group or others (mode {:o}), restrict it with `chmod 600`",
+            path.display(),
+            mode & 0o777
+        )));
+    }
+    Ok(())
+}
+
+// Windows ACLs are not checked, the file is expected in the user's profile
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Strips one pair of matching single or double quotes.
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote).and_then(|rest| rest.strip_suffix(quote)) {
+            return inner;
+        }
+    }
+    value
+}
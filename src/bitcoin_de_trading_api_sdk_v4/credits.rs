@@ -0,0 +1,338 @@
+// bitcoin_de_trading_api_sdk_v4/credits.rs
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::retry;
+
+/// What the client does when a request would bring the estimated credit balance below the floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CreditFloorAction {
+    /// Delay the request until enough credits have regenerated, at most for `max_wait`.
+    Wait,
+    /// Fail immediately with `Error::CreditFloor`, without sending the request.
+    Fail,
+}
+
+/// A lower bound for the credit balance of the API key.
+///
+/// Every API method costs credits (see
+/// [`method_credits`](crate::bitcoin_de_trading_api_sdk_v4::method_settings::method_credits)),
+/// and the API rejects requests with `ERROR_CODE_INSUFFICIENT_CREDITS` once the balance is
+/// used up. Credits regenerate over time. The client estimates the balance from the
+/// `credits` reported with the last response, the cost of the requests sent since and the
+/// regeneration rate; with a floor configured, it holds back requests that would bring the
+/// estimate below the floor.
+///
+/// Keeping a few credits in reserve leaves room for urgent requests, e.g. deleting an
+/// order, while a bulk job is running. Until the first response has reported a balance,
+/// requests are sent without delay.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::credits::CreditFloor;
+///
+/// let client = TradingApiSdkV4::builder()
+///     .api_key("your_api_key")
+///     .api_secret("your_api_secret")
+///     .credit_floor(CreditFloor::wait(5).max_wait(Duration::from_secs(30)))
+///     .build()
+///     .expect("valid configuration");
+/// assert_eq!(client.credits(), None); // Nothing reported yet
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreditFloor {
+    floor: i32,
+    action: CreditFloorAction,
+    regeneration_per_second: f64,
+    max_wait: Duration,
+}
+
+impl CreditFloor {
+    /// Delays requests that would bring the balance below `floor` until enough credits have regenerated.
+    ///
+    /// Credits regenerate at most up to the highest balance ever reported, so requests that
+    /// would need more fail at once instead of waiting. Waiting requires the `tokio` feature
+    /// or the blocking client; without a timer, requests fail as with [`fail`](Self::fail).
+    pub fn wait(floor: i32) -> Self {
+        CreditFloor {
+            floor,
+            action: CreditFloorAction::Wait,
+            regeneration_per_second: 1.0,
+            max_wait: Duration::from_secs(60),
+        }
+    }
+
+    /// Rejects requests that would bring the balance below `floor` with `Error::CreditFloor`.
+    pub fn fail(floor: i32) -> Self {
+        CreditFloor { action: CreditFloorAction::Fail, ..Self::wait(floor) }
+    }
+
+    /// Sets the number of credits assumed to regenerate per second (default: 1).
+    ///
+    /// The regeneration rate is not part of the documented cost table, and the default is
+    /// an assumption. It only decides how long requests are held back between two responses,
+    /// as every response replaces the estimate with the balance the API reports; set the
+    /// rate observed for your API key to wait no longer than necessary.
+    pub fn regeneration_per_second(mut self, regeneration_per_second: f64) -> Self {
+        self.regeneration_per_second = regeneration_per_second.max(0.0);
+        self
+    }
+
+    /// Sets the longest time a single request is delayed before it fails with
+    /// `Error::CreditFloor` instead (default: 60 seconds).
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Returns the configured floor.
+    pub fn floor(&self) -> i32 {
+        self.floor
+    }
+
+    /// Returns the configured action.
+    pub fn action(&self) -> CreditFloorAction {
+        self.action
+    }
+}
+
+/// The `credits` field every response body carries, successful or not.
+#[derive(Deserialize)]
+struct ReportedCredits {
+    #[serde(default)]
+    credits: Option<i32>,
+}
+
+/// Extracts the credit balance from a response body, if it reports one.
+pub(crate) fn reported_credits(body: &str) -> Option<i32> {
+    serde_json::from_str::<ReportedCredits>(body).ok().and_then(|reported| reported.credits)
+}
+
+/// Returns the current time, or `None` where `Instant` is not available (wasm32).
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now() -> Option<Instant> {
+    Some(Instant::now())
+}
+
+/// `Instant::now` panics on wasm32, so the estimate does not regenerate there.
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn now() -> Option<Instant> {
+    None
+}
+
+/// Tracks the credit balance of a client and enforces its optional floor.
+#[derive(Debug, Default)]
+pub(crate) struct CreditTracker {
+    floor: Option<CreditFloor>,
+    state: Mutex<CreditState>,
+}
+
+#[derive(Debug, Default)]
+struct CreditState {
+    reported: Option<i32>,    // The balance reported with the last response
+    estimate: f64,            // The reported balance minus the cost of requests sent since, as of `updated`
+    updated: Option<Instant>, // When `estimate` was last set
+    max_seen: i32,            // The highest balance ever reported, an upper bound for regeneration
+}
+
+impl CreditState {
+    /// Returns the estimated balance including regeneration, or `None` if no balance was reported yet.
+    fn current(&self, regeneration_per_second: f64) -> Option<f64> {
+        self.reported?;
+        let elapsed = match (self.updated, now()) {
+            (Some(updated), Some(now)) => now.saturating_duration_since(updated).as_secs_f64(),
+            _ => 0.0,
+        };
+        Some((self.estimate + elapsed * regeneration_per_second).min(self.cap()))
+    }
+
+    /// Returns the balance the estimate can regenerate to.
+    fn cap(&self) -> f64 {
+        (self.max_seen as f64).max(self.estimate)
+    }
+}
+
+impl CreditTracker {
+    pub(crate) fn new(floor: Option<CreditFloor>) -> Self {
+        CreditTracker { floor, state: Mutex::default() }
+    }
+
+    fn regeneration_per_second(&self) -> f64 {
+        self.floor.as_ref().map_or(1.0, |floor| floor.regeneration_per_second)
+    }
+
+    /// Returns the balance reported with the last response.
+    pub(crate) fn reported(&self) -> Option<i32> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).reported
+    }
+
+    /// Returns the estimated current balance.
+    pub(crate) fn estimated(&self) -> Option<i32> {
+        let state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.current(self.regeneration_per_second()).map(|estimate| estimate.floor() as i32)
+    }
+
+    /// Stores the balance reported by the API.
+    pub(crate) fn record(&self, credits: i32) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        state.reported = Some(credits);
+        state.estimate = credits as f64;
+        state.updated = now();
+        state.max_seen = state.max_seen.max(credits);
+    }
+
+    /// Reserves `cost` credits for a request, waiting or failing according to the floor.
+    ///
+    /// The reservation lowers the estimate immediately, so concurrent requests queue up
+    /// behind each other instead of all seeing the same balance.
+    pub(crate) async fn acquire(&self, cost: i32) -> Result<(), Error> {
+        let mut waited = Duration::ZERO;
+        while let Some(wait) = self.reserve(cost, waited, retry::HAS_TIMER)? {
+            retry::sleep(wait).await;
+            waited += wait;
+        }
+        Ok(())
+    }
+
+    /// Like [`acquire`](Self::acquire), but blocks the current thread while waiting.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn acquire_blocking(&self, cost: i32) -> Result<(), Error> {
+        let mut waited = Duration::ZERO;
+        while let Some(wait) = self.reserve(cost, waited, true)? {
+            std::thread::sleep(wait);
+            waited += wait;
+        }
+        Ok(())
+    }
+
+    /// Tries to reserve `cost` credits.
+    ///
+    /// Returns `None` once reserved, or the time to wait before trying again. `waited` is the
+    /// time already waited for this request, `can_wait` whether the caller has a timer at all.
+    fn reserve(&self, cost: i32, waited: Duration, can_wait: bool) -> Result<Option<Duration>, Error> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(available) = state.current(self.regeneration_per_second()) else {
+            return Ok(None); // No balance known yet
+        };
+        let floor = match &self.floor {
+            Some(floor) if available < (floor.floor + cost) as f64 => floor,
+            _ => {
+                // Sent right away, but counted in the estimate even without a floor.
+                state.estimate = available - cost as f64;
+                state.updated = now();
+                return Ok(None);
+            }
+        };
+        let required = floor.floor + cost;
+        let error = Error::CreditFloor { available: available.floor() as i32, cost, floor: floor.floor };
+        if floor.action == CreditFloorAction::Fail || !can_wait || floor.regeneration_per_second <= 0.0 {
+            return Err(error);
+        }
+        if required as f64 > state.cap() {
+            return Err(error); // Regeneration stops below the required balance
+        }
+        let wait = Duration::from_secs_f64((required as f64 - available) / floor.regeneration_per_second);
+        if waited + wait > floor.max_wait {
+            return Err(error);
+        }
+        Ok(Some(wait))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Moves the time of the last estimate back, as if `seconds` had passed since.
+    fn age(tracker: &CreditTracker, seconds: u64) {
+        let mut state = tracker.state.lock().unwrap();
+        state.updated = state.updated.map(|updated| updated - Duration::from_secs(seconds));
+    }
+
+    #[test]
+    fn reserve_without_floor_lowers_the_estimate() {
+        let tracker = CreditTracker::new(None);
+        assert_eq!(tracker.reserve(3, Duration::ZERO, true).unwrap(), None);
+        assert_eq!(tracker.estimated(), None); // Nothing reported yet
+
+        tracker.record(20);
+        assert_eq!(tracker.reserve(3, Duration::ZERO, true).unwrap(), None);
+        assert_eq!(tracker.reserve(2, Duration::ZERO, false).unwrap(), None);
+        assert_eq!(tracker.estimated(), Some(15));
+        assert_eq!(tracker.reported(), Some(20));
+
+        // Without a floor, requests are never held back, even below zero.
+        assert_eq!(tracker.reserve(30, Duration::ZERO, true).unwrap(), None);
+        assert_eq!(tracker.estimated(), Some(-15));
+    }
+
+    #[test]
+    fn estimate_regenerates_up_to_the_highest_reported_balance() {
+        let tracker = CreditTracker::new(Some(CreditFloor::fail(0).regeneration_per_second(2.0)));
+        tracker.record(10);
+        tracker.reserve(8, Duration::ZERO, true).unwrap();
+        assert_eq!(tracker.estimated(), Some(2));
+        age(&tracker, 3);
+        assert_eq!(tracker.estimated(), Some(8));
+        age(&tracker, 100);
+        assert_eq!(tracker.estimated(), Some(10));
+
+        // A new report replaces the estimate.
+        tracker.record(4);
+        assert_eq!(tracker.estimated(), Some(4));
+    }
+
+    #[test]
+    fn failing_floor_rejects_requests_below_the_floor() {
+        let tracker = CreditTracker::new(Some(CreditFloor::fail(5).regeneration_per_second(0.0)));
+        tracker.record(7);
+        assert_eq!(tracker.reserve(2, Duration::ZERO, true).unwrap(), None);
+        assert!(matches!(
+            tracker.reserve(1, Duration::ZERO, true),
+            Err(Error::CreditFloor { available: 5, cost: 1, floor: 5 })
+        ));
+        assert_eq!(tracker.estimated(), Some(5)); // Rejected requests cost nothing
+    }
+
+    #[test]
+    fn waiting_floor_returns_the_time_until_enough_credits_regenerated() {
+        let tracker = CreditTracker::new(Some(CreditFloor::wait(5).regeneration_per_second(2.0).max_wait(Duration::from_secs(10))));
+        tracker.record(20);
+        tracker.record(6);
+
+        // 8 credits are required, 2 are missing at 2 credits per second.
+        let wait = tracker.reserve(3, Duration::ZERO, true).unwrap().expect("has to wait");
+        assert!(wait > Duration::from_millis(900) && wait <= Duration::from_secs(1), "{:?}", wait);
+        assert_eq!(tracker.estimated(), Some(6));
+
+        // Beyond max_wait, or without a timer, the request fails instead.
+        assert!(matches!(tracker.reserve(3, Duration::from_secs(10), true), Err(Error::CreditFloor { .. })));
+        assert!(matches!(tracker.reserve(3, Duration::ZERO, false), Err(Error::CreditFloor { .. })));
+
+        age(&tracker, 1);
+        assert_eq!(tracker.reserve(3, Duration::from_secs(1), true).unwrap(), None);
+        assert_eq!(tracker.estimated(), Some(5));
+    }
+
+    #[test]
+    fn waiting_floor_fails_at_once_if_regeneration_cannot_reach_the_floor() {
+        let tracker = CreditTracker::new(Some(CreditFloor::wait(5).regeneration_per_second(2.0).max_wait(Duration::from_secs(60))));
+        tracker.record(10);
+        tracker.record(2);
+
+        // 11 credits are required, but the balance never regenerates beyond 10.
+        assert!(matches!(
+            tracker.reserve(6, Duration::ZERO, true),
+            Err(Error::CreditFloor { available: 2, cost: 6, floor: 5 })
+        ));
+        age(&tracker, 100);
+        assert!(matches!(tracker.reserve(6, Duration::ZERO, true), Err(Error::CreditFloor { available: 10, .. })));
+        assert_eq!(tracker.reserve(5, Duration::ZERO, true).unwrap(), None);
+    }
+}
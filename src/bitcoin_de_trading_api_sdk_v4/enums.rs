@@ -1,5 +1,4 @@
 // bitcoin_de_trading_api_sdk_v4/enums.rs
-#![allow(unused_doc_comments)]
 /// A macro that generates an enum with string representation capabilities.
 ///
 /// This macro creates an enum with the specified name and variants, and automatically
@@ -9,19 +8,28 @@
 ///
 /// # Parameters
 ///
+/// * `$meta` - Attributes of the enum, usually its doc comment.
 /// * `$name` - The identifier for the enum name.
 /// * `$variant => $str_val` - Pairs of enum variant names and their corresponding string values.
 ///   Multiple pairs can be specified, separated by commas.
 ///
 /// # Generated Methods
 ///
-/// The macro generates the following methods for the enum:
+/// The macro generates the following items for the enum:
+/// * `ALL: &'static [Self]` - All variants, in declaration order.
+/// * `iter() -> impl Iterator<Item = Self>` - Iterates over all variants.
 /// * `as_str(&self) -> &'static str` - Returns the string representation of the enum variant.
 /// * `to_string(&self) -> String` - Returns the string representation as an owned String.
 ///
+/// It also implements `FromStr` and `TryFrom<&str>`, which match the string representation
+/// case-insensitively (the API uses lower case trading pairs and currencies), and serde
+/// `Serialize`/`Deserialize`. Values serialize as the lower case string representation
+/// (e.g. "btceur"), as sent by the API. Deserialization also accepts integers, as the API
+/// sends integer codes like `payment_option` as JSON numbers.
 
 macro_rules! generate_enum {
-    ($name:ident, $($variant:ident => $str_val:expr),*) => {
+    ($(#[$meta:meta])* $name:ident, $($variant:ident => $str_val:expr),*) => {
+        $(#[$meta])*
         #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
         pub enum $name {
             $(
@@ -30,6 +38,18 @@ macro_rules! generate_enum {
         }
 
         impl $name {
+            /// All variants, in declaration order.
+            pub const ALL: &'static [$name] = &[
+                $(
+                    $name::$variant,
+                )*
+            ];
+
+            /// Returns an iterator over all variants, in declaration order.
+            pub fn iter() -> std::iter::Copied<std::slice::Iter<'static, $name>> {
+                Self::ALL.iter().copied()
+            }
+
             // Generate as_str method for the enum
             pub fn as_str(&self) -> &'static str {
                 match self {
@@ -50,57 +70,190 @@ macro_rules! generate_enum {
                 write!(f, "{}", self.as_str())
             }
         }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::iter()
+                    .find(|variant| variant.as_str().eq_ignore_ascii_case(s))
+                    .ok_or_else(|| crate::bitcoin_de_trading_api_sdk_v4::errors::Error::Other(
+                        format!("Invalid {}: {}", stringify!($name), s),
+                    ))
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+            fn try_from(s: &str) -> Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.as_str().to_ascii_lowercase())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        write!(f, "one of {:?}", $name::ALL.iter().map($name::as_str).collect::<Vec<_>>())
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<$name, E> {
+                        value.parse().map_err(|_| E::invalid_value(serde::de::Unexpected::Str(value), &self))
+                    }
+
+                    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<$name, E> {
+                        value.to_string().parse().map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+                    }
+
+                    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<$name, E> {
+                        value.to_string().parse().map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+                    }
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
+        }
     };
 }
 
-/// Represents the type of order in a trading operation.
+/// A macro that generates an enum for the integer codes the API uses for states and methods.
 ///
-/// This enum defines the possible order types that can be used when placing
-/// trades on the Bitcoin.de platform. It distinguishes between buy and sell orders.
+/// Unlike `generate_enum!`, the generated enum has an additional `Unknown(i32)` variant,
+/// so codes introduced by the API later still deserialize instead of failing the whole response.
 ///
-/// # Variants
+/// # Parameters
 ///
-/// * `Buy` - Represents a buy order, where the user wants to purchase an asset.
-/// * `Sell` - Represents a sell order, where the user wants to sell an asset.
+/// * `$meta` - Attributes of the enum, usually its doc comment.
+/// * `$name` - The identifier for the enum name.
+/// * `$variant => $code` - Pairs of enum variant names and their integer codes.
 ///
-/// # Examples
+/// # Generated Methods
 ///
-/// ```
-/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::OrderType;
+/// * `from_code(i32) -> Self` - Returns the variant for a code, `Unknown(code)` for unknown codes.
+/// * `code(&self) -> i32` - Returns the integer code of the variant.
 ///
-/// let order_type = OrderType::Buy;
-/// assert_eq!(order_type.as_str(), "buy");
-/// ```
-generate_enum!(OrderType,
+/// The enum (de)serializes as the integer code, and `Display` prints the code, as used in
+/// query parameters.
+macro_rules! generate_int_enum {
+    ($(#[$meta:meta])* $name:ident, $($variant:ident => $code:literal),*) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+        pub enum $name {
+            $(
+                $variant,
+            )*
+            Unknown(i32),
+        }
+
+        impl $name {
+            /// Returns the variant for an integer code, `Unknown(code)` for codes not known to the SDK.
+            pub fn from_code(code: i32) -> Self {
+                match code {
+                    $(
+                        $code => $name::$variant,
+                    )*
+                    other => $name::Unknown(other),
+                }
+            }
+
+            /// Returns the integer code used by the API.
+            pub fn code(&self) -> i32 {
+                match self {
+                    $(
+                        $name::$variant => $code,
+                    )*
+                    $name::Unknown(code) => *code,
+                }
+            }
+        }
+
+        impl From<i32> for $name {
+            fn from(code: i32) -> Self {
+                $name::from_code(code)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.code())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i32(self.code())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                i32::deserialize(deserializer).map($name::from_code)
+            }
+        }
+    };
+}
+
+generate_enum!(
+    /// Represents the type of order in a trading operation.
+    ///
+    /// This enum defines the possible order types that can be used when placing
+    /// trades on the Bitcoin.de platform. It distinguishes between buy and sell orders.
+    ///
+    /// # Variants
+    ///
+    /// * `Buy` - Represents a buy order, where the user wants to purchase an asset.
+    /// * `Sell` - Represents a sell order, where the user wants to sell an asset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::OrderType;
+    ///
+    /// let order_type = OrderType::Buy;
+    /// assert_eq!(order_type.as_str(), "buy");
+    /// ```
+    OrderType,
     Buy => "buy",
     Sell => "sell"
 );
 
-/// Represents the available trading pairs on the Bitcoin.de platform.
-///
-/// This enum defines all supported cryptocurrency trading pairs that can be used
-/// for trading operations. Each variant represents a specific trading pair in the
-/// format of base currency followed by quote currency (e.g., BTCEUR for Bitcoin/Euro).
-///
-/// # Variants
-///
-/// The enum includes various combinations of cryptocurrencies paired with:
-/// - EUR (Euro)
-/// - BTC (Bitcoin)
-/// - ETH (Ethereum)
-/// - BCH (Bitcoin Cash)
-/// - BNB (Binance Coin)
-/// - CHF (Swiss Franc)
-///
-/// # Examples
-///
-/// ```
-/// use bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
-///
-/// let pair = TradingPair::BTCEUR;
-/// assert_eq!(pair.as_str(), "BTCEUR");
-/// ```
-generate_enum!(TradingPair,
+generate_enum!(
+    /// Represents the available trading pairs on the Bitcoin.de platform.
+    ///
+    /// This enum defines all supported cryptocurrency trading pairs that can be used
+    /// for trading operations. Each variant represents a specific trading pair in the
+    /// format of base currency followed by quote currency (e.g., BTCEUR for Bitcoin/Euro).
+    ///
+    /// # Variants
+    ///
+    /// The enum includes various combinations of cryptocurrencies paired with:
+    /// - EUR (Euro)
+    /// - BTC (Bitcoin)
+    /// - ETH (Ethereum)
+    /// - BCH (Bitcoin Cash)
+    /// - BNB (Binance Coin)
+    /// - CHF (Swiss Franc)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+    ///
+    /// let pair = TradingPair::BTCEUR;
+    /// assert_eq!(pair.as_str(), "BTCEUR");
+    /// ```
+    TradingPair,
     BTCEUR => "BTCEUR",
     BCHEUR => "BCHEUR",
     ETHBTC => "ETHBTC",
@@ -171,33 +324,35 @@ generate_enum!(TradingPair,
     USDCEUR => "USDCEUR"
 );
 
-/// Represents the available currencies on the Bitcoin.de platform.
-///
-/// This enum defines all supported cryptocurrencies and fiat currencies that can be used
-/// in trading operations. Each variant represents a specific currency with its standard
-/// ticker symbol.
-///
-/// # Variants
-///
-/// The enum includes various cryptocurrencies such as:
-/// - BTC (Bitcoin)
-/// - ETH (Ethereum)
-/// - XRP (Ripple)
-///
-/// And fiat currencies:
-/// - EUR (Euro)
-/// - USD (US Dollar)
-/// - CHF (Swiss Franc)
-///
-/// # Examples
-///
-/// ```
-/// use bitcoin_de_trading_api_sdk_v4::enums::Currency;
-///
-/// let currency = Currency::BTC;
-/// assert_eq!(currency.as_str(), "BTC");
-/// ```
-generate_enum!(Currency,
+generate_enum!(
+    /// Represents the available currencies on the Bitcoin.de platform.
+    ///
+    /// This enum defines all supported cryptocurrencies and fiat currencies that can be used
+    /// in trading operations. Each variant represents a specific currency with its standard
+    /// ticker symbol.
+    ///
+    /// # Variants
+    ///
+    /// The enum includes various cryptocurrencies such as:
+    /// - BTC (Bitcoin)
+    /// - ETH (Ethereum)
+    /// - XRP (Ripple)
+    /// - USDT (Tether) and USDC (USD Coin)
+    ///
+    /// And fiat currencies:
+    /// - EUR (Euro)
+    /// - USD (US Dollar)
+    /// - CHF (Swiss Franc)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::Currency;
+    ///
+    /// let currency = Currency::BTC;
+    /// assert_eq!(currency.as_str(), "BTC");
+    /// ```
+    Currency,
     BTC => "BTC",
     BCH => "BCH",
     ETH => "ETH",
@@ -223,93 +378,209 @@ generate_enum!(Currency,
     SOL => "SOL",
     DOT => "DOT",
     UNI => "UNI",
+    BTG => "BTG",
+    DOGE => "DOGE",
+    USDT => "USDT",
+    USDC => "USDC",
     CHF => "CHF",
     USD => "USD"
 );
-const ALL_TRADING_PAIRS: &[TradingPair] = &[
-    TradingPair::BTCEUR,
-    TradingPair::BCHEUR,
-    TradingPair::ETHBTC,
-    TradingPair::ETHEUR,
-    TradingPair::LTCEUR,
-    TradingPair::LTCBTC,
-    TradingPair::XRPEUR,
-    TradingPair::XRPBTC,
-    TradingPair::EOSEUR,
-    TradingPair::EOSBTC,
-    TradingPair::BNBEUR,
-    TradingPair::BNBBTC,
-    TradingPair::XMREUR,
-    TradingPair::XMRBTC,
-    TradingPair::TRXEUR,
-    TradingPair::TRXBTC,
-    TradingPair::ETCBTC,
-    TradingPair::ETCEUR,
-    TradingPair::DASHEUR,
-    TradingPair::DASHBTC,
-    TradingPair::ZECEUR,
-    TradingPair::ZECBTC,
-    TradingPair::REPEUR,
-    TradingPair::REPBTC,
-    TradingPair::BATEUR,
-    TradingPair::BATBTC,
-    TradingPair::AIDUSDEUR,
-    TradingPair::AIDUSDBTC,
-    TradingPair::XLMEUR,
-    TradingPair::XLMBTC,
-    TradingPair::AVAXEUR,
-    TradingPair::AVAXBTC,
-    TradingPair::ADAEUR,
-    TradingPair::ADABTC,
-    TradingPair::GRTEUR,
-    TradingPair::GRTBTC,
-    TradingPair::LINKEUR,
-    TradingPair::LINKBTC,
-    TradingPair::MATICBTC,
-    TradingPair::MATICEUR,
-    TradingPair::SOLEUR,
-    TradingPair::SOLBTC,
-    TradingPair::DOTEUR,
-    TradingPair::DOTBTC,
-    TradingPair::UNIEUR,
-    TradingPair::UNIBTC,
-    TradingPair::XMRETH,
-    TradingPair::XRPETH,
-    TradingPair::LTCETH,
-    TradingPair::DASHETH,
-    TradingPair::ZECETH,
-    TradingPair::REPBCH,
-    TradingPair::BATBCH,
-    TradingPair::XLMBCH,
-    TradingPair::ADAETH,
-    TradingPair::GRTETH,
-    TradingPair::LINKETH,
-    TradingPair::MATICETH,
-    TradingPair::SOLETH,
-    TradingPair::DOTETH,
-    TradingPair::UNIBNB,
-    TradingPair::EURCHF,
-    TradingPair::BTCCHF,
-    TradingPair::ETHCHF,
-    TradingPair::BTGEUR,
-    TradingPair::DOGEEUR,
-    TradingPair::USDTEUR,
-    TradingPair::USDCEUR,
-];
+
+generate_enum!(
+    /// Represents the trust level of a Bitcoin.de user.
+    ///
+    /// Used as `min_trust_level` requirement when creating orders or filtering the orderbook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TrustLevel;
+    ///
+    /// assert_eq!(TrustLevel::Gold.as_str(), "gold");
+    /// ```
+    TrustLevel,
+    Bronze => "bronze",
+    Silver => "silver",
+    Gold => "gold",
+    Platinum => "platinum"
+);
+
+generate_enum!(
+    /// Represents the accepted payment options of an order.
+    ///
+    /// The string value is the integer code expected by the API (`payment_option`).
+    ///
+    /// # Variants
+    ///
+    /// * `ExpressOnly` - Only Express trades (1).
+    /// * `SepaOnly` - Only SEPA bank transfers (2).
+    /// * `ExpressAndSepa` - Express trades and SEPA bank transfers (3).
+    PaymentOption,
+    ExpressOnly => "1",
+    SepaOnly => "2",
+    ExpressAndSepa => "3"
+);
+
+generate_enum!(
+    /// Represents the SEPA option of an order (`sepa_option`).
+    ///
+    /// The string value is the integer code expected by the API.
+    ///
+    /// # Variants
+    ///
+    /// * `Standard` - Regular SEPA bank transfer (0).
+    /// * `Instant` - SEPA instant credit transfer (1).
+    SepaOption,
+    Standard => "0",
+    Instant => "1"
+);
+
+generate_int_enum!(
+    /// Represents the state of one of your orders (`state` in `showMyOrders` and `showMyOrderDetails`).
+    ///
+    /// # Variants
+    ///
+    /// * `Expired` - The order reached its end date (-2).
+    /// * `Cancelled` - The order was deleted (-1).
+    /// * `Pending` - The order is open in the orderbook (0).
+    /// * `Executed` - The order was traded completely (1).
+    /// * `Unknown` - A code not known to this version of the SDK.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::OrderState;
+    ///
+    /// assert_eq!(OrderState::from_code(-1), OrderState::Cancelled);
+    /// assert_eq!(OrderState::from_code(7), OrderState::Unknown(7));
+    /// ```
+    OrderState,
+    Expired => -2,
+    Cancelled => -1,
+    Pending => 0,
+    Executed => 1
+);
+
+generate_int_enum!(
+    /// Represents the state of a trade (`state` in `showMyTrades` and `showMyTradeDetails`).
+    ///
+    /// # Variants
+    ///
+    /// * `Cancelled` - The trade was cancelled (-1).
+    /// * `Pending` - The trade is waiting for payment or confirmation (0).
+    /// * `Successful` - The trade was completed (1).
+    /// * `Unknown` - A code not known to this version of the SDK.
+    TradeState,
+    Cancelled => -1,
+    Pending => 0,
+    Successful => 1
+);
+
+generate_int_enum!(
+    /// Represents the state of a withdrawal (`state` in `showWithdrawal` and `showWithdrawals`).
+    ///
+    /// # Variants
+    ///
+    /// * `Pending` - The withdrawal has not been transferred yet (0).
+    /// * `Transferred` - The withdrawal was sent to the network (1).
+    /// * `Cancelled` - The withdrawal was cancelled (2).
+    /// * `Unknown` - A code not known to this version of the SDK.
+    WithdrawalState,
+    Pending => 0,
+    Transferred => 1,
+    Cancelled => 2
+);
+
+generate_int_enum!(
+    /// Represents the state of a deposit (`state` in `showDeposit` and `showDeposits`).
+    ///
+    /// # Variants
+    ///
+    /// * `Pending` - The deposit does not have enough confirmations yet (1).
+    /// * `Credited` - The deposit was credited to the account (2).
+    /// * `Unknown` - A code not known to this version of the SDK.
+    DepositState,
+    Pending => 1,
+    Credited => 2
+);
+
+generate_int_enum!(
+    /// Represents how a trade is paid (`payment_method` in `showMyTrades` and `showMyTradeDetails`).
+    ///
+    /// # Variants
+    ///
+    /// * `Sepa` - SEPA bank transfer (1).
+    /// * `Express` - Express trading via the connected bank account (2).
+    /// * `Unknown` - A code not known to this version of the SDK.
+    PaymentMethod,
+    Sepa => 1,
+    Express => 2
+);
+
+impl Currency {
+    /// Returns true for the fiat currencies EUR, CHF and USD.
+    pub fn is_fiat(&self) -> bool {
+        self.info().is_fiat
+    }
+
+    /// Returns the number of decimal places the API accepts for amounts and prices in
+    /// this currency: 2 for fiat currencies (cents), 8 for cryptocurrencies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::Currency;
+    ///
+    /// assert_eq!(Currency::EUR.decimals(), 2);
+    /// assert_eq!(Currency::BTC.decimals(), 8);
+    /// ```
+    pub fn decimals(&self) -> u32 {
+        self.info().decimals
+    }
+}
+
 impl TradingPair {
-    /// Converts a string slice to a TradingPair enum variant using iteration.
-    /// Returns an Error if the string does not match any variant.
-    pub fn from_str(s: &str) -> Result<Self, crate::bitcoin_de_trading_api_sdk_v4::errors::Error> { // Return our custom Error
-        let lower_s = s.to_ascii_lowercase(); // Convert input string to lowercase
-
-        // Iterate over all variants, convert their string value to lowercase, and compare
-        for pair in ALL_TRADING_PAIRS.iter() {
-            if pair.as_str().to_ascii_lowercase() == lower_s {
-                return Ok(*pair); // Return the found variant (TradingPair is Copy)
-            }
-        }
+    /// Returns the currency that is traded (the first currency of the pair).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{Currency, TradingPair};
+    ///
+    /// assert_eq!(TradingPair::DASHEUR.base(), Currency::DASH);
+    /// assert_eq!(TradingPair::MATICBTC.quote(), Currency::BTC);
+    /// ```
+    pub fn base(&self) -> Currency {
+        self.info().base
+    }
+
+    /// Returns the currency that is paid with (the second currency of the pair).
+    pub fn quote(&self) -> Currency {
+        self.info().quote
+    }
 
-        // If no match is found
-        Err(crate::bitcoin_de_trading_api_sdk_v4::errors::Error::Other(format!("Invalid trading pair: {}", s))) // Use a suitable error variant
+    /// Returns the trading pair of the given base and quote currency, or `None` if
+    /// Bitcoin.de does not offer it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{Currency, TradingPair};
+    ///
+    /// assert_eq!(TradingPair::from_currencies(Currency::USDT, Currency::EUR), Some(TradingPair::USDTEUR));
+    /// assert_eq!(TradingPair::from_currencies(Currency::EUR, Currency::BTC), None);
+    /// ```
+    pub fn from_currencies(base: Currency, quote: Currency) -> Option<Self> {
+        Self::iter().find(|pair| pair.base() == base && pair.quote() == quote)
     }
-}
\ No newline at end of file
+
+    /// Parses a trading pair case-insensitively.
+    ///
+    /// Kept for callers written before `TradingPair` implemented [`FromStr`](std::str::FromStr);
+    /// it shadows the trait method, so `TradingPair::from_str` resolves here either way.
+    #[deprecated(note = "use `str::parse` or the `FromStr` implementation")]
+    #[allow(clippy::should_implement_trait)] // The trait is implemented, this only forwards to it
+    pub fn from_str(s: &str) -> Result<Self, crate::bitcoin_de_trading_api_sdk_v4::errors::Error> {
+        s.parse()
+    }
+}
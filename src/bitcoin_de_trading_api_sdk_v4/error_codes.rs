@@ -0,0 +1,304 @@
+// bitcoin_de_trading_api_sdk_v4/error_codes.rs
+use crate::bitcoin_de_trading_api_sdk_v4::constants::*;
+
+/// Generates the `ApiErrorCode` enum from the `ERROR_CODE_*` constants.
+///
+/// Each variant maps to exactly one constant, so the integer values are defined in a
+/// single place (`constants.rs`). Codes not covered by a constant map to `Unknown`.
+macro_rules! api_error_codes {
+    ($($variant:ident => $constant:ident),* $(,)?) => {
+        /// Typed error code of an API error, mapped from the `ERROR_CODE_*` constants.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::error_codes::ApiErrorCode;
+        ///
+        /// let code = ApiErrorCode::from_code(22);
+        /// assert_eq!(code, ApiErrorCode::InsufficientCredits);
+        /// assert_eq!(code.code(), 22);
+        /// assert_eq!(ApiErrorCode::from_code(4711), ApiErrorCode::Unknown(4711));
+        /// ```
+        #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+        pub enum ApiErrorCode {
+            $(
+                $variant,
+            )*
+            /// A code not defined in `constants.rs`.
+            Unknown(i32),
+        }
+
+        impl ApiErrorCode {
+            /// Maps an integer error code as returned by the API to its variant.
+            pub fn from_code(code: i32) -> Self {
+                match code {
+                    $(
+                        $constant => ApiErrorCode::$variant,
+                    )*
+                    other => ApiErrorCode::Unknown(other),
+                }
+            }
+
+            /// Returns the integer error code.
+            pub fn code(&self) -> i32 {
+                match self {
+                    $(
+                        ApiErrorCode::$variant => $constant,
+                    )*
+                    ApiErrorCode::Unknown(code) => *code,
+                }
+            }
+        }
+    };
+}
+
+api_error_codes! {
+    MissingHeader => ERROR_CODE_MISSING_HEADER,
+    InactiveApiKey => ERROR_CODE_INACTIVE_API_KEY,
+    WrongSignature => ERROR_CODE_WRONG_SIGNATURE,
+    MissingPostParameter => ERROR_CODE_MISSING_POST_PARAMETER,
+    MissingGetParameter => ERROR_CODE_MISSING_GET_PARAMETER,
+    InvalidNonce => ERROR_CODE_INVALID_NONCE,
+    UnknownApiMethod => ERROR_CODE_UNKNOWN_API_METHOD,
+    PermissionDenied => ERROR_CODE_PERMISSION_DENIED,
+    TradingPairNotTradable => ERROR_CODE_TRADING_PAIR_NOT_TRADABLE,
+    InvalidOrderType => ERROR_CODE_INVALID_ORDER_TYPE,
+    InvalidAmount => ERROR_CODE_INVALID_AMOUNT,
+    InvalidPrice => ERROR_CODE_INVALID_PRICE,
+    OrderNotFound => ERROR_CODE_ORDER_NOT_FOUND,
+    TradeNotFound => ERROR_CODE_TRADE_NOT_FOUND,
+    WithdrawalNotFound => ERROR_CODE_WITHDRAWAL_NOT_FOUND,
+    DepositNotFound => ERROR_CODE_DEPOSIT_NOT_FOUND,
+    AddressNotFound => ERROR_CODE_ADDRESS_NOT_FOUND,
+    AmountTooLow => ERROR_CODE_AMOUNT_TOO_LOW,
+    AmountTooHigh => ERROR_CODE_AMOUNT_TOO_HIGH,
+    PriceTooLow => ERROR_CODE_PRICE_TOO_LOW,
+    PriceTooHigh => ERROR_CODE_PRICE_TOO_HIGH,
+    InsufficientCredits => ERROR_CODE_INSUFFICIENT_CREDITS,
+    InsufficientVolume => ERROR_CODE_INSUFFICIENT_VOLUME,
+    InvalidPaymentOption => ERROR_CODE_INVALID_PAYMENT_OPTION,
+    InvalidRating => ERROR_CODE_INVALID_RATING,
+    InvalidOrderId => ERROR_CODE_INVALID_ORDER_ID,
+    InvalidTradeId => ERROR_CODE_INVALID_TRADE_ID,
+    InvalidWithdrawalId => ERROR_CODE_INVALID_WITHDRAWAL_ID,
+    InvalidDepositId => ERROR_CODE_INVALID_DEPOSIT_ID,
+    InvalidAddressId => ERROR_CODE_INVALID_ADDRESS_ID,
+    InvalidCurrency => ERROR_CODE_INVALID_CURRENCY,
+    InvalidTradingPair => ERROR_CODE_INVALID_TRADING_PAIR,
+    InvalidOrderPaymentOptions => ERROR_CODE_INVALID_ORDER_PAYMENT_OPTIONS,
+    InvalidAccountLedgerType => ERROR_CODE_INVALID_ACCOUNT_LEDGER_TYPE,
+    InvalidTrustLevel => ERROR_CODE_INVALID_TRUST_LEVEL,
+    InvalidTradeState => ERROR_CODE_INVALID_TRADE_STATE,
+    InvalidOrderState => ERROR_CODE_INVALID_ORDER_STATE,
+    InvalidWithdrawalState => ERROR_CODE_INVALID_WITHDRAWAL_STATE,
+    InvalidDepositState => ERROR_CODE_INVALID_DEPOSIT_STATE,
+    InvalidPaymentMethod => ERROR_CODE_INVALID_PAYMENT_METHOD,
+    InvalidWithdrawalRejectReason => ERROR_CODE_INVALID_WITHDRAWAL_REJECT_REASON,
+    InvalidDepositRejectReason => ERROR_CODE_INVALID_DEPOSIT_REJECT_REASON,
+    InvalidAddressPoolState => ERROR_CODE_INVALID_ADDRESS_POOL_STATE,
+    InvalidOutgoingAddressState => ERROR_CODE_INVALID_OUTGOING_ADDRESS_STATE,
+    AddressPoolIsEmpty => ERROR_CODE_ADDRESS_POOL_IS_EMPTY,
+    NoNewAddressCreated => ERROR_CODE_NO_NEW_ADDRESS_CREATED,
+    InvalidRecipientAddress => ERROR_CODE_INVALID_RECIPIENT_ADDRESS,
+    InvalidRecipientPurpose => ERROR_CODE_INVALID_RECIPIENT_PURPOSE,
+    InvalidComment => ERROR_CODE_INVALID_COMMENT,
+    InvalidNetworkFee => ERROR_CODE_INVALID_NETWORK_FEE,
+    InvalidVolumeCurrencyToPayAfterFee => ERROR_CODE_INVALID_VOLUME_CURRENCY_TO_PAY_AFTER_FEE,
+    InvalidAmountCurrencyToTradeAfterFee => ERROR_CODE_INVALID_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE,
+    InvalidIsPaidFromCorrectBankAccount => ERROR_CODE_INVALID_IS_PAID_FROM_CORRECT_BANK_ACCOUNT,
+    PendingWithdrawalExists => ERROR_CODE_PENDING_WITHDRAWAL_EXISTS,
+    PendingDepositExists => ERROR_CODE_PENDING_DEPOSIT_EXISTS,
+    PendingOutgoingAddressExists => ERROR_CODE_PENDING_OUTGOING_ADDRESS_EXISTS,
+    PendingAddressPoolExists => ERROR_CODE_PENDING_ADDRESS_POOL_EXISTS,
+    InvalidEmailAddress => ERROR_CODE_INVALID_EMAIL_ADDRESS,
+    EmailAddressAlreadyExists => ERROR_CODE_EMAIL_ADDRESS_ALREADY_EXISTS,
+    EmailAddressNotFound => ERROR_CODE_EMAIL_ADDRESS_NOT_FOUND,
+    InvalidPassword => ERROR_CODE_INVALID_PASSWORD,
+    PasswordTooShort => ERROR_CODE_PASSWORD_TOO_SHORT,
+    PasswordTooWeak => ERROR_CODE_PASSWORD_TOO_WEAK,
+    InvalidUsername => ERROR_CODE_INVALID_USERNAME,
+    UsernameAlreadyExists => ERROR_CODE_USERNAME_ALREADY_EXISTS,
+    UsernameNotFound => ERROR_CODE_USERNAME_NOT_FOUND,
+    InvalidSessionId => ERROR_CODE_INVALID_SESSION_ID,
+    SessionIdExpired => ERROR_CODE_SESSION_ID_EXPIRED,
+    InvalidActivationCode => ERROR_CODE_INVALID_ACTIVATION_CODE,
+    ActivationCodeExpired => ERROR_CODE_ACTIVATION_CODE_EXPIRED,
+    InvalidResetPasswordCode => ERROR_CODE_INVALID_RESET_PASSWORD_CODE,
+    ResetPasswordCodeExpired => ERROR_CODE_RESET_PASSWORD_CODE_EXPIRED,
+    InvalidTwoFactorAuthenticationCode => ERROR_CODE_INVALID_TWO_FACTOR_AUTHENTICATION_CODE,
+    TwoFactorAuthenticationCodeExpired => ERROR_CODE_TWO_FACTOR_AUTHENTICATION_CODE_EXPIRED,
+    TwoFactorAuthenticationCodeIncorrect => ERROR_CODE_TWO_FACTOR_AUTHENTICATION_CODE_INCORRECT,
+    TwoFactorAuthenticationAlreadyEnabled => ERROR_CODE_TWO_FACTOR_AUTHENTICATION_ALREADY_ENABLED,
+    TwoFactorAuthenticationNotEnabled => ERROR_CODE_TWO_FACTOR_AUTHENTICATION_NOT_ENABLED,
+    InvalidPhoneNumber => ERROR_CODE_INVALID_PHONE_NUMBER,
+    PhoneNumberAlreadyExists => ERROR_CODE_PHONE_NUMBER_ALREADY_EXISTS,
+    PhoneNumberNotFound => ERROR_CODE_PHONE_NUMBER_NOT_FOUND,
+    InvalidBankAccount => ERROR_CODE_INVALID_BANK_ACCOUNT,
+    BankAccountAlreadyExists => ERROR_CODE_BANK_ACCOUNT_ALREADY_EXISTS,
+    BankAccountNotFound => ERROR_CODE_BANK_ACCOUNT_NOT_FOUND,
+    InvalidIban => ERROR_CODE_INVALID_IBAN,
+    InvalidBic => ERROR_CODE_INVALID_BIC,
+    InvalidAccountHolder => ERROR_CODE_INVALID_ACCOUNT_HOLDER,
+    InvalidPin => ERROR_CODE_INVALID_PIN,
+    PinIncorrect => ERROR_CODE_PIN_INCORRECT,
+    PinAlreadySet => ERROR_CODE_PIN_ALREADY_SET,
+    PinNotSet => ERROR_CODE_PIN_NOT_SET,
+    InvalidKycState => ERROR_CODE_INVALID_KYC_STATE,
+    KycStateNotFound => ERROR_CODE_KYC_STATE_NOT_FOUND,
+    InvalidLegalForm => ERROR_CODE_INVALID_LEGAL_FORM,
+    LegalFormNotFound => ERROR_CODE_LEGAL_FORM_NOT_FOUND,
+    InvalidGender => ERROR_CODE_INVALID_GENDER,
+    GenderNotFound => ERROR_CODE_GENDER_NOT_FOUND,
+    InvalidTitle => ERROR_CODE_INVALID_TITLE,
+    TitleNotFound => ERROR_CODE_TITLE_NOT_FOUND,
+    InvalidNationality => ERROR_CODE_INVALID_NATIONALITY,
+    NationalityNotFound => ERROR_CODE_NATIONALITY_NOT_FOUND,
+    InvalidBirthDate => ERROR_CODE_INVALID_BIRTH_DATE,
+    InvalidBirthCity => ERROR_CODE_INVALID_BIRTH_CITY,
+    InvalidBirthCountry => ERROR_CODE_INVALID_BIRTH_COUNTRY,
+    InvalidResidenceStreet => ERROR_CODE_INVALID_RESIDENCE_STREET,
+    InvalidResidenceZipCode => ERROR_CODE_INVALID_RESIDENCE_ZIP_CODE,
+    InvalidResidenceCity => ERROR_CODE_INVALID_RESIDENCE_CITY,
+    InvalidResidenceCountry => ERROR_CODE_INVALID_RESIDENCE_COUNTRY,
+    InvalidCompanyName => ERROR_CODE_INVALID_COMPANY_NAME,
+    InvalidCompanyRegisterNumber => ERROR_CODE_INVALID_COMPANY_REGISTER_NUMBER,
+    InvalidCompanyVatId => ERROR_CODE_INVALID_COMPANY_VAT_ID,
+    InvalidCompanyStreet => ERROR_CODE_INVALID_COMPANY_STREET,
+    InvalidCompanyZipCode => ERROR_CODE_INVALID_COMPANY_ZIP_CODE,
+    InvalidCompanyCity => ERROR_CODE_INVALID_COMPANY_CITY,
+    InvalidCompanyCountry => ERROR_CODE_INVALID_COMPANY_COUNTRY,
+    InvalidCompanyFoundationDate => ERROR_CODE_INVALID_COMPANY_FOUNDATION_DATE,
+    InvalidCompanyIndustrySector => ERROR_CODE_INVALID_COMPANY_INDUSTRY_SECTOR,
+    InvalidCompanyWebsite => ERROR_CODE_INVALID_COMPANY_WEBSITE,
+    InvalidCompanyPhoneNumber => ERROR_CODE_INVALID_COMPANY_PHONE_NUMBER,
+    InvalidTaxNumber => ERROR_CODE_INVALID_TAX_NUMBER,
+    InvalidArrayFormat => ERROR_CODE_INVALID_ARRAY_FORMAT,
+    InvalidTrustLevelRating => ERROR_CODE_INVALID_TRUST_LEVEL_RATING,
+    InvalidLanguage => ERROR_CODE_INVALID_LANGUAGE,
+    InvalidLocale => ERROR_CODE_INVALID_LOCALE,
+    InvalidDateFormat => ERROR_CODE_INVALID_DATE_FORMAT,
+    InvalidTimeFormat => ERROR_CODE_INVALID_TIME_FORMAT,
+    InvalidDatetimeFormat => ERROR_CODE_INVALID_DATETIME_FORMAT,
+    InvalidTimestampFormat => ERROR_CODE_INVALID_TIMESTAMP_FORMAT,
+    InvalidBooleanFormat => ERROR_CODE_INVALID_BOOLEAN_FORMAT,
+    InvalidIntegerFormat => ERROR_CODE_INVALID_INTEGER_FORMAT,
+    InvalidFloatFormat => ERROR_CODE_INVALID_FLOAT_FORMAT,
+    InvalidEnumValue => ERROR_CODE_INVALID_ENUM_VALUE,
+    InvalidJsonFormat => ERROR_CODE_INVALID_JSON_FORMAT,
+    InvalidXmlFormat => ERROR_CODE_INVALID_XML_FORMAT,
+    InvalidCsvFormat => ERROR_CODE_INVALID_CSV_FORMAT,
+    InvalidYamlFormat => ERROR_CODE_INVALID_YAML_FORMAT,
+    InvalidMimeType => ERROR_CODE_INVALID_MIME_TYPE,
+    InvalidFileExtension => ERROR_CODE_INVALID_FILE_EXTENSION,
+    InvalidFileSize => ERROR_CODE_INVALID_FILE_SIZE,
+    InvalidImageFormat => ERROR_CODE_INVALID_IMAGE_FORMAT,
+    InvalidImageResolution => ERROR_CODE_INVALID_IMAGE_RESOLUTION,
+    InvalidVideoFormat => ERROR_CODE_INVALID_VIDEO_FORMAT,
+    InvalidVideoResolution => ERROR_CODE_INVALID_VIDEO_RESOLUTION,
+    InvalidAudioFormat => ERROR_CODE_INVALID_AUDIO_FORMAT,
+    InvalidAudioBitrate => ERROR_CODE_INVALID_AUDIO_BITRATE,
+    InvalidDocumentFormat => ERROR_CODE_INVALID_DOCUMENT_FORMAT,
+    InvalidDocumentSize => ERROR_CODE_INVALID_DOCUMENT_SIZE,
+    InvalidSignatureFormat => ERROR_CODE_INVALID_SIGNATURE_FORMAT,
+    InvalidEncryptionAlgorithm => ERROR_CODE_INVALID_ENCRYPTION_ALGORITHM,
+    InvalidCompressionAlgorithm => ERROR_CODE_INVALID_COMPRESSION_ALGORITHM,
+    InvalidHashAlgorithm => ERROR_CODE_INVALID_HASH_ALGORITHM,
+    InvalidIpAddress => ERROR_CODE_INVALID_IP_ADDRESS,
+    InvalidDomainName => ERROR_CODE_INVALID_DOMAIN_NAME,
+    InvalidUrlFormat => ERROR_CODE_INVALID_URL_FORMAT,
+    InvalidEmailFormatGeneric => ERROR_CODE_INVALID_EMAIL_FORMAT_GENERIC,
+    InvalidUsernameFormatGeneric => ERROR_CODE_INVALID_USERNAME_FORMAT_GENERIC,
+    InvalidPasswordFormatGeneric => ERROR_CODE_INVALID_PASSWORD_FORMAT_GENERIC,
+    InvalidPhoneNumberFormatGeneric => ERROR_CODE_INVALID_PHONE_NUMBER_FORMAT_GENERIC,
+    InvalidBankAccountFormatGeneric => ERROR_CODE_INVALID_BANK_ACCOUNT_FORMAT_GENERIC,
+    InvalidIbanFormatGeneric => ERROR_CODE_INVALID_IBAN_FORMAT_GENERIC,
+    InvalidBicFormatGeneric => ERROR_CODE_INVALID_BIC_FORMAT_GENERIC,
+    InvalidAccountHolderFormatGeneric => ERROR_CODE_INVALID_ACCOUNT_HOLDER_FORMAT_GENERIC,
+    InvalidPinFormatGeneric => ERROR_CODE_INVALID_PIN_FORMAT_GENERIC,
+    InvalidKycStateFormatGeneric => ERROR_CODE_INVALID_KYC_STATE_FORMAT_GENERIC,
+    InvalidLegalFormFormatGeneric => ERROR_CODE_INVALID_LEGAL_FORM_FORMAT_GENERIC,
+    InvalidGenderFormatGeneric => ERROR_CODE_INVALID_GENDER_FORMAT_GENERIC,
+    InvalidTitleFormatGeneric => ERROR_CODE_INVALID_TITLE_FORMAT_GENERIC,
+    InvalidNationalityFormatGeneric => ERROR_CODE_INVALID_NATIONALITY_FORMAT_GENERIC,
+    InvalidBirthDateFormatGeneric => ERROR_CODE_INVALID_BIRTH_DATE_FORMAT_GENERIC,
+    InvalidBirthCityFormatGeneric => ERROR_CODE_INVALID_BIRTH_CITY_FORMAT_GENERIC,
+    InvalidBirthCountryFormatGeneric => ERROR_CODE_INVALID_BIRTH_COUNTRY_FORMAT_GENERIC,
+    InvalidResidenceFormatGeneric => ERROR_CODE_INVALID_RESIDENCE_FORMAT_GENERIC,
+    InvalidCompanyFormatGeneric => ERROR_CODE_INVALID_COMPANY_FORMAT_GENERIC,
+    InvalidCompanyIndustrySectorFormatGeneric => ERROR_CODE_INVALID_COMPANY_INDUSTRY_SECTOR_FORMAT_GENERIC,
+    InvalidCompanyWebsiteFormatGeneric => ERROR_CODE_INVALID_COMPANY_WEBSITE_FORMAT_GENERIC,
+    InvalidCompanyPhoneNumberFormatGeneric => ERROR_CODE_INVALID_COMPANY_PHONE_NUMBER_FORMAT_GENERIC,
+    InvalidTaxNumberFormatGeneric => ERROR_CODE_INVALID_TAX_NUMBER_FORMAT_GENERIC,
+    InvalidLanguageFormatGeneric => ERROR_CODE_INVALID_LANGUAGE_FORMAT_GENERIC,
+    InvalidLocaleFormatGeneric => ERROR_CODE_INVALID_LOCALE_FORMAT_GENERIC,
+    InvalidDateFormatGeneric => ERROR_CODE_INVALID_DATE_FORMAT_GENERIC,
+    InvalidTimeFormatGeneric => ERROR_CODE_INVALID_TIME_FORMAT_GENERIC,
+    InvalidDatetimeFormatGeneric => ERROR_CODE_INVALID_DATETIME_FORMAT_GENERIC,
+    InvalidTimestampFormatGeneric => ERROR_CODE_INVALID_TIMESTAMP_FORMAT_GENERIC,
+    InvalidBooleanFormatGeneric => ERROR_CODE_INVALID_BOOLEAN_FORMAT_GENERIC,
+    InvalidIntegerFormatGeneric => ERROR_CODE_INVALID_INTEGER_FORMAT_GENERIC,
+    InvalidFloatFormatGeneric => ERROR_CODE_INVALID_FLOAT_FORMAT_GENERIC,
+    InvalidEnumValueFormatGeneric => ERROR_CODE_INVALID_ENUM_VALUE_FORMAT_GENERIC,
+    InvalidJsonFormatGeneric => ERROR_CODE_INVALID_JSON_FORMAT_GENERIC,
+    InvalidXmlFormatGeneric => ERROR_CODE_INVALID_XML_FORMAT_GENERIC,
+    InvalidCsvFormatGeneric => ERROR_CODE_INVALID_CSV_FORMAT_GENERIC,
+    InvalidYamlFormatGeneric => ERROR_CODE_INVALID_YAML_FORMAT_GENERIC,
+    InvalidMimeTypeFormatGeneric => ERROR_CODE_INVALID_MIME_TYPE_FORMAT_GENERIC,
+    InvalidFileExtensionFormatGeneric => ERROR_CODE_INVALID_FILE_EXTENSION_FORMAT_GENERIC,
+    InvalidFileSizeFormatGeneric => ERROR_CODE_INVALID_FILE_SIZE_FORMAT_GENERIC,
+    InvalidImageFormatFormatGeneric => ERROR_CODE_INVALID_IMAGE_FORMAT_FORMAT_GENERIC,
+    InvalidImageResolutionFormatGeneric => ERROR_CODE_INVALID_IMAGE_RESOLUTION_FORMAT_GENERIC,
+    InvalidVideoFormatFormatGeneric => ERROR_CODE_INVALID_VIDEO_FORMAT_FORMAT_GENERIC,
+    InvalidVideoResolutionFormatGeneric => ERROR_CODE_INVALID_VIDEO_RESOLUTION_FORMAT_GENERIC,
+    InvalidAudioFormatFormatGeneric => ERROR_CODE_INVALID_AUDIO_FORMAT_FORMAT_GENERIC,
+    InvalidAudioBitrateFormatGeneric => ERROR_CODE_INVALID_AUDIO_BITRATE_FORMAT_GENERIC,
+    InvalidDocumentFormatFormatGeneric => ERROR_CODE_INVALID_DOCUMENT_FORMAT_FORMAT_GENERIC,
+    InvalidDocumentSizeFormatGeneric => ERROR_CODE_INVALID_DOCUMENT_SIZE_FORMAT_GENERIC,
+    InvalidSignatureFormatFormatGeneric => ERROR_CODE_INVALID_SIGNATURE_FORMAT_FORMAT_GENERIC,
+    InvalidEncryptionAlgorithmFormatGeneric => ERROR_CODE_INVALID_ENCRYPTION_ALGORITHM_FORMAT_GENERIC,
+    InvalidCompressionAlgorithmFormatGeneric => ERROR_CODE_INVALID_COMPRESSION_ALGORITHM_FORMAT_GENERIC,
+    InvalidHashAlgorithmFormatGeneric => ERROR_CODE_INVALID_HASH_ALGORITHM_FORMAT_GENERIC,
+    InvalidIpAddressFormatGeneric => ERROR_CODE_INVALID_IP_ADDRESS_FORMAT_GENERIC,
+    InvalidDomainNameFormatGeneric => ERROR_CODE_INVALID_DOMAIN_NAME_FORMAT_GENERIC,
+    InvalidUrlFormatGenericFormatGeneric => ERROR_CODE_INVALID_URL_FORMAT_GENERIC_FORMAT_GENERIC,
+    UnknownError => ERROR_CODE_UNKNOWN_ERROR,
+    Maintenance => ERROR_CODE_MAINTENANCE,
+}
+
+impl ApiErrorCode {
+    /// Returns true if the request was rejected because of its credentials or signature.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(
+            self,
+            ApiErrorCode::MissingHeader
+                | ApiErrorCode::InactiveApiKey
+                | ApiErrorCode::WrongSignature
+                | ApiErrorCode::PermissionDenied
+        )
+    }
+
+    /// Returns true if the request was rejected because of its nonce.
+    pub fn is_nonce_error(&self) -> bool {
+        matches!(self, ApiErrorCode::InvalidNonce)
+    }
+
+    /// Returns true if the API key has run out of credits.
+    pub fn is_insufficient_credits(&self) -> bool {
+        matches!(self, ApiErrorCode::InsufficientCredits)
+    }
+}
+
+impl From<i32> for ApiErrorCode {
+    fn from(code: i32) -> Self {
+        ApiErrorCode::from_code(code)
+    }
+}
+
+impl std::fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ApiErrorCode::Unknown(code) => write!(f, "Unknown({})", code),
+            known => write!(f, "{:?}({})", known, known.code()),
+        }
+    }
+}
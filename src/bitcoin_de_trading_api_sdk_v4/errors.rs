@@ -1,33 +1,69 @@
 // bitcoin_de_trading_api_sdk_v4/errors
 use reqwest::StatusCode;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use thiserror::Error;
 use std::time::SystemTimeError;
 use reqwest::header::InvalidHeaderValue;
 
+use crate::bitcoin_de_trading_api_sdk_v4::error_codes::ApiErrorCode;
+
 
 /// Represents a single error detail within the API response.
 /// Based on the "Error-Details" table in the documentation.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ApiErrorDetail {
+    #[serde(default)]
     pub message: String,
     // The documentation says 'code' is string, but example is integer (50).
-    // The constants are i32. Let's use i32, which aligns with constants and example value.
+    // The constants are i32, so numeric strings like "50" are accepted as well.
+    #[serde(deserialize_with = "deserialize_error_code")]
     pub code: i32,
+    #[serde(default)]
     pub field: Option<String>, // Optional field name
 }
 
+impl ApiErrorDetail {
+    /// Returns the typed error code.
+    pub fn error_code(&self) -> ApiErrorCode {
+        ApiErrorCode::from_code(self.code)
+    }
+}
+
+/// Deserializes an error code given either as JSON number or as numeric string.
+fn deserialize_error_code<'de, D>(deserializer: D) -> Result<i32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(i32),
+        String(String),
+    }
+
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(code) => Ok(code),
+        NumberOrString::String(code) => code
+            .trim()
+            .parse()
+            .map_err(|_| de::Error::custom(format!("invalid error code: {}", code))),
+    }
+}
+
 
 /// Represents the structure of an error response from the Bitcoin.de API.
 ///
 /// This struct is used to deserialize the JSON body returned by the API
-/// when an error occurs.
+/// when an error occurs, e.g.
+/// `{"errors":[{"message":"Invalid nonce","code":6,"field":null}],"credits":18}`.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ApiErrorBody {
-    /// A list of error codes returned by the API.
-    pub errors: Vec<i32>,
-    /// A list of error messages corresponding to the error codes.
-    pub messages: Vec<String>,
+    /// The errors reported by the API.
+    #[serde(default)]
+    pub errors: Vec<ApiErrorDetail>,
+    /// The remaining credits of the API key, if reported.
+    #[serde(default)]
+    pub credits: Option<i32>,
 }
 
 /// Custom error type for the Bitcoin.de Trading API SDK.
@@ -61,6 +97,10 @@ pub enum Error {
     #[error("System time error: {0}")]
     SystemTime(#[from] SystemTimeError),
 
+    /// An I/O error occurred, e.g. while binding the mock server or accessing a file.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// An error occurred due to an invalid HTTP header value.
     #[error("Invalid HTTP header value: {0}")]
     InvalidHeaderValue(#[from] InvalidHeaderValue),
@@ -72,6 +112,32 @@ pub enum Error {
     #[error("Missing required path parameter: {0}")]
     MissingPathParameter(&'static str),
 
+    /// A path parameter value cannot be represented as a single URL path segment
+    /// (empty, `.` or `..`).
+    #[error("Invalid path parameter '{0}': {1:?}")]
+    InvalidPathParameter(&'static str, String),
+
+    /// A request parameter failed local validation before the request was sent.
+    #[error("Invalid parameter '{0}': {1}")]
+    InvalidParameter(&'static str, String),
+
+    /// The client configuration is incomplete or contradictory.
+    #[error("Invalid client configuration: {0}")]
+    Configuration(String),
+
+    /// A cassette could not be read, or holds no recorded response for a replayed request.
+    #[error("Cassette error: {0}")]
+    Cassette(String),
+
+    /// A request was not sent because it would bring the estimated credit balance below
+    /// the configured `CreditFloor`.
+    #[error("Credit floor reached: request costs {cost} credits, estimated balance is {available}, floor is {floor}")]
+    CreditFloor {
+        available: i32,
+        cost: i32,
+        floor: i32,
+    },
+
     /// An error occurred because the API request returned a non-success status code.
     #[error("API request failed with status {status}: {body}")]
     Api {
@@ -95,4 +161,49 @@ impl Error {
             api_error_details,
         }
     }
+
+    /// Returns the typed error codes of an `Api` error.
+    ///
+    /// Empty for all other variants and for API errors whose body could not be parsed.
+    pub fn api_codes(&self) -> Vec<ApiErrorCode> {
+        match self {
+            Error::Api { api_error_details: Some(details), .. } => {
+                details.errors.iter().map(ApiErrorDetail::error_code).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the error details of an `Api` error (message, code and field).
+    ///
+    /// Empty for all other variants and for API errors whose body could not be parsed.
+    pub fn api_errors(&self) -> &[ApiErrorDetail] {
+        match self {
+            Error::Api { api_error_details: Some(details), .. } => &details.errors,
+            _ => &[],
+        }
+    }
+
+    /// Returns the remaining credits reported in the body of an `Api` error.
+    pub fn credits(&self) -> Option<i32> {
+        match self {
+            Error::Api { api_error_details: Some(details), .. } => details.credits,
+            _ => None,
+        }
+    }
+
+    /// Returns true if the API rejected the credentials or the signature of the request.
+    pub fn is_auth_error(&self) -> bool {
+        self.api_codes().iter().any(ApiErrorCode::is_auth_error)
+    }
+
+    /// Returns true if the API rejected the nonce of the request.
+    pub fn is_nonce_error(&self) -> bool {
+        self.api_codes().iter().any(ApiErrorCode::is_nonce_error)
+    }
+
+    /// Returns true if the API key has run out of credits.
+    pub fn is_insufficient_credits(&self) -> bool {
+        self.api_codes().iter().any(ApiErrorCode::is_insufficient_credits)
+    }
 }
\ No newline at end of file
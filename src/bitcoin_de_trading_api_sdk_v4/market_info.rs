@@ -0,0 +1,333 @@
+// bitcoin_de_trading_api_sdk_v4/market_info.rs
+//! Static metadata of the currencies and trading pairs offered by Bitcoin.de.
+//!
+//! [`CURRENCIES`](crate::bitcoin_de_trading_api_sdk_v4::market_info::CURRENCIES) and
+//! [`TRADING_PAIRS`](crate::bitcoin_de_trading_api_sdk_v4::market_info::TRADING_PAIRS) hold
+//! one entry per variant of [`Currency`] and [`TradingPair`], in declaration order, and are
+//! looked up with [`Currency::info`] and [`TradingPair::info`]. The SDK uses them to reject
+//! amounts and prices with more decimal places than their currency supports, and to check
+//! that a trade is settled the way a `mark_*` method expects, before a request is sent.
+//! Values are never rounded by the SDK; use
+//! [`PairInfo::round_amount`](crate::bitcoin_de_trading_api_sdk_v4::market_info::PairInfo::round_amount) and
+//! [`PairInfo::round_price`](crate::bitcoin_de_trading_api_sdk_v4::market_info::PairInfo::round_price)
+//! to bring them to the supported precision first.
+//!
+//! Minimum order amounts are not part of the metadata: the API documentation does not list
+//! them, so they are enforced only by the API, which rejects orders below its current limit
+//! with [`ERROR_CODE_AMOUNT_TOO_LOW`](crate::bitcoin_de_trading_api_sdk_v4::constants::ERROR_CODE_AMOUNT_TOO_LOW).
+//!
+//! ```
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{Currency, TradingPair};
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::market_info::Settlement;
+//! use rust_decimal::Decimal;
+//!
+//! assert_eq!(Currency::EUR.info().decimals, 2);
+//!
+//! let btceur = TradingPair::BTCEUR.info();
+//! assert_eq!(btceur.settlement, Settlement::BankTransfer);
+//! assert_eq!(btceur.round_price(Decimal::new(2500012345, 5)), Decimal::new(2500012, 2)); // 25000.12
+//! assert_eq!(btceur.round_amount(Decimal::new(123456789, 9)), Decimal::new(12345678, 8)); // never rounded up
+//!
+//! assert!(TradingPair::ETHBTC.info().is_crypto_to_crypto());
+//! ```
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{Currency, TradingPair};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+/// Decimal places of amounts and prices in fiat currencies (cents).
+const FIAT_DECIMALS: u32 = 2;
+/// Decimal places of amounts and prices in cryptocurrencies accepted by the API.
+const CRYPTO_DECIMALS: u32 = 8;
+
+/// Metadata of a currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrencyInfo {
+    /// The currency this entry describes.
+    pub currency: Currency,
+    /// The display name, e.g. "Bitcoin".
+    pub name: &'static str,
+    /// The number of decimal places the API accepts for amounts and prices in this currency.
+    pub decimals: u32,
+    /// Whether this is a fiat currency (EUR, CHF, USD).
+    pub is_fiat: bool,
+}
+
+impl CurrencyInfo {
+    /// Rounds a value to the precision of the currency, half away from zero.
+    pub fn round(&self, value: Decimal) -> Decimal {
+        value.round_dp_with_strategy(self.decimals, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    /// Cuts a value down to the precision of the currency, e.g. an amount that must not
+    /// exceed a balance.
+    pub fn truncate(&self, value: Decimal) -> Decimal {
+        value.round_dp_with_strategy(self.decimals, RoundingStrategy::ToZero)
+    }
+}
+
+/// How the trades of a trading pair are settled, which determines the `mark_*` methods
+/// used during a trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Settlement {
+    /// The quote currency is fiat and paid by bank transfer: the buyer calls
+    /// `mark_trade_as_paid`, the seller `mark_trade_as_payment_received`.
+    BankTransfer,
+    /// Both currencies are cryptocurrencies: the buyer calls `mark_coins_as_transferred`,
+    /// the seller `mark_coins_as_received`.
+    CoinTransfer,
+}
+
+/// Metadata of a trading pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairInfo {
+    /// The trading pair this entry describes.
+    pub trading_pair: TradingPair,
+    /// The currency that is traded, in which amounts are given (e.g. BTC for btceur).
+    pub base: Currency,
+    /// The currency that is paid with, in which prices and volumes are given (e.g. EUR for btceur).
+    pub quote: Currency,
+    /// How trades of this pair are settled.
+    pub settlement: Settlement,
+}
+
+impl PairInfo {
+    /// Returns true if both currencies are cryptocurrencies (e.g. ethbtc).
+    pub fn is_crypto_to_crypto(&self) -> bool {
+        self.settlement == Settlement::CoinTransfer
+    }
+
+    /// Cuts an amount of the base currency down to its precision.
+    pub fn round_amount(&self, amount: Decimal) -> Decimal {
+        self.base.info().truncate(amount)
+    }
+
+    /// Rounds a price or volume in the quote currency to its precision, half away from zero.
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        self.quote.info().round(price)
+    }
+
+    /// Checks that trades of this pair are settled as `settlement`, for the `mark_*` methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` for `parameter`, naming the methods to use instead.
+    pub(crate) fn expect_settlement(&self, settlement: Settlement, parameter: &'static str) -> Result<(), Error> {
+        if self.settlement == settlement {
+            return Ok(());
+        }
+        let methods = match self.settlement {
+            Settlement::BankTransfer => "mark_trade_as_paid and mark_trade_as_payment_received",
+            Settlement::CoinTransfer => "mark_coins_as_transferred and mark_coins_as_received",
+        };
+        Err(Error::InvalidParameter(
+            parameter,
+            format!("trades of {} are settled by {:?}, use {}", self.trading_pair, self.settlement, methods),
+        ))
+    }
+}
+
+impl Currency {
+    /// Returns the metadata of the currency.
+    pub fn info(&self) -> &'static CurrencyInfo {
+        &CURRENCIES[*self as usize]
+    }
+}
+
+impl TradingPair {
+    /// Returns the metadata of the trading pair.
+    pub fn info(&self) -> &'static PairInfo {
+        &TRADING_PAIRS[*self as usize]
+    }
+}
+
+/// All currencies, in the declaration order of [`Currency`].
+pub static CURRENCIES: [CurrencyInfo; Currency::ALL.len()] = CURRENCY_TABLE;
+
+/// All trading pairs, in the declaration order of [`TradingPair`].
+pub static TRADING_PAIRS: [PairInfo; TradingPair::ALL.len()] = PAIR_TABLE;
+
+const fn currency(currency: Currency, name: &'static str, is_fiat: bool) -> CurrencyInfo {
+    let decimals = if is_fiat { FIAT_DECIMALS } else { CRYPTO_DECIMALS };
+    CurrencyInfo { currency, name, decimals, is_fiat }
+}
+
+const fn pair(trading_pair: TradingPair, base: Currency, quote: Currency) -> PairInfo {
+    let settlement = if CURRENCY_TABLE[quote as usize].is_fiat { Settlement::BankTransfer } else { Settlement::CoinTransfer };
+    PairInfo { trading_pair, base, quote, settlement }
+}
+
+const CURRENCY_TABLE: [CurrencyInfo; Currency::ALL.len()] = [
+    currency(Currency::BTC, "Bitcoin", false),
+    currency(Currency::BCH, "Bitcoin Cash", false),
+    currency(Currency::ETH, "Ethereum", false),
+    currency(Currency::EUR, "Euro", true),
+    currency(Currency::LTC, "Litecoin", false),
+    currency(Currency::XRP, "XRP", false),
+    currency(Currency::EOS, "EOS", false),
+    currency(Currency::BNB, "BNB", false),
+    currency(Currency::XMR, "Monero", false),
+    currency(Currency::TRX, "TRON", false),
+    currency(Currency::ETC, "Ethereum Classic", false),
+    currency(Currency::DASH, "Dash", false),
+    currency(Currency::ZEC, "Zcash", false),
+    currency(Currency::REP, "Augur", false),
+    currency(Currency::BAT, "Basic Attention Token", false),
+    currency(Currency::AIDUS, "AIDUS Token", false),
+    currency(Currency::XLM, "Stellar", false),
+    currency(Currency::AVAX, "Avalanche", false),
+    currency(Currency::ADA, "Cardano", false),
+    currency(Currency::GRT, "The Graph", false),
+    currency(Currency::LINK, "Chainlink", false),
+    currency(Currency::MATIC, "Polygon", false),
+    currency(Currency::SOL, "Solana", false),
+    currency(Currency::DOT, "Polkadot", false),
+    currency(Currency::UNI, "Uniswap", false),
+    currency(Currency::BTG, "Bitcoin Gold", false),
+    currency(Currency::DOGE, "Dogecoin", false),
+    currency(Currency::USDT, "Tether", false),
+    currency(Currency::USDC, "USD Coin", false),
+    currency(Currency::CHF, "Swiss Franc", true),
+    currency(Currency::USD, "US Dollar", true),
+];
+
+// Explicit table, the pair names cannot be split at a fixed position (e.g. DASHEUR, AIDUSDEUR).
+const PAIR_TABLE: [PairInfo; TradingPair::ALL.len()] = [
+    pair(TradingPair::BTCEUR, Currency::BTC, Currency::EUR),
+    pair(TradingPair::BCHEUR, Currency::BCH, Currency::EUR),
+    pair(TradingPair::ETHBTC, Currency::ETH, Currency::BTC),
+    pair(TradingPair::ETHEUR, Currency::ETH, Currency::EUR),
+    pair(TradingPair::LTCEUR, Currency::LTC, Currency::EUR),
+    pair(TradingPair::LTCBTC, Currency::LTC, Currency::BTC),
+    pair(TradingPair::XRPEUR, Currency::XRP, Currency::EUR),
+    pair(TradingPair::XRPBTC, Currency::XRP, Currency::BTC),
+    pair(TradingPair::EOSEUR, Currency::EOS, Currency::EUR),
+    pair(TradingPair::EOSBTC, Currency::EOS, Currency::BTC),
+    pair(TradingPair::BNBEUR, Currency::BNB, Currency::EUR),
+    pair(TradingPair::BNBBTC, Currency::BNB, Currency::BTC),
+    pair(TradingPair::XMREUR, Currency::XMR, Currency::EUR),
+    pair(TradingPair::XMRBTC, Currency::XMR, Currency::BTC),
+    pair(TradingPair::TRXEUR, Currency::TRX, Currency::EUR),
+    pair(TradingPair::TRXBTC, Currency::TRX, Currency::BTC),
+    pair(TradingPair::ETCBTC, Currency::ETC, Currency::BTC),
+    pair(TradingPair::ETCEUR, Currency::ETC, Currency::EUR),
+    pair(TradingPair::DASHEUR, Currency::DASH, Currency::EUR),
+    pair(TradingPair::DASHBTC, Currency::DASH, Currency::BTC),
+    pair(TradingPair::ZECEUR, Currency::ZEC, Currency::EUR),
+    pair(TradingPair::ZECBTC, Currency::ZEC, Currency::BTC),
+    pair(TradingPair::REPEUR, Currency::REP, Currency::EUR),
+    pair(TradingPair::REPBTC, Currency::REP, Currency::BTC),
+    pair(TradingPair::BATEUR, Currency::BAT, Currency::EUR),
+    pair(TradingPair::BATBTC, Currency::BAT, Currency::BTC),
+    pair(TradingPair::AIDUSDEUR, Currency::AIDUS, Currency::EUR),
+    pair(TradingPair::AIDUSDBTC, Currency::AIDUS, Currency::BTC),
+    pair(TradingPair::XLMEUR, Currency::XLM, Currency::EUR),
+    pair(TradingPair::XLMBTC, Currency::XLM, Currency::BTC),
+    pair(TradingPair::AVAXEUR, Currency::AVAX, Currency::EUR),
+    pair(TradingPair::AVAXBTC, Currency::AVAX, Currency::BTC),
+    pair(TradingPair::ADAEUR, Currency::ADA, Currency::EUR),
+    pair(TradingPair::ADABTC, Currency::ADA, Currency::BTC),
+    pair(TradingPair::GRTEUR, Currency::GRT, Currency::EUR),
+    pair(TradingPair::GRTBTC, Currency::GRT, Currency::BTC),
+    pair(TradingPair::LINKEUR, Currency::LINK, Currency::EUR),
+    pair(TradingPair::LINKBTC, Currency::LINK, Currency::BTC),
+    pair(TradingPair::MATICBTC, Currency::MATIC, Currency::BTC),
+    pair(TradingPair::MATICEUR, Currency::MATIC, Currency::EUR),
+    pair(TradingPair::SOLEUR, Currency::SOL, Currency::EUR),
+    pair(TradingPair::SOLBTC, Currency::SOL, Currency::BTC),
+    pair(TradingPair::DOTEUR, Currency::DOT, Currency::EUR),
+    pair(TradingPair::DOTBTC, Currency::DOT, Currency::BTC),
+    pair(TradingPair::UNIEUR, Currency::UNI, Currency::EUR),
+    pair(TradingPair::UNIBTC, Currency::UNI, Currency::BTC),
+    pair(TradingPair::XMRETH, Currency::XMR, Currency::ETH),
+    pair(TradingPair::XRPETH, Currency::XRP, Currency::ETH),
+    pair(TradingPair::LTCETH, Currency::LTC, Currency::ETH),
+    pair(TradingPair::DASHETH, Currency::DASH, Currency::ETH),
+    pair(TradingPair::ZECETH, Currency::ZEC, Currency::ETH),
+    pair(TradingPair::REPBCH, Currency::REP, Currency::BCH),
+    pair(TradingPair::BATBCH, Currency::BAT, Currency::BCH),
+    pair(TradingPair::XLMBCH, Currency::XLM, Currency::BCH),
+    pair(TradingPair::ADAETH, Currency::ADA, Currency::ETH),
+    pair(TradingPair::GRTETH, Currency::GRT, Currency::ETH),
+    pair(TradingPair::LINKETH, Currency::LINK, Currency::ETH),
+    pair(TradingPair::MATICETH, Currency::MATIC, Currency::ETH),
+    pair(TradingPair::SOLETH, Currency::SOL, Currency::ETH),
+    pair(TradingPair::DOTETH, Currency::DOT, Currency::ETH),
+    pair(TradingPair::UNIBNB, Currency::UNI, Currency::BNB),
+    pair(TradingPair::EURCHF, Currency::EUR, Currency::CHF),
+    pair(TradingPair::BTCCHF, Currency::BTC, Currency::CHF),
+    pair(TradingPair::ETHCHF, Currency::ETH, Currency::CHF),
+    pair(TradingPair::BTGEUR, Currency::BTG, Currency::EUR),
+    pair(TradingPair::DOGEEUR, Currency::DOGE, Currency::EUR),
+    pair(TradingPair::USDTEUR, Currency::USDT, Currency::EUR),
+    pair(TradingPair::USDCEUR, Currency::USDC, Currency::EUR),
+];
+
+// Entries are looked up by discriminant, so the tables must follow the declaration order
+const _: () = {
+    let mut index = 0;
+    while index < CURRENCY_TABLE.len() {
+        assert!(CURRENCY_TABLE[index].currency as usize == index, "CURRENCY_TABLE is not in declaration order");
+        index += 1;
+    }
+    let mut index = 0;
+    while index < PAIR_TABLE.len() {
+        assert!(PAIR_TABLE[index].trading_pair as usize == index, "PAIR_TABLE is not in declaration order");
+        index += 1;
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_currency_has_info() {
+        for &currency in Currency::ALL {
+            let info = currency.info();
+            assert_eq!(info.currency, currency);
+            assert!(!info.name.is_empty());
+            assert_eq!(info.decimals, if info.is_fiat { FIAT_DECIMALS } else { CRYPTO_DECIMALS }, "{}", currency);
+        }
+        let fiat: Vec<Currency> = Currency::ALL.iter().copied().filter(|currency| currency.info().is_fiat).collect();
+        assert_eq!(fiat, [Currency::EUR, Currency::CHF, Currency::USD]);
+    }
+
+    #[test]
+    fn every_trading_pair_has_info() {
+        for &trading_pair in TradingPair::ALL {
+            let info = trading_pair.info();
+            assert_eq!(info.trading_pair, trading_pair);
+            // The AIDUS token trades as AIDUSD
+            assert_eq!(trading_pair.to_string(), format!("{}{}", info.base, info.quote).replacen("AIDUS", "AIDUSD", 1));
+            assert_ne!(info.base, info.quote);
+            let expected = if info.quote.info().is_fiat { Settlement::BankTransfer } else { Settlement::CoinTransfer };
+            assert_eq!(info.settlement, expected, "{}", trading_pair);
+        }
+    }
+
+    #[test]
+    fn fiat_quotes_are_settled_by_bank_transfer() {
+        for trading_pair in [TradingPair::BTCEUR, TradingPair::EURCHF, TradingPair::BTCCHF, TradingPair::ETHCHF] {
+            assert_eq!(trading_pair.info().settlement, Settlement::BankTransfer, "{}", trading_pair);
+        }
+        assert_eq!(TradingPair::EURCHF.info().base, Currency::EUR);
+        assert!(TradingPair::UNIBNB.info().is_crypto_to_crypto());
+    }
+
+    #[test]
+    fn aidus_pairs_trade_the_aidus_token() {
+        let aidusdeur = TradingPair::AIDUSDEUR.info();
+        assert_eq!((aidusdeur.base, aidusdeur.quote), (Currency::AIDUS, Currency::EUR));
+        let aidusdbtc = TradingPair::AIDUSDBTC.info();
+        assert_eq!((aidusdbtc.base, aidusdbtc.quote), (Currency::AIDUS, Currency::BTC));
+    }
+
+    #[test]
+    fn rounding_follows_the_currency_precision() {
+        let btceur = TradingPair::BTCEUR.info();
+        assert_eq!(btceur.round_price(Decimal::new(2500005, 3)), Decimal::new(250001, 2)); // 2500.005 -> 2500.01
+        assert_eq!(btceur.round_amount(Decimal::new(199999999999, 11)), Decimal::new(199999999, 8)); // 1.99999999999
+        assert_eq!(Currency::EUR.info().truncate(Decimal::new(-1239, 3)), Decimal::new(-123, 2));
+    }
+}
@@ -26,6 +26,10 @@ pub mod constants {
     pub const HTTP_METHOD_POST: &str = "POST";
     pub const HTTP_METHOD_DELETE: &str = "DELETE";
 
+    // Query parameter selecting the page of paged methods (showMyOrders, showMyTrades, showAccountLedger,
+    // showWithdrawals, showDeposits, showOutgoingAddresses, listAddressPool)
+    pub const PARAMETER_PAGE: &str = "page";
+
     // Method Names (ensure these match the keys used in METHOD_SETTINGS)
     pub const METHOD_SHOW_ORDERBOOK: &str = "showOrderbook";
     pub const METHOD_SHOW_ORDER_DETAILS: &str = "showOrderDetails";
@@ -68,6 +72,17 @@ pub mod constants {
     // Orderbook
     pub const SHOW_ORDERBOOK_PARAMETER_TRADING_PAIR: &str = "trading_pair";
     pub const SHOW_ORDERBOOK_PARAMETER_TYPE: &str = "type";
+    pub const SHOW_ORDERBOOK_PARAMETER_AMOUNT_CURRENCY_TO_TRADE: &str = "amount_currency_to_trade"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_PRICE: &str = "price"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_ORDER_REQUIREMENTS_FULLFILLED: &str = "order_requirements_fullfilled"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_ONLY_KYC_FULL: &str = "only_kyc_full"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_ONLY_EXPRESS_ORDERS: &str = "only_express_orders"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_PAYMENT_OPTION: &str = "payment_option"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_SEPA_OPTION: &str = "sepa_option"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_ONLY_SAME_BANKGROUP: &str = "only_same_bankgroup"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_ONLY_SAME_BIC: &str = "only_same_bic"; // Optional
+    pub const SHOW_ORDERBOOK_PARAMETER_SEAT_OF_BANK: &str = "seat_of_bank"; // Optional array
+    pub const SHOW_ORDERBOOK_PARAMETER_MIN_TRUST_LEVEL: &str = "min_trust_level"; // Optional
     // Order Details
     pub const SHOW_ORDER_DETAILS_PARAMETER_TRADING_PAIR: &str = "trading_pair";
     pub const SHOW_ORDER_DETAILS_PARAMETER_ORDER_ID: &str = "order_id";
@@ -77,11 +92,22 @@ pub mod constants {
     pub const CREATE_ORDER_PARAMETER_MAX_AMOUNT: &str = "max_amount_currency_to_trade"; // Definition was here
     pub const CREATE_ORDER_PARAMETER_PRICE: &str = "price";
     pub const CREATE_ORDER_PARAMETER_ORDER_PAYMENT_OPTIONS: &str = "payment_option"; // Optional
+    pub const CREATE_ORDER_PARAMETER_MIN_AMOUNT: &str = "min_amount_currency_to_trade"; // Optional
+    pub const CREATE_ORDER_PARAMETER_END_DATETIME: &str = "end_datetime"; // Optional, RFC 3339
+    pub const CREATE_ORDER_PARAMETER_NEW_ORDER_FOR_REMAINING_AMOUNT: &str = "new_order_for_remaining_amount"; // Optional
+    pub const CREATE_ORDER_PARAMETER_MIN_TRUST_LEVEL: &str = "min_trust_level"; // Optional
+    pub const CREATE_ORDER_PARAMETER_ONLY_KYC_FULL: &str = "only_kyc_full"; // Optional
+    pub const CREATE_ORDER_PARAMETER_SEAT_OF_BANK: &str = "seat_of_bank"; // Optional array
+    pub const CREATE_ORDER_PARAMETER_SEPA_OPTION: &str = "sepa_option"; // Optional
     // Delete Order
     pub const DELETE_ORDER_PARAMETER_TRADING_PAIR: &str = "trading_pair";
     pub const DELETE_ORDER_PARAMETER_ORDER_ID: &str = "order_id";
     // Show My Orders
     pub const SHOW_MY_ORDERS_PARAMETER_TRADING_PAIR: &str = "trading_pair"; // Optional path param - Definition was here
+    pub const SHOW_MY_ORDERS_PARAMETER_TYPE: &str = "type"; // Optional
+    pub const SHOW_MY_ORDERS_PARAMETER_STATE: &str = "state"; // Optional
+    pub const SHOW_MY_ORDERS_PARAMETER_DATE_START: &str = "date_start"; // Optional, RFC 3339
+    pub const SHOW_MY_ORDERS_PARAMETER_DATE_END: &str = "date_end"; // Optional, RFC 3339
     // Show My Order Details
     pub const SHOW_MY_ORDER_DETAILS_PARAMETER_TRADING_PAIR: &str = "trading_pair";
     pub const SHOW_MY_ORDER_DETAILS_PARAMETER_ORDER_ID: &str = "order_id"; // Definition was here
@@ -90,6 +116,7 @@ pub mod constants {
     pub const EXECUTE_TRADE_PARAMETER_ORDER_ID: &str = "order_id";
     pub const EXECUTE_TRADE_PARAMETER_TYPE: &str = "type";
     pub const EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE: &str = "amount_currency_to_trade";
+    pub const EXECUTE_TRADE_PARAMETER_PAYMENT_OPTION: &str = "payment_option"; // Optional
     // Show My Trades
     pub const SHOW_MY_TRADES_PARAMETER_TRADING_PAIR: &str = "trading_pair"; // Optional path param - Definition was here
     // Show My Trade Details
@@ -178,6 +205,8 @@ pub struct MethodSetting {
     /// An ordered list of path segments that form the URL path after the base URI (`/v4/`).
     /// Segments starting with ':' are placeholders (e.g., ":trading_pair", ":order_id")
     /// that will be replaced by values from the input parameters.
+    /// Placeholders ending with '?' are optional (e.g., ":trading_pair?"): without a value,
+    /// the segment is left out of the path.
     /// Literal segments are used directly (e.g., "orders", "rates", "history").
     pub path_segments: &'static [&'static str],
 
@@ -202,6 +231,21 @@ pub struct MethodSetting {
     pub id_parameter: Option<&'static str>,
 }
 
+impl MethodSetting {
+    /// Parses a path segment.
+    ///
+    /// Returns the parameter name and whether it is optional for placeholders
+    /// (":order_id" gives `("order_id", false)`, ":trading_pair?" gives `("trading_pair", true)`),
+    /// or `None` for literal segments.
+    pub fn placeholder(segment: &'static str) -> Option<(&'static str, bool)> {
+        let name = segment.strip_prefix(':')?;
+        Some(match name.strip_suffix('?') {
+            Some(name) => (name, true),
+            None => (name, false),
+        })
+    }
+}
+
 /// Initializes and provides a static mapping of all supported Bitcoin.de API v4 methods to their configuration settings.
 ///
 /// This function creates a comprehensive HashMap that defines the configuration for each API endpoint
@@ -238,8 +282,17 @@ pub static METHOD_SETTINGS: LazyLock<HashMap<&'static str, MethodSetting>> = Laz
         // These are the expected *query* parameters for GET
         parameters: &[
             SHOW_ORDERBOOK_PARAMETER_TYPE, // 'type'
-            // Add other optional query params from docs if needed for validation later
-            // "amount_currency_to_trade", "price", "order_requirements_fullfilled", etc.
+            SHOW_ORDERBOOK_PARAMETER_AMOUNT_CURRENCY_TO_TRADE, // optional
+            SHOW_ORDERBOOK_PARAMETER_PRICE, // optional
+            SHOW_ORDERBOOK_PARAMETER_ORDER_REQUIREMENTS_FULLFILLED, // optional
+            SHOW_ORDERBOOK_PARAMETER_ONLY_KYC_FULL, // optional
+            SHOW_ORDERBOOK_PARAMETER_ONLY_EXPRESS_ORDERS, // optional
+            SHOW_ORDERBOOK_PARAMETER_PAYMENT_OPTION, // optional
+            SHOW_ORDERBOOK_PARAMETER_SEPA_OPTION, // optional
+            SHOW_ORDERBOOK_PARAMETER_ONLY_SAME_BANKGROUP, // optional
+            SHOW_ORDERBOOK_PARAMETER_ONLY_SAME_BIC, // optional
+            SHOW_ORDERBOOK_PARAMETER_SEAT_OF_BANK, // optional, sent as seat_of_bank[n]
+            SHOW_ORDERBOOK_PARAMETER_MIN_TRUST_LEVEL, // optional
         ],
         id_parameter: None,
     });
@@ -265,8 +318,14 @@ pub static METHOD_SETTINGS: LazyLock<HashMap<&'static str, MethodSetting>> = Laz
             CREATE_ORDER_PARAMETER_TYPE, // 'type'
             CREATE_ORDER_PARAMETER_MAX_AMOUNT, // 'max_amount_currency_to_trade' - Use constant
             CREATE_ORDER_PARAMETER_PRICE, // 'price'
-            // Add other optional body params from docs if needed for validation later
-            // "min_amount_currency_to_trade", "end_datetime", etc.
+            CREATE_ORDER_PARAMETER_MIN_AMOUNT, // optional
+            CREATE_ORDER_PARAMETER_END_DATETIME, // optional
+            CREATE_ORDER_PARAMETER_NEW_ORDER_FOR_REMAINING_AMOUNT, // optional
+            CREATE_ORDER_PARAMETER_MIN_TRUST_LEVEL, // optional
+            CREATE_ORDER_PARAMETER_ONLY_KYC_FULL, // optional
+            CREATE_ORDER_PARAMETER_SEAT_OF_BANK, // optional, sent as seat_of_bank[n]
+            CREATE_ORDER_PARAMETER_ORDER_PAYMENT_OPTIONS, // optional
+            CREATE_ORDER_PARAMETER_SEPA_OPTION, // optional
         ],
         id_parameter: None,
     });
@@ -288,16 +347,16 @@ pub static METHOD_SETTINGS: LazyLock<HashMap<&'static str, MethodSetting>> = Laz
     // Alternatively, define two separate method constants and entries if needed.
     settings.insert(METHOD_SHOW_MY_ORDERS, MethodSetting {
         http_method: HTTP_METHOD_GET,
-        // This definition assumes a trading_pair is provided.
-        // If no trading_pair is given, the path should just be "orders".
-        // The build_url_path logic needs to handle optional path segments or have separate method definitions.
-        // For now, defining it like the specific pair endpoint:
-        path_segments: & [":trading_pair", "orders"], // Assumes specific pair for this definition
+        // GET /v4/:trading_pair/orders for one pair, GET /v4/orders for all pairs
+        path_segments: & [":trading_pair?", "orders"],
         trading_pair_parameter: Some(SHOW_MY_ORDERS_PARAMETER_TRADING_PAIR), // Parameter name if provided - Use constant
         currency_parameter: None,
-        // Query parameters like 'type', 'state', 'date_start', 'date_end', 'page'
         parameters: &[
-            // Add expected query params here if needed for validation
+            SHOW_MY_ORDERS_PARAMETER_TYPE, // optional
+            SHOW_MY_ORDERS_PARAMETER_STATE, // optional
+            SHOW_MY_ORDERS_PARAMETER_DATE_START, // optional
+            SHOW_MY_ORDERS_PARAMETER_DATE_END, // optional
+            PARAMETER_PAGE, // optional
         ],
         id_parameter: None,
     });
@@ -324,7 +383,7 @@ pub static METHOD_SETTINGS: LazyLock<HashMap<&'static str, MethodSetting>> = Laz
         parameters: &[
             EXECUTE_TRADE_PARAMETER_TYPE, // 'type'
             EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE, // 'amount_currency_to_trade'
-            // Add optional 'payment_option' if needed
+            EXECUTE_TRADE_PARAMETER_PAYMENT_OPTION, // optional
         ],
         id_parameter: Some(EXECUTE_TRADE_PARAMETER_ORDER_ID), // Identifies order_id path param
     });
@@ -334,7 +393,8 @@ pub static METHOD_SETTINGS: LazyLock<HashMap<&'static str, MethodSetting>> = Laz
     // Similar to showMyOrders, defining the specific-pair version here.
     settings.insert(METHOD_SHOW_MY_TRADES, MethodSetting {
         http_method: HTTP_METHOD_GET,
-        path_segments: & [":trading_pair", "trades"], // Assumes specific pair for this definition
+        // GET /v4/:trading_pair/trades for one pair, GET /v4/trades for all pairs
+        path_segments: & [":trading_pair?", "trades"],
         trading_pair_parameter: Some(SHOW_MY_TRADES_PARAMETER_TRADING_PAIR), // Parameter name if provided - Use constant
         currency_parameter: None,
         // Query parameters like 'type', 'state', 'date_start', 'date_end', 'page', etc.
@@ -642,3 +702,40 @@ pub static METHOD_SETTINGS: LazyLock<HashMap<&'static str, MethodSetting>> = Laz
     // Return the populated HashMap
     settings
 });
+
+/// Returns the number of credits the API charges for one call of `method_name`, or `None`
+/// for method names not in [`METHOD_SETTINGS`].
+///
+/// The costs are taken from the "Credits" table of the Trading API v4 documentation
+/// (<https://www.bitcoin.de/de/api/tapi/v4/docu>). They are only used to estimate the
+/// balance between two responses: every response reports the actual balance, which
+/// replaces the estimate, so a cost that changes on the API side only skews the estimate
+/// until the next response.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::{constants::METHOD_CREATE_ORDER, method_credits};
+///
+/// assert_eq!(method_credits(METHOD_CREATE_ORDER), Some(1));
+/// assert_eq!(method_credits("unknownMethod"), None);
+/// ```
+pub fn method_credits(method_name: &str) -> Option<i32> {
+    use constants::*;
+
+    match method_name {
+        METHOD_CREATE_ORDER | METHOD_EXECUTE_TRADE | METHOD_MARK_TRADE_AS_PAID |
+        METHOD_MARK_TRADE_AS_PAYMENT_RECEIVED | METHOD_ADD_TRADE_RATING |
+        METHOD_MARK_COINS_AS_TRANSFERRED | METHOD_MARK_COINS_AS_RECEIVED => Some(1),
+        METHOD_SHOW_ORDERBOOK | METHOD_SHOW_ORDER_DETAILS | METHOD_DELETE_ORDER |
+        METHOD_SHOW_MY_ORDERS | METHOD_SHOW_MY_ORDER_DETAILS | METHOD_SHOW_ACCOUNT_INFO |
+        METHOD_SHOW_PERMISSIONS | METHOD_CREATE_WITHDRAWAL | METHOD_DELETE_WITHDRAWAL |
+        METHOD_SHOW_WITHDRAWAL | METHOD_SHOW_WITHDRAWAL_MIN_NETWORK_FEE | METHOD_SHOW_WITHDRAWALS |
+        METHOD_SHOW_OUTGOING_ADDRESSES | METHOD_REQUEST_DEPOSIT_ADDRESS | METHOD_SHOW_DEPOSIT |
+        METHOD_SHOW_DEPOSITS | METHOD_ADD_TO_ADDRESS_POOL | METHOD_REMOVE_FROM_ADDRESS_POOL |
+        METHOD_LIST_ADDRESS_POOL => Some(2),
+        METHOD_SHOW_MY_TRADES | METHOD_SHOW_MY_TRADE_DETAILS | METHOD_SHOW_ACCOUNT_LEDGER |
+        METHOD_SHOW_ORDERBOOK_COMPACT | METHOD_SHOW_PUBLIC_TRADE_HISTORY | METHOD_SHOW_RATES => Some(3),
+        _ => None,
+    }
+}
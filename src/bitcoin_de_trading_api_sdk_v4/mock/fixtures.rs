@@ -0,0 +1,249 @@
+// bitcoin_de_trading_api_sdk_v4/mock/fixtures.rs
+//! Canned success responses of the mock server, modelled after the examples in the
+//! official API documentation. `errors` and `credits` are added by the server.
+
+use serde_json::{json, Value};
+
+use super::MockRequest;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
+
+/// Returns the canned success body for an API method.
+///
+/// Path parameters like the trading pair, currency or ids are echoed back where the
+/// response contains them, so the fixtures stay consistent with the request.
+pub(super) fn canned_response(method_name: &str, request: &MockRequest) -> Value {
+    let trading_pair = request.param("trading_pair").unwrap_or("btceur").to_string();
+    let currency = request.param("currency").unwrap_or("btc").to_string();
+
+    match method_name {
+        METHOD_SHOW_ORDERBOOK => json!({
+            "orders": [orderbook_entry(&trading_pair, request.param("type").unwrap_or("buy"))]
+        }),
+        METHOD_SHOW_ORDER_DETAILS => {
+            let mut order = orderbook_entry(&trading_pair, "sell");
+            order["order_id"] = json!(request.param("order_id").unwrap_or("A1B2D3"));
+            json!({ "order": order })
+        }
+        METHOD_CREATE_ORDER => json!({ "order_id": "A1234BC" }),
+        METHOD_SHOW_MY_ORDERS => json!({
+            "orders": [my_order(&trading_pair, "A1234BC")],
+            "page": page(request)
+        }),
+        METHOD_SHOW_MY_ORDER_DETAILS => json!({
+            "order": my_order(&trading_pair, request.param("order_id").unwrap_or("A1234BC"))
+        }),
+        METHOD_SHOW_MY_TRADES => json!({
+            "trades": [my_trade(&trading_pair, "2EDYNS")],
+            "page": page(request)
+        }),
+        METHOD_SHOW_MY_TRADE_DETAILS => json!({
+            "trade": my_trade(&trading_pair, request.param("trade_id").unwrap_or("2EDYNS"))
+        }),
+        METHOD_SHOW_ACCOUNT_INFO => json!({
+            "data": {
+                "balances": {
+                    "btc": { "total_amount": "5.00000000", "available_amount": "4.50000000", "reserved_amount": "0.50000000" },
+                    "eth": { "total_amount": "12.50000000", "available_amount": "12.50000000", "reserved_amount": "0" }
+                },
+                "encrypted_information": { "bic_short": "HASPDEHH", "bic_full": "HASPDEHHXXX", "uid": "d8e7f6a5" }
+            }
+        }),
+        METHOD_SHOW_ACCOUNT_LEDGER => json!({
+            "account_ledger": [{
+                "date": "2015-08-13T10:15:41+02:00",
+                "type": "sell",
+                "reference": "2EDYNS",
+                "trade": {
+                    "trade_id": "2EDYNS",
+                    "trading_pair": format!("{}eur", currency),
+                    "price": "300.00",
+                    "is_external_wallet_trade": false,
+                    "primary_currency": {
+                        currency.as_str(): { "currency": currency, "before_fee": "0.50000000", "after_fee": "0.49750000" }
+                    },
+                    "secondary_currency": {
+                        "eur": { "currency": "eur", "before_fee": "150.00", "after_fee": "149.25" }
+                    }
+                },
+                "cashflow": "-0.50000000",
+                "balance": "4.50000000"
+            }],
+            "page": page(request)
+        }),
+        METHOD_SHOW_PERMISSIONS => json!({
+            "permissions": ["showAccountInfo", "showOrderbook", "createOrder", "deleteOrder", "executeTrade"]
+        }),
+        METHOD_CREATE_WITHDRAWAL => json!({ "withdrawal_id": 123413 }),
+        METHOD_SHOW_WITHDRAWAL => json!({
+            "withdrawal": withdrawal(request.param("withdrawal_id").unwrap_or("123"))
+        }),
+        METHOD_SHOW_WITHDRAWALS => json!({
+            "withdrawals": [withdrawal("123")],
+            "page": page(request)
+        }),
+        METHOD_SHOW_WITHDRAWAL_MIN_NETWORK_FEE => json!({ "min_network_fee": "0.00020000" }),
+        METHOD_REQUEST_DEPOSIT_ADDRESS => json!({
+            "address": "1CJfmmAkhwqKzVG8ZLDoWp5c1Ty2GA9nvr",
+            "recipient_purpose": null
+        }),
+        METHOD_SHOW_DEPOSIT => json!({
+            "deposit": deposit(request.param("deposit_id").and_then(|id| id.parse().ok()).unwrap_or(123))
+        }),
+        METHOD_SHOW_DEPOSITS => json!({
+            "deposits": [deposit(123)],
+            "page": page(request)
+        }),
+        METHOD_SHOW_OUTGOING_ADDRESSES | METHOD_LIST_ADDRESS_POOL => json!({
+            "outgoing_address": [{
+                "address_id": 7411,
+                "recipient_address": "1CJfmmAkhwqKzVG8ZLDoWp5c1Ty2GA9nvr",
+                "recipient_purpose": null,
+                "comment": "Mein Self-Hosted Wallet"
+            }],
+            "page": page(request)
+        }),
+        METHOD_SHOW_ORDERBOOK_COMPACT => json!({
+            "trading_pair": trading_pair,
+            "orders": {
+                "bids": [{ "price": "299.50", "amount_currency_to_trade": "1.25" }],
+                "asks": [{ "price": "300.00", "amount_currency_to_trade": "0.75" }]
+            }
+        }),
+        METHOD_SHOW_PUBLIC_TRADE_HISTORY => json!({
+            "trading_pair": trading_pair,
+            "trades": [
+                { "date": 1439454941, "price": "300.00", "amount_currency_to_trade": "0.50000000", "tid": 2844384 },
+                { "date": 1439455012, "price": "301.25", "amount_currency_to_trade": "1.00000000", "tid": 2844385 }
+            ]
+        }),
+        METHOD_SHOW_RATES => json!({
+            "trading_pair": trading_pair,
+            "rates": { "rate_weighted": "300.25", "rate_weighted_3h": "299.80", "rate_weighted_12h": "298.10" }
+        }),
+        // deleteOrder, executeTrade, markTradeAs*, addTradeRating, deleteWithdrawal,
+        // add/removeFromAddressPool and markCoinsAs* only return errors and credits
+        _ => json!({}),
+    }
+}
+
+/// The page block of paged responses, echoing the requested page.
+fn page(request: &MockRequest) -> Value {
+    let current = request.param("page").and_then(|page| page.parse::<i32>().ok()).unwrap_or(1);
+    json!({ "current": current, "last": current.max(1) })
+}
+
+fn trading_partner_information() -> Value {
+    json!({
+        "username": "bla",
+        "is_kyc_full": true,
+        "trust_level": "gold",
+        "depositor": "Max Mustermann",
+        "iban": "DE12345678901234567890",
+        "bank_name": "Sparkasse",
+        "bic": "HASPDEHHXXX",
+        "seat_of_bank": "DE",
+        "amount_trades": 52,
+        "rating": 99
+    })
+}
+
+fn order_requirements() -> Value {
+    json!({
+        "min_trust_level": "gold",
+        "only_kyc_full": true,
+        "seat_of_bank": ["DE", "NL"],
+        "payment_option": 1
+    })
+}
+
+fn orderbook_entry(trading_pair: &str, order_type: &str) -> Value {
+    json!({
+        "order_id": "A1B2D3",
+        "is_external_wallet_order": false,
+        "trading_pair": trading_pair,
+        "type": order_type,
+        "max_amount_currency_to_trade": "1.5",
+        "min_amount_currency_to_trade": "0.1",
+        "price": "230.55",
+        "max_volume_currency_to_pay": "345.83",
+        "min_volume_currency_to_pay": "23.06",
+        "order_requirements_fullfilled": true,
+        "sepa_option": 0,
+        "trading_partner_information": trading_partner_information(),
+        "order_requirements": order_requirements()
+    })
+}
+
+fn my_order(trading_pair: &str, order_id: &str) -> Value {
+    json!({
+        "order_id": order_id,
+        "trading_pair": trading_pair,
+        "is_external_wallet_order": false,
+        "type": "buy",
+        "max_amount_currency_to_trade": "0.5",
+        "min_amount_currency_to_trade": "0.1",
+        "price": "250.55",
+        "max_volume_currency_to_pay": "125.28",
+        "min_volume_currency_to_pay": "25.06",
+        "end_datetime": "2015-01-20T15:00:00+02:00",
+        "new_order_for_remaining_amount": true,
+        "state": 0,
+        "order_requirements": order_requirements(),
+        "sepa_option": 0,
+        "created_at": "2015-01-10T15:00:00+02:00"
+    })
+}
+
+fn my_trade(trading_pair: &str, trade_id: &str) -> Value {
+    json!({
+        "trade_id": trade_id,
+        "is_external_wallet_trade": false,
+        "trading_pair": trading_pair,
+        "type": "sell",
+        "amount_currency_to_trade": "0.5",
+        "price": "300.00",
+        "volume_currency_to_pay": "150.00",
+        "amount_currency_to_trade_after_fee": "0.4975",
+        "volume_currency_to_pay_after_fee": "149.25",
+        "fee_currency_to_pay": "0.75",
+        "fee_currency_to_trade": "0.0025",
+        "new_order_id_for_remaining_amount": null,
+        "state": 1,
+        "is_trade_marked_as_paid": true,
+        "trade_marked_as_paid_at": "2015-01-28T13:05:00+01:00",
+        "my_rating_for_trading_partner": "pending",
+        "trading_partner_information": trading_partner_information(),
+        "created_at": "2015-01-28T13:00:00+01:00",
+        "successfully_finished_at": null,
+        "cancelled_at": null,
+        "payment_method": 1
+    })
+}
+
+fn withdrawal(withdrawal_id: &str) -> Value {
+    json!({
+        "withdrawal_id": withdrawal_id,
+        "address": "1CJfmmAkhwqKzVG8ZLDoWp5c1Ty2GA9nvr",
+        "recipient_purpose": null,
+        "amount": "0.50000000",
+        "network_fee": "0.00020000",
+        "comment": "Bitpay",
+        "created_at": "2018-01-17T10:36:44+01:00",
+        "state": 0,
+        "transferred_at": null,
+        "txid": null
+    })
+}
+
+fn deposit(deposit_id: i64) -> Value {
+    json!({
+        "deposit_id": deposit_id,
+        "address": "1CJfmmAkhwqKzVG8ZLDoWp5c1Ty2GA9nvr",
+        "recipient_purpose": null,
+        "amount": "0.50000000",
+        "state": 2,
+        "txid": "f2bb8d3a4c8f1b6d4bdc2f8a0a9fa4ab7a2f5e2f0e46a62e7fbbd6d3ec9a9e7a",
+        "confirmations": 6,
+        "created_at": "2018-01-17T10:36:44+01:00"
+    })
+}
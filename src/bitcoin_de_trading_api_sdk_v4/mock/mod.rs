@@ -0,0 +1,429 @@
+// bitcoin_de_trading_api_sdk_v4/mock/mod.rs
+//! In-process stand-in for the Bitcoin.de Trading API v4.
+//!
+//! The [`MockServer`] listens on a random local port, implements every route defined in
+//! [`METHOD_SETTINGS`], verifies the `X-API-KEY`, `X-API-NONCE` and `X-API-SIGNATURE`
+//! headers with the same HMAC scheme the client uses, and answers with canned responses
+//! that deserialize into the SDK's response structs. Responses can be overridden per
+//! API method, either once ([`MockServer::enqueue`]) or permanently
+//! ([`MockServer::set_response`], [`MockServer::set_handler`]).
+//!
+//! [`MockServer`]: crate::bitcoin_de_trading_api_sdk_v4::mock::MockServer
+//! [`MockServer::enqueue`]: crate::bitcoin_de_trading_api_sdk_v4::mock::MockServer::enqueue
+//! [`MockServer::set_response`]: crate::bitcoin_de_trading_api_sdk_v4::mock::MockServer::set_response
+//! [`MockServer::set_handler`]: crate::bitcoin_de_trading_api_sdk_v4::mock::MockServer::set_handler
+//! [`METHOD_SETTINGS`]: crate::bitcoin_de_trading_api_sdk_v4::method_settings::METHOD_SETTINGS
+//!
+//! Only available with the `mock` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::METHOD_SHOW_RATES;
+//! use bitcoin_de::enums::TradingPair;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), bitcoin_de::Error> {
+//!     let server = MockServer::start("test_key", "test_secret").await?;
+//!     let client = server.client()?;
+//!
+//!     // Canned response
+//!     let rates = client.show_rates(TradingPair::BTCEUR).await?;
+//!     assert_eq!(rates.trading_pair().unwrap(), TradingPair::BTCEUR);
+//!
+//!     // Scripted one-shot error
+//!     server.enqueue(METHOD_SHOW_RATES, MockResponse::error(404, 32, "Invalid trading pair"));
+//!     assert!(client.show_rates(TradingPair::BTCEUR).await.is_err());
+//!
+//!     assert_eq!(server.requests().len(), 2);
+//!     Ok(())
+//! }
+//! ```
+
+mod fixtures;
+
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, Method, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+use axum::Router;
+use serde_json::{json, Value};
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+use crate::bitcoin_de_trading_api_sdk_v4::constants::{
+    ERROR_CODE_INACTIVE_API_KEY, ERROR_CODE_INVALID_NONCE, ERROR_CODE_MISSING_HEADER,
+    ERROR_CODE_UNKNOWN_API_METHOD, ERROR_CODE_WRONG_SIGNATURE, HEADER_X_API_KEY, HEADER_X_API_SIGNATURE,
+    HEADER_X_NONCE,
+};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::{MethodSetting, METHOD_SETTINGS};
+use crate::bitcoin_de_trading_api_sdk_v4::signature::verify_signature;
+use crate::bitcoin_de_trading_api_sdk_v4::trading_api_sdk_v4::TradingApiSdkV4;
+
+/// Credits reported by the mock server unless changed with [`MockServer::set_credits`].
+pub const DEFAULT_MOCK_CREDITS: i32 = 20;
+
+/// A response the mock server sends for an API method.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The JSON body. For objects, missing `errors` and `credits` fields are filled in.
+    pub body: Value,
+}
+
+impl MockResponse {
+    /// A `200 OK` response with the given JSON body.
+    pub fn ok(body: Value) -> Self {
+        MockResponse { status: 200, body }
+    }
+
+    /// An error response in the documented format:
+    /// `{"errors":[{"message":..,"code":..,"field":null}],"credits":..}`.
+    pub fn error(status: u16, code: i32, message: &str) -> Self {
+        MockResponse {
+            status,
+            body: json!({ "errors": [{ "message": message, "code": code, "field": null }] }),
+        }
+    }
+
+    /// A response with an arbitrary status and body, sent as-is apart from the
+    /// `errors`/`credits` defaults.
+    pub fn new(status: u16, body: Value) -> Self {
+        MockResponse { status, body }
+    }
+}
+
+/// A request received and authenticated by the mock server.
+#[derive(Debug, Clone)]
+pub struct MockRequest {
+    /// The API method name the request was routed to (e.g. `showRates`).
+    pub method_name: &'static str,
+    /// The HTTP method (GET, POST, DELETE).
+    pub http_method: String,
+    /// The request path, e.g. `/v4/btceur/rates`.
+    pub path: String,
+    /// Decoded values of the placeholders in the route, keyed without the leading ':'.
+    pub path_params: HashMap<String, String>,
+    /// Decoded query string parameters (GET/DELETE).
+    pub query: Vec<(String, String)>,
+    /// Decoded form body parameters (POST).
+    pub form: Vec<(String, String)>,
+    /// The `X-API-NONCE` header value.
+    pub nonce: u64,
+}
+
+impl MockRequest {
+    /// Looks up a parameter in the path, query and form parameters (in that order).
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.path_params
+            .get(name)
+            .map(String::as_str)
+            .or_else(|| self.query.iter().chain(self.form.iter()).find(|(key, _)| key == name).map(|(_, value)| value.as_str()))
+    }
+}
+
+/// A scripted response generator for an API method.
+pub type MockHandler = Arc<dyn Fn(&MockRequest) -> MockResponse + Send + Sync>;
+
+/// Shared state between the [`MockServer`] handle and the request handler.
+struct MockState {
+    api_key: String,
+    api_secret: String,
+    /// Scheme, host and port, e.g. "http://127.0.0.1:41234", used to rebuild the signed URL.
+    origin: String,
+    /// Path prefix of all routes, e.g. "/v4".
+    base_path: String,
+    credits: Mutex<i32>,
+    last_nonce: Mutex<u64>,
+    queued: Mutex<HashMap<&'static str, VecDeque<MockResponse>>>,
+    handlers: Mutex<HashMap<&'static str, MockHandler>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+/// An in-process mock of the Bitcoin.de Trading API v4.
+///
+/// The server runs on the current Tokio runtime and is shut down when the handle is dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<MockState>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Starts a mock server on a random port of `127.0.0.1` that accepts requests signed
+    /// with the given credentials.
+    pub async fn start(api_key: impl Into<String>, api_secret: impl Into<String>) -> Result<Self, Error> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
+        let addr = listener.local_addr()?;
+
+        let state = Arc::new(MockState {
+            api_key: api_key.into(),
+            api_secret: api_secret.into(),
+            origin: format!("http://{}", addr),
+            base_path: "/v4".to_string(),
+            credits: Mutex::new(DEFAULT_MOCK_CREDITS),
+            last_nonce: Mutex::new(0),
+            queued: Mutex::new(HashMap::new()),
+            handlers: Mutex::new(HashMap::new()),
+            requests: Mutex::new(Vec::new()),
+        });
+
+        let app = Router::new().fallback(handle_request).with_state(state.clone());
+        let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await;
+        });
+
+        Ok(MockServer { addr, state, shutdown: Some(shutdown_tx) })
+    }
+
+    /// The base URL to configure the client with, e.g. `http://127.0.0.1:41234/v4`.
+    pub fn base_url(&self) -> String {
+        format!("{}{}", self.state.origin, self.state.base_path)
+    }
+
+    /// The socket address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Creates a client pointed at this server, using the server's credentials.
+    pub fn client(&self) -> Result<TradingApiSdkV4, Error> {
+        TradingApiSdkV4::builder()
+            .api_key(self.state.api_key.clone())
+            .api_secret(self.state.api_secret.clone())
+            .base_url(self.base_url())
+            .build()
+    }
+
+    /// Creates a blocking client for this server, with matching credentials and base URL.
+    ///
+    /// The server keeps running on the runtime it was started on, so the blocking client
+    /// has to be used from a thread outside of that runtime.
+    #[cfg(feature = "blocking")]
+    pub fn blocking_client(&self) -> Result<crate::bitcoin_de_trading_api_sdk_v4::blocking::TradingApiSdkV4, Error> {
+        TradingApiSdkV4::builder()
+            .api_key(self.state.api_key.clone())
+            .api_secret(self.state.api_secret.clone())
+            .base_url(self.base_url())
+            .build_blocking()
+    }
+
+    /// Queues a one-shot response for the given API method (e.g. `METHOD_SHOW_RATES`).
+    ///
+    /// Queued responses are served in FIFO order before any handler or canned response.
+    pub fn enqueue(&self, method_name: &'static str, response: MockResponse) {
+        self.state.queued.lock().unwrap().entry(method_name).or_default().push_back(response);
+    }
+
+    /// Replaces the canned response of an API method with a fixed response.
+    pub fn set_response(&self, method_name: &'static str, response: MockResponse) {
+        self.set_handler(method_name, move |_| response.clone());
+    }
+
+    /// Replaces the canned response of an API method with a handler that can inspect the request.
+    pub fn set_handler<F>(&self, method_name: &'static str, handler: F)
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        self.state.handlers.lock().unwrap().insert(method_name, Arc::new(handler));
+    }
+
+    /// Removes all queued responses and handlers, restoring the canned responses.
+    pub fn reset(&self) {
+        self.state.queued.lock().unwrap().clear();
+        self.state.handlers.lock().unwrap().clear();
+    }
+
+    /// Sets the credits reported in responses.
+    pub fn set_credits(&self, credits: i32) {
+        *self.state.credits.lock().unwrap() = credits;
+    }
+
+    /// Returns all authenticated requests received so far, in order of arrival.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// Returns the authenticated requests received for the given API method.
+    pub fn requests_for(&self, method_name: &str) -> Vec<MockRequest> {
+        self.requests().into_iter().filter(|request| request.method_name == method_name).collect()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// Finds the most specific method setting matching the HTTP method and path segments.
+///
+/// Literal segments must match exactly, placeholders match any value and optional
+/// placeholders may be left out. If several settings match (e.g.
+/// `:currency/withdrawals/min_network_fee` and `:currency/withdrawals/:withdrawal_id`),
+/// the one with the most literal segments wins.
+fn match_route(http_method: &str, segments: &[&str]) -> Option<(&'static str, &'static MethodSetting, HashMap<String, String>)> {
+    let mut best: Option<(usize, &'static str, &'static MethodSetting, Vec<&'static str>)> = None;
+    for (method_name, setting) in METHOD_SETTINGS.iter() {
+        if setting.http_method != http_method {
+            continue;
+        }
+        // The path with all optional placeholders, and without them
+        let full: Vec<&'static str> = setting.path_segments.to_vec();
+        let required: Vec<&'static str> = full
+            .iter()
+            .copied()
+            .filter(|segment| !matches!(MethodSetting::placeholder(segment), Some((_, true))))
+            .collect();
+        for expected in [full, required] {
+            if expected.len() != segments.len() {
+                continue;
+            }
+            let mut literals = 0;
+            let matches = expected.iter().zip(segments).all(|(expected, actual)| {
+                if MethodSetting::placeholder(expected).is_some() {
+                    true
+                } else {
+                    literals += 1;
+                    expected == actual
+                }
+            });
+            if matches && best.as_ref().is_none_or(|(best_literals, _, _, _)| literals > *best_literals) {
+                best = Some((literals, method_name, setting, expected));
+            }
+        }
+    }
+
+    best.map(|(_, method_name, setting, expected)| {
+        let path_params = expected
+            .iter()
+            .zip(segments)
+            .filter_map(|(expected, actual)| {
+                let value = percent_encoding::percent_decode_str(actual).decode_utf8_lossy();
+                MethodSetting::placeholder(expected).map(|(name, _)| (name.to_string(), value.into_owned()))
+            })
+            .collect();
+        (method_name, setting, path_params)
+    })
+}
+
+/// Builds a JSON response, filling in `errors` and `credits` for object bodies.
+fn json_response(state: &MockState, response: MockResponse) -> Response {
+    let mut body = response.body;
+    if let Value::Object(map) = &mut body {
+        map.entry("errors").or_insert_with(|| json!([]));
+        map.entry("credits").or_insert_with(|| json!(*state.credits.lock().unwrap()));
+    }
+    let status = StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    (status, [("Content-Type", "application/json")], body.to_string()).into_response()
+}
+
+/// Returns the value of a header, if present and valid UTF-8.
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// Routes, authenticates and answers a single request.
+async fn handle_request(
+    State(state): State<Arc<MockState>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let http_method = method.as_str().to_string();
+    let path = uri.path().to_string();
+
+    // 1. Route
+    let route = path
+        .strip_prefix(&state.base_path)
+        .map(|rest| rest.trim_matches('/').split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>())
+        .and_then(|segments| match_route(&http_method, &segments));
+    let Some((method_name, setting, path_params)) = route else {
+        return json_response(&state, MockResponse::error(404, ERROR_CODE_UNKNOWN_API_METHOD, "Unknown API method"));
+    };
+
+    // 2. Authenticate
+    let (Some(api_key), Some(nonce), Some(signature)) = (
+        header(&headers, HEADER_X_API_KEY),
+        header(&headers, HEADER_X_NONCE),
+        header(&headers, HEADER_X_API_SIGNATURE),
+    ) else {
+        return json_response(&state, MockResponse::error(401, ERROR_CODE_MISSING_HEADER, "Missing header"));
+    };
+    if api_key != state.api_key {
+        return json_response(&state, MockResponse::error(401, ERROR_CODE_INACTIVE_API_KEY, "Inactive API key"));
+    }
+
+    let query: Vec<(String, String)> = serde_urlencoded::from_str(uri.query().unwrap_or("")).unwrap_or_default();
+    let form: Vec<(String, String)> = if setting.http_method == "POST" {
+        serde_urlencoded::from_bytes(&body).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+
+    // GET/DELETE sign the full URL including the query string, POST signs the URL and the body
+    let url_for_signature = match uri.path_and_query() {
+        Some(path_and_query) if setting.http_method != "POST" => format!("{}{}", state.origin, path_and_query),
+        _ => format!("{}{}", state.origin, path),
+    };
+    let form_for_signature: Vec<(&str, &str)> = form.iter().map(|(key, value)| (key.as_str(), value.as_str())).collect();
+    let valid_signature = verify_signature(
+        setting.http_method,
+        &url_for_signature,
+        &state.api_key,
+        &state.api_secret,
+        nonce,
+        &form_for_signature,
+        signature,
+    );
+    if !valid_signature {
+        return json_response(&state, MockResponse::error(401, ERROR_CODE_WRONG_SIGNATURE, "Invalid signature"));
+    }
+
+    // Nonces must be strictly increasing, as on the real API
+    let nonce = {
+        let mut last_nonce = state.last_nonce.lock().unwrap();
+        match nonce.parse::<u64>() {
+            Ok(nonce) if nonce > *last_nonce => {
+                *last_nonce = nonce;
+                nonce
+            }
+            _ => {
+                drop(last_nonce);
+                return json_response(&state, MockResponse::error(401, ERROR_CODE_INVALID_NONCE, "Invalid nonce"));
+            }
+        }
+    };
+
+    let request = MockRequest { method_name, http_method, path, path_params, query, form, nonce };
+    state.requests.lock().unwrap().push(request.clone());
+
+    // 3. Respond: queued response, then handler, then canned fixture
+    let queued = state.queued.lock().unwrap().get_mut(method_name).and_then(VecDeque::pop_front);
+    let response = match queued {
+        Some(response) => response,
+        None => {
+            let handler = state.handlers.lock().unwrap().get(method_name).cloned();
+            match handler {
+                Some(handler) => handler(&request),
+                None => MockResponse::ok(fixtures::canned_response(method_name, &request)),
+            }
+        }
+    };
+    json_response(&state, response)
+}
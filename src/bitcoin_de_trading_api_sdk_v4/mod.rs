@@ -10,12 +10,37 @@ pub mod constants;
 /// Defines the various errors that can be returned by the SDK.
 pub mod errors;
 
+/// Typed API error codes
+///
+/// The `ApiErrorCode` enum, generated from the `ERROR_CODE_*` constants, to branch
+/// on the reasons an API request failed.
+pub mod error_codes;
+pub use error_codes::ApiErrorCode;
+
 /// Enumeration types used throughout the Bitcoin.de Trading API
 ///
 /// Contains various enum definitions that represent different states, types,
-/// and options available in the Bitcoin.de Trading API.
+/// and options available in the Bitcoin.de Trading API. The string based enums parse
+/// case-insensitively and (de)serialize with serde, so they can be used in response types
+/// and configuration files directly.
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{Currency, OrderType, TradingPair};
+///
+/// let pair: TradingPair = "btceur".parse().unwrap();
+/// assert_eq!(pair, TradingPair::BTCEUR);
+/// assert_eq!(OrderType::try_from("sell").unwrap(), OrderType::Sell);
+/// assert_eq!(Currency::iter().count(), Currency::ALL.len());
+/// assert_eq!(serde_json::from_str::<TradingPair>("\"etheur\"").unwrap(), TradingPair::ETHEUR);
+/// ```
 pub mod enums;
 pub use enums::*;
+
+/// Currency and trading pair metadata
+///
+/// Static `CurrencyInfo` and `PairInfo` tables with the precision of each currency and the
+/// settlement of each trading pair. Minimum order amounts are enforced only by the API.
+pub mod market_info;
 /// Settings for API method calls
 ///
 /// Provides configuration structures and options that can be used to customize
@@ -34,12 +59,78 @@ pub mod config;
 /// handling, authentication, and the various API endpoints.
 pub mod trading_api_sdk_v4;
 
+/// Trait abstraction of the API methods
+///
+/// The `BitcoinDeApi` trait implemented by `TradingApiSdkV4`, so strategy code can be
+/// tested against fakes or run against alternative backends.
+pub mod api;
+pub use api::BitcoinDeApi;
+
+/// Builder for customized Trading API clients
+///
+/// Allows configuring the base URL, a pre-configured HTTP client, timeouts,
+/// user agent and proxy settings before creating a `TradingApiSdkV4`.
+pub mod builder;
+
+/// Nonce generation
+///
+/// The `NonceProvider` trait with a strictly increasing in-memory default and a
+/// file-backed implementation that never reuses a nonce across restarts.
+pub mod nonce;
+
+/// Retry policy for transient failures
+///
+/// Opt-in exponential backoff with jitter for timeouts, rate limiting, server errors
+/// and nonce collisions, without blindly repeating non-idempotent requests.
+pub mod retry;
+
+/// Credit budget tracking
+///
+/// The `CreditFloor` policy, which keeps the estimated credit balance of the API key
+/// above a reserve by delaying or rejecting requests before the API refuses them.
+pub mod credits;
+
+/// Request signing
+///
+/// `sign_request` and `verify_signature` for the `X-API-SIGNATURE` header, with
+/// documented test vectors, shared by the clients and the mock server.
+pub mod signature;
+
+/// Recording and replay of API calls
+///
+/// JSONL cassettes of requests and responses written by a recording client, replayed
+/// without network to reproduce incidents or used as fixtures for the response types.
+pub mod cassette;
+
+/// Pagination of list methods
+///
+/// The `Paged` trait implemented by the paged responses, used by the `*_stream` and
+/// `*_all` methods to walk through all pages.
+pub mod pagination;
+
+/// Synchronous client
+///
+/// A blocking `TradingApiSdkV4` on top of `reqwest::blocking` for scripts and FFI
+/// consumers without an async runtime, sharing signing, method settings and response types.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+/// In-process mock of the Trading API
+///
+/// A local HTTP server implementing all API routes with signature verification
+/// and canned or scripted responses, for integration tests without real credentials.
+#[cfg(feature = "mock")]
+pub mod mock;
+
 /// Re-export of the main Trading API SDK client
 ///
 /// This is the primary entry point for interacting with the Bitcoin.de
 /// Trading API v4.
 pub use trading_api_sdk_v4::TradingApiSdkV4;
 
+/// Re-export of the client builder
+pub use builder::TradingApiSdkV4Builder;
+
 /// Re-export of the custom Error type
 ///
 /// Makes the custom error type from the `errors` module directly available
@@ -48,3 +139,10 @@ pub use trading_api_sdk_v4::TradingApiSdkV4;
 pub use errors::Error;
 pub mod responses;
 pub use responses::*;
+
+/// Typed request parameters
+///
+/// Request structs that validate their values locally and convert them into the
+/// parameters of the corresponding API method.
+pub mod requests;
+pub use requests::*;
@@ -0,0 +1,189 @@
+// bitcoin_de_trading_api_sdk_v4/nonce.rs
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+/// Source of the `X-API-NONCE` values.
+///
+/// The API rejects a request whose nonce is not greater than the last nonce used with the
+/// same API key (`ERROR_CODE_INVALID_NONCE`). Implementations must therefore return strictly
+/// increasing values, also when called concurrently from several tasks or threads.
+///
+/// To share one provider between several clients using the same API key, pass an
+/// `Arc` of it to each builder.
+pub trait NonceProvider: Send + Sync {
+    /// Returns the nonce for the next request.
+    fn next_nonce(&self) -> Result<u64, Error>;
+}
+
+impl<T: NonceProvider + ?Sized> NonceProvider for Arc<T> {
+    fn next_nonce(&self) -> Result<u64, Error> {
+        (**self).next_nonce()
+    }
+}
+
+/// Returns the current time in microseconds since the Unix epoch.
+fn now_micros() -> Result<u64, Error> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_micros() as u64)
+}
+
+/// The default nonce provider: the current time in microseconds, but always greater than
+/// the previously returned nonce.
+///
+/// Concurrent requests never get the same nonce, and if the system clock steps back the
+/// nonces keep increasing from the last value until the clock has caught up again.
+/// The state lives in memory only; see [`FileNonceProvider`] to survive restarts that
+/// coincide with a clock step back.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::nonce::{AtomicNonceProvider, NonceProvider};
+///
+/// let provider = AtomicNonceProvider::new();
+/// let first = provider.next_nonce().unwrap();
+/// let second = provider.next_nonce().unwrap();
+/// assert!(second > first);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicNonceProvider {
+    last: AtomicU64,
+}
+
+impl AtomicNonceProvider {
+    /// Creates a provider starting at the current time.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a provider whose nonces are all greater than `last_nonce`.
+    pub fn starting_after(last_nonce: u64) -> Self {
+        AtomicNonceProvider { last: AtomicU64::new(last_nonce) }
+    }
+}
+
+impl NonceProvider for AtomicNonceProvider {
+    fn next_nonce(&self) -> Result<u64, Error> {
+        let now = now_micros()?;
+        let mut last = self.last.load(Ordering::Acquire);
+        loop {
+            let next = now.max(last + 1);
+            match self.last.compare_exchange_weak(last, next, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => return Ok(next),
+                Err(current) => last = current, // Another thread was faster, try again
+            }
+        }
+    }
+}
+
+/// A nonce provider that persists a high-water mark in a file, so a restarted process
+/// never reuses a nonce, even if the system clock was set back in the meantime.
+///
+/// To avoid a disk write per request, the provider reserves blocks of nonces: before it
+/// hands out a nonce beyond the persisted mark, it writes a new mark `reservation` higher
+/// (default: one second worth of microseconds). After a restart, nonces continue above
+/// the persisted mark.
+///
+/// Use one file per API key and do not share it between running processes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::nonce::FileNonceProvider;
+///
+/// let client = TradingApiSdkV4::builder()
+///     .api_key("your_api_key")
+///     .api_secret("your_api_secret")
+///     .nonce_provider(FileNonceProvider::open("/var/lib/my-bot/nonce").unwrap())
+///     .build()
+///     .unwrap();
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct FileNonceProvider {
+    path: PathBuf,
+    reservation: u64,
+    state: Mutex<FileNonceState>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+struct FileNonceState {
+    last: u64,     // The last nonce handed out
+    reserved: u64, // The high-water mark persisted in the file
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileNonceProvider {
+    /// Default number of nonces reserved per file write (one second in microseconds).
+    pub const DEFAULT_RESERVATION: u64 = 1_000_000;
+
+    /// Opens or creates the nonce file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Io` - if the file exists but cannot be read.
+    /// * `Error::Other` - if the file does not contain a valid nonce.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::with_reservation(path, Self::DEFAULT_RESERVATION)
+    }
+
+    /// Opens or creates the nonce file at `path`, reserving `reservation` nonces per file write.
+    pub fn with_reservation(path: impl AsRef<Path>, reservation: u64) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let persisted = match std::fs::read_to_string(&path) {
+            Ok(content) => content
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| Error::Other(format!("Invalid nonce in {}: {:?}", path.display(), content.trim())))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e.into()),
+        };
+        Ok(FileNonceProvider {
+            path,
+            reservation: reservation.max(1),
+            state: Mutex::new(FileNonceState { last: persisted, reserved: persisted }),
+        })
+    }
+
+    /// Returns the path of the nonce file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Writes the high-water mark atomically (temporary file and rename) and syncs it to disk.
+    fn persist(&self, reserved: u64) -> Result<(), Error> {
+        use std::io::Write;
+
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let mut file = std::fs::File::create(&tmp_path)?;
+        file.write_all(reserved.to_string().as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl NonceProvider for FileNonceProvider {
+    fn next_nonce(&self) -> Result<u64, Error> {
+        let mut state = self.state.lock().map_err(|_| Error::Other("Nonce state lock poisoned".to_string()))?;
+        let next = now_micros()?.max(state.last + 1);
+        if next > state.reserved {
+            // Persist before handing out the nonce, so it can never be reused after a restart
+            let reserved = next + self.reservation;
+            self.persist(reserved)?;
+            state.reserved = reserved;
+        }
+        state.last = next;
+        Ok(next)
+    }
+}
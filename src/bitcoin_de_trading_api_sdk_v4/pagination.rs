@@ -0,0 +1,158 @@
+// bitcoin_de_trading_api_sdk_v4/pagination.rs
+use std::collections::HashMap;
+use std::future::Future;
+
+use futures_util::stream::{self, Stream, TryStreamExt};
+
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::PARAMETER_PAGE;
+use crate::bitcoin_de_trading_api_sdk_v4::responses::*;
+
+/// A response of a paged API method: one page of items plus the `page` details.
+///
+/// Implemented for the responses of `showMyOrders`, `showMyTrades`, `showAccountLedger`,
+/// `showWithdrawals`, `showDeposits`, `showOutgoingAddresses` and `listAddressPool`.
+pub trait Paged {
+    /// The type of the listed items.
+    type Item;
+
+    /// Returns the current and the last page number.
+    fn page_details(&self) -> &PageDetails;
+
+    /// Consumes the response, returning the items of this page.
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl Paged for ShowMyOrdersResponse {
+    type Item = MyOrderDetails;
+
+    fn page_details(&self) -> &PageDetails {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.orders
+    }
+}
+
+impl Paged for ShowMyTradesResponse {
+    type Item = MyTradeDetails;
+
+    fn page_details(&self) -> &PageDetails {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.trades
+    }
+}
+
+impl Paged for ShowAccountLedgerResponse {
+    type Item = LedgerEntry;
+
+    fn page_details(&self) -> &PageDetails {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.account_ledger
+    }
+}
+
+impl Paged for ShowWithdrawalsResponse {
+    type Item = WithdrawalDetails;
+
+    fn page_details(&self) -> &PageDetails {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.withdrawals
+    }
+}
+
+impl Paged for ShowDepositsResponse {
+    type Item = DepositDetails;
+
+    fn page_details(&self) -> &PageDetails {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.deposits
+    }
+}
+
+// Also the response of listAddressPool
+impl Paged for ShowOutgoingAddressesResponse {
+    type Item = OutgoingAddressDetails;
+
+    fn page_details(&self) -> &PageDetails {
+        &self.page
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.outgoing_address
+    }
+}
+
+/// Returns a copy of `params` requesting the given page.
+pub(crate) fn with_page(params: &HashMap<&'static str, String>, page: i32) -> HashMap<&'static str, String> {
+    let mut params = params.clone();
+    params.insert(PARAMETER_PAGE, page.to_string());
+    params
+}
+
+/// Turns a page fetching function into a stream of the items of all pages.
+///
+/// Pages are requested lazily, starting at page 1, when the items of the previous page
+/// have been consumed. The stream ends after the last page reported by the API, or
+/// after the first error.
+pub(crate) fn paginate<'a, R, F, Fut>(mut fetch_page: F) -> impl Stream<Item = Result<R::Item, Error>> + 'a
+where
+    R: Paged + 'a,
+    R::Item: 'a,
+    F: FnMut(i32) -> Fut + 'a,
+    Fut: Future<Output = Result<R, Error>> + 'a,
+{
+    stream::try_unfold(Some(1), move |next_page: Option<i32>| {
+        let request = next_page.map(|page| (page, fetch_page(page)));
+        async move {
+            let Some((page, request)) = request else {
+                return Ok(None); // The last page has been fetched
+            };
+            let response = request.await?;
+            // Count from the requested page, so a server echoing a wrong page number cannot loop forever
+            let next_page = (page < response.page_details().last).then_some(page + 1);
+            Ok::<_, Error>(Some((response.into_items(), next_page)))
+        }
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Like [`paginate`], but for the blocking client: an iterator fetching the next page
+/// when the items of the previous one have been consumed.
+#[cfg(feature = "blocking")]
+pub(crate) fn paginate_blocking<R, F>(mut fetch_page: F) -> impl Iterator<Item = Result<R::Item, Error>>
+where
+    R: Paged,
+    F: FnMut(i32) -> Result<R, Error>,
+{
+    let mut next_page = Some(1);
+    let mut items = Vec::new().into_iter();
+    std::iter::from_fn(move || loop {
+        if let Some(item) = items.next() {
+            return Some(Ok(item));
+        }
+        // Taking the page ends the iterator after the last page, or after the first error
+        let page = next_page.take()?;
+        match fetch_page(page) {
+            Ok(response) => {
+                next_page = (page < response.page_details().last).then_some(page + 1);
+                items = response.into_items().into_iter();
+            }
+            Err(error) => return Some(Err(error)),
+        }
+    })
+}
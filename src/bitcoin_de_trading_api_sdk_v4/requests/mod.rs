@@ -0,0 +1,177 @@
+// bitcoin_de_trading_api_sdk_v4/requests/mod.rs
+//! Typed request parameters for API methods with non-trivial inputs.
+//!
+//! Each request struct validates its values locally and converts them into the
+//! form/query parameters expected by the API, so callers do not need to know the
+//! raw parameter names.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+
+use crate::bitcoin_de_trading_api_sdk_v4::enums::Currency;
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+mod order;
+mod orderbook;
+mod trades;
+
+pub use order::*;
+pub use orderbook::*;
+pub use trades::*;
+
+/// Parameters of a request, keyed by the parameter constants or by keys built per
+/// request, like the `seat_of_bank[n]` entries of an array.
+pub(crate) type RequestParameters = HashMap<Cow<'static, str>, String>;
+
+/// Converts the parameters passed to `TradingApiSdkV4::do_request`.
+pub(crate) fn request_parameters(parameters: Option<HashMap<&'static str, String>>) -> RequestParameters {
+    parameters.into_iter().flatten().map(|(name, value)| (Cow::Borrowed(name), value)).collect()
+}
+
+/// Formats a boolean the way the API expects it in form and query parameters.
+pub(crate) fn bool_param(value: bool) -> String {
+    if value { "1" } else { "0" }.to_string()
+}
+
+/// Formats an amount or price in `currency` for a form or query parameter.
+///
+/// Values are written from the `Decimal` itself, never via `f64`, with trailing zeros
+/// removed (`0.50` becomes `0.5`).
+///
+/// # Errors
+///
+/// Returns `Error::InvalidParameter` if the value has more decimal places than the
+/// currency supports (see [`Currency::decimals`]), instead of silently rounding it.
+pub(crate) fn decimal_param(name: &'static str, value: Decimal, currency: Currency) -> Result<String, Error> {
+    let value = value.normalize();
+    if value.scale() > currency.decimals() {
+        return Err(Error::InvalidParameter(
+            name,
+            format!("{} has more than {} decimal places, the precision of {}", value, currency.decimals(), currency),
+        ));
+    }
+    Ok(value.to_string())
+}
+
+/// Formats an amount, price or volume that must be greater than zero, like
+/// [`decimal_param`], for the API methods without a typed request.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidParameter` if the value is zero or negative, or has more
+/// decimal places than the currency supports.
+pub(crate) fn positive_decimal_param(name: &'static str, value: Decimal, currency: Currency) -> Result<String, Error> {
+    if value <= Decimal::ZERO {
+        return Err(Error::InvalidParameter(name, "must be positive".to_string()));
+    }
+    decimal_param(name, value, currency)
+}
+
+/// An ISO 3166-1 alpha-2 country code in upper case (e.g. "DE"), as used for the
+/// `seat_of_bank` criteria of orders and the orderbook.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::CountryCode;
+///
+/// let code: CountryCode = "AT".parse().unwrap();
+/// assert_eq!(code.as_str(), "AT");
+/// assert!("at".parse::<CountryCode>().is_err());
+/// assert!(CountryCode::try_from("AUT").is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct CountryCode([u8; 2]);
+
+impl CountryCode {
+    /// Returns the two-letter code.
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("country codes consist of ASCII letters")
+    }
+}
+
+impl std::fmt::Display for CountryCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for CountryCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.as_bytes() {
+            [first, second] if first.is_ascii_uppercase() && second.is_ascii_uppercase() => Ok(CountryCode([*first, *second])),
+            _ => Err(Error::Other(format!("Invalid CountryCode: {}", s))),
+        }
+    }
+}
+
+impl TryFrom<&str> for CountryCode {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<CountryCode> for String {
+    fn from(code: CountryCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+/// Validates the `seat_of_bank` countries of a request and returns them as the
+/// `seat_of_bank[n]` parameters of the array `name`.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidParameter` for `name` if a country is listed twice.
+pub(crate) fn seat_of_bank_params(name: &'static str, countries: &[CountryCode]) -> Result<Vec<(Cow<'static, str>, String)>, Error> {
+    for (index, code) in countries.iter().enumerate() {
+        if countries[..index].contains(code) {
+            return Err(Error::InvalidParameter(name, format!("'{}' is listed twice", code)));
+        }
+    }
+    // Arrays are sent as seat_of_bank[0]=DE&seat_of_bank[1]=AT
+    Ok(countries
+        .iter()
+        .enumerate()
+        .map(|(index, code)| (Cow::Owned(format!("{}[{}]", name, index)), String::from(*code)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decimals_beyond_the_currency_precision_are_rejected() {
+        assert!(matches!(decimal_param("price", Decimal::new(25000125, 3), Currency::EUR), Err(Error::InvalidParameter("price", _))));
+        assert_eq!(decimal_param("price", Decimal::new(2500012, 2), Currency::EUR).unwrap(), "25000.12");
+        assert!(decimal_param("amount", Decimal::new(123456789, 9), Currency::BTC).is_err());
+        assert_eq!(decimal_param("amount", Decimal::new(12345678, 8), Currency::BTC).unwrap(), "0.12345678");
+    }
+
+    #[test]
+    fn trailing_zeros_are_removed() {
+        assert_eq!(decimal_param("amount", Decimal::new(150000000, 8), Currency::BTC).unwrap(), "1.5");
+        assert_eq!(decimal_param("price", Decimal::new(2500000, 2), Currency::EUR).unwrap(), "25000");
+        // Zeros beyond the precision do not count as decimal places
+        assert_eq!(decimal_param("price", Decimal::new(1230000, 5), Currency::EUR).unwrap(), "12.3");
+    }
+
+    #[test]
+    fn positive_decimal_param_rejects_zero_and_negative_values() {
+        for value in [Decimal::ZERO, Decimal::new(-1, 1)] {
+            match positive_decimal_param("amount", value, Currency::BTC) {
+                Err(Error::InvalidParameter(name, reason)) => assert_eq!((name, reason.as_str()), ("amount", "must be positive")),
+                other => panic!("expected a rejection of {}, got {:?}", value, other),
+            }
+        }
+        assert!(positive_decimal_param("amount", Decimal::new(1, 9), Currency::BTC).is_err());
+        assert_eq!(positive_decimal_param("amount", Decimal::new(1, 8), Currency::BTC).unwrap(), "0.00000001");
+    }
+}
@@ -0,0 +1,307 @@
+// bitcoin_de_trading_api_sdk_v4/requests/order.rs
+use chrono::{DateTime, SecondsFormat, Utc};
+use rust_decimal::Decimal;
+
+use super::{bool_param, decimal_param, seat_of_bank_params, CountryCode, RequestParameters};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{OrderState, OrderType, PaymentOption, SepaOption, TradingPair, TrustLevel};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
+
+/// Parameters for the `createOrder` API method.
+///
+/// Created with [`CreateOrderRequest::new`] from the required values; optional
+/// requirements are added with the chained setters. The request is validated
+/// locally before it is sent.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, PaymentOption, TrustLevel};
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::CreateOrderRequest;
+/// use rust_decimal::Decimal;
+///
+/// # fn main() -> Result<(), bitcoin_de::Error> {
+/// let request = CreateOrderRequest::new(OrderType::Buy, Decimal::new(5, 1), Decimal::new(2500000, 2))
+///     .min_amount_currency_to_trade(Decimal::new(1, 1))
+///     .min_trust_level(TrustLevel::Gold)
+///     .seat_of_bank(["DE".parse()?, "AT".parse()?])
+///     .payment_option(PaymentOption::SepaOnly);
+/// assert!(request.validate().is_ok());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateOrderRequest {
+    /// Buy or sell order.
+    pub order_type: OrderType,
+    /// Maximum amount of the currency to trade (e.g. BTC for btceur).
+    pub max_amount_currency_to_trade: Decimal,
+    /// Price per unit in the currency to pay (e.g. EUR for btceur).
+    pub price: Decimal,
+    /// Minimum amount a trading partner has to buy/sell.
+    pub min_amount_currency_to_trade: Option<Decimal>,
+    /// Point in time the order expires.
+    pub end_datetime: Option<DateTime<Utc>>,
+    /// Whether a new order is created for the remaining amount after a partial trade.
+    pub new_order_for_remaining_amount: Option<bool>,
+    /// Minimum trust level of the trading partner.
+    pub min_trust_level: Option<TrustLevel>,
+    /// Only trade with fully identified (KYC) trading partners.
+    pub only_kyc_full: Option<bool>,
+    /// Allowed countries of the trading partner's bank.
+    pub seat_of_bank: Vec<CountryCode>,
+    /// Accepted payment options.
+    pub payment_option: Option<PaymentOption>,
+    /// SEPA option of the order.
+    pub sepa_option: Option<SepaOption>,
+}
+
+impl CreateOrderRequest {
+    /// Creates a request with the required parameters and no further requirements.
+    pub fn new(order_type: OrderType, max_amount_currency_to_trade: Decimal, price: Decimal) -> Self {
+        CreateOrderRequest {
+            order_type,
+            max_amount_currency_to_trade,
+            price,
+            min_amount_currency_to_trade: None,
+            end_datetime: None,
+            new_order_for_remaining_amount: None,
+            min_trust_level: None,
+            only_kyc_full: None,
+            seat_of_bank: Vec::new(),
+            payment_option: None,
+            sepa_option: None,
+        }
+    }
+
+    /// Sets the minimum amount a trading partner has to buy/sell.
+    pub fn min_amount_currency_to_trade(mut self, min_amount: Decimal) -> Self {
+        self.min_amount_currency_to_trade = Some(min_amount);
+        self
+    }
+
+    /// Sets the point in time the order expires.
+    pub fn end_datetime(mut self, end_datetime: DateTime<Utc>) -> Self {
+        self.end_datetime = Some(end_datetime);
+        self
+    }
+
+    /// Sets whether a new order is created for the remaining amount after a partial trade.
+    pub fn new_order_for_remaining_amount(mut self, enabled: bool) -> Self {
+        self.new_order_for_remaining_amount = Some(enabled);
+        self
+    }
+
+    /// Sets the minimum trust level of the trading partner.
+    pub fn min_trust_level(mut self, trust_level: TrustLevel) -> Self {
+        self.min_trust_level = Some(trust_level);
+        self
+    }
+
+    /// Restricts the order to fully identified (KYC) trading partners.
+    pub fn only_kyc_full(mut self, only_kyc_full: bool) -> Self {
+        self.only_kyc_full = Some(only_kyc_full);
+        self
+    }
+
+    /// Sets the allowed countries of the trading partner's bank.
+    pub fn seat_of_bank(mut self, countries: impl IntoIterator<Item = CountryCode>) -> Self {
+        self.seat_of_bank = countries.into_iter().collect();
+        self
+    }
+
+    /// Sets the accepted payment options.
+    pub fn payment_option(mut self, payment_option: PaymentOption) -> Self {
+        self.payment_option = Some(payment_option);
+        self
+    }
+
+    /// Sets the SEPA option of the order.
+    pub fn sepa_option(mut self, sepa_option: SepaOption) -> Self {
+        self.sepa_option = Some(sepa_option);
+        self
+    }
+
+    /// Checks the parameters locally, without contacting the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if
+    /// * the amount or price is not positive,
+    /// * the minimum amount is not positive or exceeds the maximum amount,
+    /// * the end datetime is not in the future,
+    /// * a seat of bank is listed twice.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.max_amount_currency_to_trade <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(CREATE_ORDER_PARAMETER_MAX_AMOUNT, "must be positive".to_string()));
+        }
+        if self.price <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(CREATE_ORDER_PARAMETER_PRICE, "must be positive".to_string()));
+        }
+        if let Some(min_amount) = self.min_amount_currency_to_trade {
+            if min_amount <= Decimal::ZERO {
+                return Err(Error::InvalidParameter(CREATE_ORDER_PARAMETER_MIN_AMOUNT, "must be positive".to_string()));
+            }
+            if min_amount > self.max_amount_currency_to_trade {
+                return Err(Error::InvalidParameter(
+                    CREATE_ORDER_PARAMETER_MIN_AMOUNT,
+                    format!("{} exceeds the maximum amount {}", min_amount, self.max_amount_currency_to_trade),
+                ));
+            }
+        }
+        if let Some(end_datetime) = self.end_datetime {
+            if end_datetime <= Utc::now() {
+                return Err(Error::InvalidParameter(CREATE_ORDER_PARAMETER_END_DATETIME, "must be in the future".to_string()));
+            }
+        }
+        seat_of_bank_params(CREATE_ORDER_PARAMETER_SEAT_OF_BANK, &self.seat_of_bank)?;
+        Ok(())
+    }
+
+    /// Validates the request and converts it into the form parameters of `createOrder`.
+    ///
+    /// Amounts with more decimal places than the pair's base currency supports, and prices
+    /// with more than its quote currency supports, are rejected rather than rounded. The
+    /// minimum order amount is left to the API. The trading pair itself is not included, it
+    /// is added by `TradingApiSdkV4::create_order`.
+    pub(crate) fn to_parameters(&self, trading_pair: TradingPair) -> Result<RequestParameters, Error> {
+        self.validate()?;
+
+        let (base, quote) = (trading_pair.base(), trading_pair.quote());
+        let mut params = RequestParameters::new();
+        params.insert(CREATE_ORDER_PARAMETER_TYPE.into(), self.order_type.to_string());
+        params.insert(CREATE_ORDER_PARAMETER_MAX_AMOUNT.into(), decimal_param(CREATE_ORDER_PARAMETER_MAX_AMOUNT, self.max_amount_currency_to_trade, base)?);
+        params.insert(CREATE_ORDER_PARAMETER_PRICE.into(), decimal_param(CREATE_ORDER_PARAMETER_PRICE, self.price, quote)?);
+        if let Some(min_amount) = self.min_amount_currency_to_trade {
+            params.insert(CREATE_ORDER_PARAMETER_MIN_AMOUNT.into(), decimal_param(CREATE_ORDER_PARAMETER_MIN_AMOUNT, min_amount, base)?);
+        }
+        if let Some(end_datetime) = self.end_datetime {
+            params.insert(CREATE_ORDER_PARAMETER_END_DATETIME.into(), end_datetime.to_rfc3339_opts(SecondsFormat::Secs, false));
+        }
+        if let Some(enabled) = self.new_order_for_remaining_amount {
+            params.insert(CREATE_ORDER_PARAMETER_NEW_ORDER_FOR_REMAINING_AMOUNT.into(), bool_param(enabled));
+        }
+        if let Some(trust_level) = self.min_trust_level {
+            params.insert(CREATE_ORDER_PARAMETER_MIN_TRUST_LEVEL.into(), trust_level.to_string());
+        }
+        if let Some(only_kyc_full) = self.only_kyc_full {
+            params.insert(CREATE_ORDER_PARAMETER_ONLY_KYC_FULL.into(), bool_param(only_kyc_full));
+        }
+        params.extend(seat_of_bank_params(CREATE_ORDER_PARAMETER_SEAT_OF_BANK, &self.seat_of_bank)?);
+        if let Some(payment_option) = self.payment_option {
+            params.insert(CREATE_ORDER_PARAMETER_ORDER_PAYMENT_OPTIONS.into(), payment_option.to_string());
+        }
+        if let Some(sepa_option) = self.sepa_option {
+            params.insert(CREATE_ORDER_PARAMETER_SEPA_OPTION.into(), sepa_option.to_string());
+        }
+        Ok(params)
+    }
+}
+
+/// Filter for the `showMyOrders` API method.
+///
+/// All criteria are optional; the default filter lists the open orders, as the API does
+/// without a `state` parameter.
+///
+/// # Examples
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::OrderType;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::MyOrdersFilter;
+///
+/// let filter = MyOrdersFilter::new()
+///     .order_type(OrderType::Sell)
+///     .date_start(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+/// assert!(filter.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MyOrdersFilter {
+    /// Only buy or only sell orders.
+    pub order_type: Option<OrderType>,
+    /// Only orders in this state.
+    pub state: Option<OrderState>,
+    /// Only orders created at or after this point in time.
+    pub date_start: Option<DateTime<Utc>>,
+    /// Only orders created before this point in time.
+    pub date_end: Option<DateTime<Utc>>,
+    /// The page to fetch, starting at 1. Ignored by `show_my_orders_stream` and `show_my_orders_all`.
+    pub page: Option<u32>,
+}
+
+impl MyOrdersFilter {
+    /// Creates a filter without any criteria.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lists only buy or only sell orders.
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = Some(order_type);
+        self
+    }
+
+    /// Lists only orders in the given state.
+    pub fn state(mut self, state: OrderState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Lists only orders created at or after `date_start`.
+    pub fn date_start(mut self, date_start: DateTime<Utc>) -> Self {
+        self.date_start = Some(date_start);
+        self
+    }
+
+    /// Lists only orders created before `date_end`.
+    pub fn date_end(mut self, date_end: DateTime<Utc>) -> Self {
+        self.date_end = Some(date_end);
+        self
+    }
+
+    /// Selects the page to fetch, starting at 1.
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Checks the criteria locally, without contacting the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if `date_end` is before `date_start` or `page` is 0.
+    pub fn validate(&self) -> Result<(), Error> {
+        if let (Some(date_start), Some(date_end)) = (self.date_start, self.date_end) {
+            if date_end < date_start {
+                return Err(Error::InvalidParameter(SHOW_MY_ORDERS_PARAMETER_DATE_END, "must not be before date_start".to_string()));
+            }
+        }
+        if self.page == Some(0) {
+            return Err(Error::InvalidParameter(PARAMETER_PAGE, "pages start at 1".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Validates the filter and converts it into the query parameters of `showMyOrders`.
+    pub(crate) fn to_parameters(&self) -> Result<RequestParameters, Error> {
+        self.validate()?;
+
+        let mut params = RequestParameters::new();
+        if let Some(order_type) = self.order_type {
+            params.insert(SHOW_MY_ORDERS_PARAMETER_TYPE.into(), order_type.to_string());
+        }
+        if let Some(state) = self.state {
+            params.insert(SHOW_MY_ORDERS_PARAMETER_STATE.into(), state.to_string());
+        }
+        if let Some(date_start) = self.date_start {
+            params.insert(SHOW_MY_ORDERS_PARAMETER_DATE_START.into(), date_start.to_rfc3339_opts(SecondsFormat::Secs, false));
+        }
+        if let Some(date_end) = self.date_end {
+            params.insert(SHOW_MY_ORDERS_PARAMETER_DATE_END.into(), date_end.to_rfc3339_opts(SecondsFormat::Secs, false));
+        }
+        if let Some(page) = self.page {
+            params.insert(PARAMETER_PAGE.into(), page.to_string());
+        }
+        Ok(params)
+    }
+}
@@ -0,0 +1,204 @@
+// bitcoin_de_trading_api_sdk_v4/requests/orderbook.rs
+use rust_decimal::Decimal;
+
+use super::{bool_param, decimal_param, seat_of_bank_params, CountryCode, RequestParameters};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, PaymentOption, SepaOption, TradingPair, TrustLevel};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
+
+/// Query for the `showOrderbook` API method.
+///
+/// The order type is required: `type=buy` lists the sell orders you can buy from,
+/// `type=sell` the buy orders you can sell to. All other criteria are optional.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, TrustLevel};
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::OrderbookQuery;
+/// use rust_decimal::Decimal;
+///
+/// # fn main() -> Result<(), bitcoin_de::Error> {
+/// let query = OrderbookQuery::new(OrderType::Buy)
+///     .amount_currency_to_trade(Decimal::new(5, 1))
+///     .only_kyc_full(true)
+///     .min_trust_level(TrustLevel::Gold)
+///     .seat_of_bank(["DE".parse()?, "AT".parse()?]);
+/// assert!(query.validate().is_ok());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderbookQuery {
+    /// Buy (lists sell orders) or sell (lists buy orders).
+    pub order_type: OrderType,
+    /// Only orders that can be traded with this amount of the currency to trade.
+    pub amount_currency_to_trade: Option<Decimal>,
+    /// Only orders with this price or better.
+    pub price: Option<Decimal>,
+    /// Only orders whose requirements you fulfil.
+    pub order_requirements_fullfilled: Option<bool>,
+    /// Only orders of fully identified (KYC) users.
+    pub only_kyc_full: Option<bool>,
+    /// Only orders that can be paid via Express trading.
+    pub only_express_orders: Option<bool>,
+    /// Only orders with this payment option.
+    pub payment_option: Option<PaymentOption>,
+    /// Only orders with this SEPA option.
+    pub sepa_option: Option<SepaOption>,
+    /// Only orders of users whose bank belongs to your bank group.
+    pub only_same_bankgroup: Option<bool>,
+    /// Only orders of users with the same bank (BIC) as you.
+    pub only_same_bic: Option<bool>,
+    /// Only orders of users whose bank is located in one of these countries.
+    pub seat_of_bank: Vec<CountryCode>,
+    /// Only orders of users with at least this trust level.
+    pub min_trust_level: Option<TrustLevel>,
+}
+
+impl OrderbookQuery {
+    /// Creates a query for the given order type without further criteria.
+    pub fn new(order_type: OrderType) -> Self {
+        OrderbookQuery {
+            order_type,
+            amount_currency_to_trade: None,
+            price: None,
+            order_requirements_fullfilled: None,
+            only_kyc_full: None,
+            only_express_orders: None,
+            payment_option: None,
+            sepa_option: None,
+            only_same_bankgroup: None,
+            only_same_bic: None,
+            seat_of_bank: Vec::new(),
+            min_trust_level: None,
+        }
+    }
+
+    /// Lists only orders that can be traded with this amount.
+    pub fn amount_currency_to_trade(mut self, amount: Decimal) -> Self {
+        self.amount_currency_to_trade = Some(amount);
+        self
+    }
+
+    /// Lists only orders with this price or better.
+    pub fn price(mut self, price: Decimal) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    /// Lists only orders whose requirements you fulfil.
+    pub fn order_requirements_fullfilled(mut self, enabled: bool) -> Self {
+        self.order_requirements_fullfilled = Some(enabled);
+        self
+    }
+
+    /// Lists only orders of fully identified (KYC) users.
+    pub fn only_kyc_full(mut self, enabled: bool) -> Self {
+        self.only_kyc_full = Some(enabled);
+        self
+    }
+
+    /// Lists only orders that can be paid via Express trading.
+    pub fn only_express_orders(mut self, enabled: bool) -> Self {
+        self.only_express_orders = Some(enabled);
+        self
+    }
+
+    /// Lists only orders with this payment option.
+    pub fn payment_option(mut self, payment_option: PaymentOption) -> Self {
+        self.payment_option = Some(payment_option);
+        self
+    }
+
+    /// Lists only orders with this SEPA option.
+    pub fn sepa_option(mut self, sepa_option: SepaOption) -> Self {
+        self.sepa_option = Some(sepa_option);
+        self
+    }
+
+    /// Lists only orders of users whose bank belongs to your bank group.
+    pub fn only_same_bankgroup(mut self, enabled: bool) -> Self {
+        self.only_same_bankgroup = Some(enabled);
+        self
+    }
+
+    /// Lists only orders of users with the same bank (BIC) as you.
+    pub fn only_same_bic(mut self, enabled: bool) -> Self {
+        self.only_same_bic = Some(enabled);
+        self
+    }
+
+    /// Lists only orders of users whose bank is located in one of these countries.
+    pub fn seat_of_bank(mut self, countries: impl IntoIterator<Item = CountryCode>) -> Self {
+        self.seat_of_bank = countries.into_iter().collect();
+        self
+    }
+
+    /// Lists only orders of users with at least this trust level.
+    pub fn min_trust_level(mut self, min_trust_level: TrustLevel) -> Self {
+        self.min_trust_level = Some(min_trust_level);
+        self
+    }
+
+    /// Checks the criteria locally, without contacting the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if the amount or price is not positive, or a
+    /// `seat_of_bank` entry is repeated.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.amount_currency_to_trade.is_some_and(|amount| amount <= Decimal::ZERO) {
+            return Err(Error::InvalidParameter(SHOW_ORDERBOOK_PARAMETER_AMOUNT_CURRENCY_TO_TRADE, "must be positive".to_string()));
+        }
+        if self.price.is_some_and(|price| price <= Decimal::ZERO) {
+            return Err(Error::InvalidParameter(SHOW_ORDERBOOK_PARAMETER_PRICE, "must be positive".to_string()));
+        }
+        seat_of_bank_params(SHOW_ORDERBOOK_PARAMETER_SEAT_OF_BANK, &self.seat_of_bank)?;
+        Ok(())
+    }
+
+    /// Validates the query and converts it into the query parameters of `showOrderbook`.
+    ///
+    /// An amount with more decimal places than the pair's base currency supports, or a
+    /// price with more than its quote currency supports, is rejected rather than rounded.
+    /// The trading pair itself is not included, it is added by `TradingApiSdkV4::show_orderbook`.
+    pub(crate) fn to_parameters(&self, trading_pair: TradingPair) -> Result<RequestParameters, Error> {
+        self.validate()?;
+
+        let mut params = RequestParameters::new();
+        params.insert(SHOW_ORDERBOOK_PARAMETER_TYPE.into(), self.order_type.to_string());
+        if let Some(amount) = self.amount_currency_to_trade {
+            params.insert(
+                SHOW_ORDERBOOK_PARAMETER_AMOUNT_CURRENCY_TO_TRADE.into(),
+                decimal_param(SHOW_ORDERBOOK_PARAMETER_AMOUNT_CURRENCY_TO_TRADE, amount, trading_pair.base())?,
+            );
+        }
+        if let Some(price) = self.price {
+            params.insert(SHOW_ORDERBOOK_PARAMETER_PRICE.into(), decimal_param(SHOW_ORDERBOOK_PARAMETER_PRICE, price, trading_pair.quote())?);
+        }
+        let flags = [
+            (SHOW_ORDERBOOK_PARAMETER_ORDER_REQUIREMENTS_FULLFILLED, self.order_requirements_fullfilled),
+            (SHOW_ORDERBOOK_PARAMETER_ONLY_KYC_FULL, self.only_kyc_full),
+            (SHOW_ORDERBOOK_PARAMETER_ONLY_EXPRESS_ORDERS, self.only_express_orders),
+            (SHOW_ORDERBOOK_PARAMETER_ONLY_SAME_BANKGROUP, self.only_same_bankgroup),
+            (SHOW_ORDERBOOK_PARAMETER_ONLY_SAME_BIC, self.only_same_bic),
+        ];
+        for (name, value) in flags {
+            if let Some(enabled) = value {
+                params.insert(name.into(), bool_param(enabled));
+            }
+        }
+        if let Some(payment_option) = self.payment_option {
+            params.insert(SHOW_ORDERBOOK_PARAMETER_PAYMENT_OPTION.into(), payment_option.to_string());
+        }
+        if let Some(sepa_option) = self.sepa_option {
+            params.insert(SHOW_ORDERBOOK_PARAMETER_SEPA_OPTION.into(), sepa_option.to_string());
+        }
+        params.extend(seat_of_bank_params(SHOW_ORDERBOOK_PARAMETER_SEAT_OF_BANK, &self.seat_of_bank)?);
+        if let Some(min_trust_level) = self.min_trust_level {
+            params.insert(SHOW_ORDERBOOK_PARAMETER_MIN_TRUST_LEVEL.into(), min_trust_level.to_string());
+        }
+        Ok(params)
+    }
+}
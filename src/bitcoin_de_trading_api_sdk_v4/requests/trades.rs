@@ -0,0 +1,111 @@
+// bitcoin_de_trading_api_sdk_v4/requests/trades.rs
+use rust_decimal::Decimal;
+
+use super::{decimal_param, RequestParameters};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, PaymentOption, TradingPair};
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
+use crate::bitcoin_de_trading_api_sdk_v4::responses::PublicOrderDetails;
+
+/// Parameters for the `executeTrade` API method.
+///
+/// # Examples
+///
+/// ```
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, PaymentOption};
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::ExecuteTradeRequest;
+/// use rust_decimal::Decimal;
+///
+/// let request = ExecuteTradeRequest::new(OrderType::Buy, Decimal::new(25, 2))
+///     .payment_option(PaymentOption::ExpressOnly);
+/// assert!(request.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecuteTradeRequest {
+    /// Buy or sell.
+    pub order_type: OrderType,
+    /// Amount of the currency to trade (e.g. BTC for btceur).
+    pub amount_currency_to_trade: Decimal,
+    /// Payment option to use for the trade.
+    pub payment_option: Option<PaymentOption>,
+}
+
+impl ExecuteTradeRequest {
+    /// Creates a request with the required parameters.
+    pub fn new(order_type: OrderType, amount_currency_to_trade: Decimal) -> Self {
+        ExecuteTradeRequest { order_type, amount_currency_to_trade, payment_option: None }
+    }
+
+    /// Sets the payment option to use for the trade.
+    pub fn payment_option(mut self, payment_option: PaymentOption) -> Self {
+        self.payment_option = Some(payment_option);
+        self
+    }
+
+    /// Checks the parameters locally, without contacting the API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if the amount is not positive.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.amount_currency_to_trade <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(
+                EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE,
+                "must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks the amount against the limits of the order the trade is executed against.
+    ///
+    /// The API would reject amounts outside these limits with `ERROR_CODE_AMOUNT_TOO_LOW`
+    /// or `ERROR_CODE_AMOUNT_TOO_HIGH` after charging credits for the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if the amount is below the order's
+    /// `min_amount_currency_to_trade` or above its `max_amount_currency_to_trade`.
+    pub fn validate_against_order(&self, order: &PublicOrderDetails) -> Result<(), Error> {
+        self.validate()?;
+        if self.amount_currency_to_trade < order.min_amount_currency_to_trade {
+            return Err(Error::InvalidParameter(
+                EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE,
+                format!(
+                    "{} is below the minimum amount {} of order {}",
+                    self.amount_currency_to_trade, order.min_amount_currency_to_trade, order.order_id
+                ),
+            ));
+        }
+        if self.amount_currency_to_trade > order.max_amount_currency_to_trade {
+            return Err(Error::InvalidParameter(
+                EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE,
+                format!(
+                    "{} is above the maximum amount {} of order {}",
+                    self.amount_currency_to_trade, order.max_amount_currency_to_trade, order.order_id
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates the request and converts it into the form parameters of `executeTrade`.
+    ///
+    /// An amount with more decimal places than the pair's base currency supports is
+    /// rejected rather than rounded. The trading pair and order id are not included, they
+    /// are added by `TradingApiSdkV4::execute_trade`.
+    pub(crate) fn to_parameters(&self, trading_pair: TradingPair) -> Result<RequestParameters, Error> {
+        self.validate()?;
+
+        let mut params = RequestParameters::new();
+        params.insert(EXECUTE_TRADE_PARAMETER_TYPE.into(), self.order_type.to_string());
+        params.insert(
+            EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE.into(),
+            decimal_param(EXECUTE_TRADE_PARAMETER_AMOUNT_CURRENCY_TO_TRADE, self.amount_currency_to_trade, trading_pair.base())?,
+        );
+        if let Some(payment_option) = self.payment_option {
+            params.insert(EXECUTE_TRADE_PARAMETER_PAYMENT_OPTION.into(), payment_option.to_string());
+        }
+        Ok(params)
+    }
+}
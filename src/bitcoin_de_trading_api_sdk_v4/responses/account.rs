@@ -181,6 +181,8 @@ pub struct LedgerTradeDetails {
     // extra_currencies: HashMap<String, HashMap<String, Decimal>>,
 }
 
+typed_accessors!(LedgerTradeDetails { trading_pair: TradingPair });
+
 
 /// Represents the successful response for `showAccountLedger`.
 /// Based on the Success-Response example JSON structure.
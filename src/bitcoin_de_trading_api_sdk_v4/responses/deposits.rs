@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc};
 
 // Import common structs from the parent module (mod.rs)
 use super::{PageDetails, ApiErrorDetail};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::DepositState;
 
 
 // --- Deposit Response Structs ---
@@ -21,7 +22,7 @@ pub struct DepositDetails {
     pub recipient_purpose: Option<String>, // Optional String
     #[serde(with = "rust_decimal::serde::str")] // Numbers as strings in examples
     pub amount: Decimal, // Float -> Decimal
-    pub state: i32, // Integer (Deposit-State-Values)
+    pub state: DepositState, // Integer (Deposit-State-Values)
     pub txid: String, // String
     pub confirmations: i32, // Integer
     #[serde(rename = "created_at")]
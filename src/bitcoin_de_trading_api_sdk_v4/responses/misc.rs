@@ -37,12 +37,14 @@ pub struct CompactOrderbook {
 // #[serde(rename_all = "snake_case")] // Apply snake_case if needed
 pub struct ShowOrderbookCompactResponse {
     #[serde(rename = "trading_pair")]
-    pub trading_pair: String, // String
+    pub trading_pair: String,
     pub orders: CompactOrderbook, // Nested object
     pub errors: Vec<ApiErrorDetail>, // Empty array on success
     pub credits: i32,
 }
 
+typed_accessors!(ShowOrderbookCompactResponse { trading_pair: TradingPair });
+
 
 /// Represents a public trade history entry.
 /// Based on the "Trade Informationen" table for showPublicTradeHistory.
@@ -64,12 +66,14 @@ pub struct PublicTradeEntry {
 // #[serde(rename_all = "snake_case")] // Apply snake_case if needed
 pub struct ShowPublicTradeHistoryResponse {
     #[serde(rename = "trading_pair")]
-    pub trading_pair: String, // String
+    pub trading_pair: String,
     pub trades: Vec<PublicTradeEntry>, // Array of PublicTradeEntry
     pub errors: Vec<ApiErrorDetail>, // Empty array on success
     pub credits: i32,
 }
 
+typed_accessors!(ShowPublicTradeHistoryResponse { trading_pair: TradingPair });
+
 
 /// Details within the `rates` object in `ShowRatesResponse`.
 /// Based on the "Rates" table.
@@ -93,12 +97,14 @@ pub struct RatesDetails {
 // #[serde(rename_all = "snake_case")] // Apply snake_case if needed
 pub struct ShowRatesResponse {
     #[serde(rename = "trading_pair")]
-    pub trading_pair: String, // String
+    pub trading_pair: String,
     pub rates: RatesDetails, // Nested object
     pub errors: Vec<ApiErrorDetail>, // Empty array on success
     pub credits: i32,
 }
 
+typed_accessors!(ShowRatesResponse { trading_pair: TradingPair });
+
 
 /// Represents outgoing address details (used for address pool as well).
 /// Based on the structure shown in showOutgoingAddresses example.
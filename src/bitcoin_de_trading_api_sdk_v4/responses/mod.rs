@@ -5,6 +5,27 @@
 /// received from the Bitcoin.de Trading API v4. They are organized
 /// into sub-modules based on API endpoint categories.
 
+/// Implements accessors that parse the raw string fields of a response into their enums.
+///
+/// Trading pairs and order types are kept as received (e.g. "btceur"), so a value the SDK
+/// does not know yet does not fail the whole response, and serializes back unchanged.
+macro_rules! typed_accessors {
+    ($name:ident { $($field:ident: $enum:ident),* }) => {
+        impl $name {
+            $(
+                #[doc = concat!("Returns `", stringify!($field), "` as a [`", stringify!($enum), "`](crate::bitcoin_de_trading_api_sdk_v4::enums::", stringify!($enum), ").")]
+                ///
+                /// # Errors
+                ///
+                /// Returns `Error::Other` if the value is not known to the SDK.
+                pub fn $field(&self) -> Result<crate::bitcoin_de_trading_api_sdk_v4::enums::$enum, crate::bitcoin_de_trading_api_sdk_v4::errors::Error> {
+                    self.$field.parse()
+                }
+            )*
+        }
+    };
+}
+
 // Declare the modules for each response category file in this directory
 pub mod account;
 pub mod deposits;
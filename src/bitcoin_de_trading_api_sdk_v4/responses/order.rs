@@ -7,6 +7,7 @@ use chrono::{DateTime, Utc};
 // This assumes TradingPartnerInformation, OrderRequirements, PageDetails,
 // and ApiErrorDetail are defined or re-exported in responses/mod.rs
 use super::{TradingPartnerInformation, OrderRequirements, PageDetails, ApiErrorDetail};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::OrderState;
 
 
 // --- Order Response Structs ---
@@ -21,9 +22,9 @@ pub struct OrderbookEntry {
     #[serde(rename = "is_external_wallet_order")]
     pub is_external_wallet_order: bool, // Boolean
     #[serde(rename = "trading_pair")]
-    pub trading_pair: String, // String
+    pub trading_pair: String,
     #[serde(rename = "type")] // "type" is a keyword, needs rename
-    pub order_type: String, // String (buy/sell) - Renamed to avoid keyword conflict
+    pub order_type: String, // Renamed, "type" is a keyword
     #[serde(rename = "max_amount_currency_to_trade")]
     #[serde(with = "rust_decimal::serde::str")]
     pub max_amount_currency_to_trade: Decimal, // Float -> Decimal (string in example)
@@ -48,6 +49,8 @@ pub struct OrderbookEntry {
     pub order_requirements: OrderRequirements, // Nested object
 }
 
+typed_accessors!(OrderbookEntry { trading_pair: TradingPair, order_type: OrderType });
+
 
 /// Represents the successful response for `showOrderbook`.
 /// Based on the Success-Response example JSON structure.
@@ -66,11 +69,11 @@ pub struct MyOrderDetails {
     #[serde(rename = "order_id")]
     pub order_id: String, // String
     #[serde(rename = "trading_pair")]
-    pub trading_pair: String, // String
+    pub trading_pair: String,
     #[serde(rename = "is_external_wallet_order")]
     pub is_external_wallet_order: bool, // Boolean
     #[serde(rename = "type")] // "type" is a keyword
-    pub order_type: String, // String (buy/sell) - Renamed
+    pub order_type: String, // Renamed, "type" is a keyword
     #[serde(rename = "max_amount_currency_to_trade")]
     #[serde(with = "rust_decimal::serde::str")]
     pub max_amount_currency_to_trade: Decimal, // Float -> Decimal (string in example)
@@ -90,7 +93,7 @@ pub struct MyOrderDetails {
     pub end_datetime: Option<DateTime<Utc>>, // Optional DateTime
     #[serde(rename = "new_order_for_remaining_amount")]
     pub new_order_for_remaining_amount: bool, // Boolean
-    pub state: i32, // Integer (Order-State-Values)
+    pub state: OrderState, // Integer (Order-State-Values)
     #[serde(rename = "order_requirements")]
     // Note: showMyOrders example JSON includes order_requirements directly in the order object
     pub order_requirements: OrderRequirements, // Nested object
@@ -107,6 +110,8 @@ pub struct MyOrderDetails {
     pub trading_partner_information: Option<TradingPartnerInformation>, // Optional Nested object
 }
 
+typed_accessors!(MyOrderDetails { trading_pair: TradingPair, order_type: OrderType });
+
 
 /// Represents the successful response for `showMyOrders`.
 /// Based on the Success-Response example JSON structure.
@@ -119,12 +124,60 @@ pub struct ShowMyOrdersResponse {
 }
 
 
-/// Represents the successful response for `showMyOrderDetails`.
+/// Represents details of a public order of another user.
+/// Based on the "Order-Details" table for showOrderDetails.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PublicOrderDetails {
+    #[serde(rename = "order_id")]
+    pub order_id: String, // String
+    #[serde(rename = "trading_pair")]
+    pub trading_pair: String,
+    #[serde(rename = "is_external_wallet_order", default)]
+    pub is_external_wallet_order: bool, // Boolean
+    #[serde(rename = "type")] // "type" is a keyword
+    pub order_type: String, // Renamed, "type" is a keyword
+    #[serde(rename = "max_amount_currency_to_trade")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub max_amount_currency_to_trade: Decimal, // Float -> Decimal (string in example)
+    #[serde(rename = "min_amount_currency_to_trade")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub min_amount_currency_to_trade: Decimal, // Float -> Decimal (string in example)
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal, // Float -> Decimal (string in example)
+    #[serde(rename = "max_volume_currency_to_pay")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub max_volume_currency_to_pay: Decimal, // Float -> Decimal (string in example)
+    #[serde(rename = "min_volume_currency_to_pay")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub min_volume_currency_to_pay: Decimal, // Float -> Decimal (string in example)
+    #[serde(rename = "order_requirements_fullfilled")]
+    pub order_requirements_fullfilled: bool, // Boolean
+    #[serde(rename = "sepa_option", default)]
+    pub sepa_option: Option<i32>, // Integer, only for orders with SEPA payment
+    #[serde(rename = "trading_partner_information")]
+    pub trading_partner_information: TradingPartnerInformation, // Nested object
+    #[serde(rename = "order_requirements")]
+    pub order_requirements: OrderRequirements, // Nested object
+}
+
+typed_accessors!(PublicOrderDetails { trading_pair: TradingPair, order_type: OrderType });
+
+
+/// Represents the successful response for `showOrderDetails`.
 /// Based on the Success-Response example JSON structure.
-// This struct uses MyOrderDetails for its 'order' field.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ShowOrderDetailsResponse {
     // The example JSON has the order details directly under "order".
+    #[serde(rename = "order")] // Field name in the JSON response
+    pub order_details: PublicOrderDetails, // Nested PublicOrderDetails object (renamed field)
+    pub errors: Vec<ApiErrorDetail>, // Empty array on success
+    pub credits: i32,
+}
+
+/// Represents the successful response for `showMyOrderDetails`.
+/// Based on the Success-Response example JSON structure.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShowMyOrderDetailsResponse {
     #[serde(rename = "order")] // Field name in the JSON response
     pub order_details: MyOrderDetails, // Nested MyOrderDetails object (renamed field)
     pub errors: Vec<ApiErrorDetail>, // Empty array on success
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 // Import common structs from the parent module (mod.rs) and the BasicSuccessResponse
 use super::{TradingPartnerInformation, CurrencyAmounts, PageDetails, ApiErrorDetail, misc::BasicSuccessResponse};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{PaymentMethod, TradeState};
 
 
 // --- Trade Response Structs ---
@@ -20,9 +21,9 @@ pub struct MyTradeDetails {
     #[serde(rename = "is_external_wallet_trade")]
     pub is_external_wallet_trade: bool, // Boolean
     #[serde(rename = "trading_pair")]
-    pub trading_pair: String, // String
+    pub trading_pair: String,
     #[serde(rename = "type")] // "type" is a keyword
-    pub trade_type: String, // String (buy/sell) - Renamed
+    pub trade_type: String, // Renamed, "type" is a keyword
     #[serde(rename = "amount_currency_to_trade")]
     #[serde(with = "rust_decimal::serde::str")]
     pub amount_currency_to_trade: Decimal, // Float -> Decimal (string in example)
@@ -45,7 +46,7 @@ pub struct MyTradeDetails {
     pub fee_currency_to_trade: Decimal, // Float -> Decimal (string in example)
     #[serde(rename = "new_order_id_for_remaining_amount")]
     pub new_order_id_for_remaining_amount: Option<String>, // Optional String
-    pub state: i32, // Integer (Trade-State-Values)
+    pub state: TradeState, // Integer (Trade-State-Values)
     #[serde(rename = "is_trade_marked_as_paid")]
     pub is_trade_marked_as_paid: Option<bool>, // Optional Boolean (only in showMyTradeDetails example)
     #[serde(rename = "trade_marked_as_paid_at")]
@@ -62,7 +63,7 @@ pub struct MyTradeDetails {
     #[serde(rename = "cancelled_at")]
     pub cancelled_at: Option<DateTime<Utc>>, // Optional DateTime
     #[serde(rename = "payment_method")]
-    pub payment_method: i32, // Integer (Payment method values)
+    pub payment_method: PaymentMethod, // Integer (Payment method values)
 
     // Handle the nested currency details in the showAccountLedger trade details example
     // "primary_currency": {"currency_to_trade": { ... }}
@@ -84,6 +85,8 @@ pub struct MyTradeDetails {
     // pub volume_from_example: Option<Decimal>,
 }
 
+typed_accessors!(MyTradeDetails { trading_pair: TradingPair, trade_type: OrderType });
+
 
 /// Represents the successful response for `showMyTrades`.
 /// Based on the Success-Response example JSON structure.
@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc};
 
 // Import common structs from the parent module (mod.rs)
 use super::{PageDetails, ApiErrorDetail};
+use crate::bitcoin_de_trading_api_sdk_v4::enums::WithdrawalState;
 
 
 // --- Withdrawal Response Structs ---
@@ -27,7 +28,7 @@ pub struct WithdrawalDetails {
     pub comment: Option<String>, // Optional String (example shows "Bitpay", table says String)
     #[serde(rename = "created_at")]
     pub created_at: DateTime<Utc>, // String (RFC 3339) -> DateTime<Utc>
-    pub state: i32, // Integer (Withdrawal-State-Values)
+    pub state: WithdrawalState, // Integer (Withdrawal-State-Values)
     #[serde(rename = "transferred_at")]
     pub transferred_at: Option<DateTime<Utc>>, // Optional DateTime
     pub txid: Option<String>, // Optional String (NULL in example)
@@ -0,0 +1,257 @@
+// bitcoin_de_trading_api_sdk_v4/retry.rs
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::bitcoin_de_trading_api_sdk_v4::error_codes::ApiErrorCode;
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+/// Policy for automatically retrying failed requests.
+///
+/// Retries are opt-in: a client only retries if a policy is configured via
+/// [`TradingApiSdkV4Builder::retry_policy`](crate::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4Builder::retry_policy).
+/// Every attempt is signed again with a fresh nonce.
+///
+/// A failure is retried if it is transient: a timeout or connection error, one of the
+/// configured HTTP statuses (default: 429, 500, 502, 503, 504) or one of the configured
+/// API error codes (default: invalid nonce, unknown error, maintenance).
+///
+/// POST requests (e.g. `createOrder`, `executeTrade`, `createWithdrawal`) are not idempotent.
+/// They are only retried if the failure proves the request was rejected before it was
+/// processed (connection failures, HTTP 429, invalid nonce), unless
+/// [`retry_non_idempotent`](Self::retry_non_idempotent) is enabled.
+///
+/// The async client needs a timer to wait between attempts, which the `tokio` feature
+/// provides (enabled by `cmdline`, `backend` and `mock`). Without it, e.g. on wasm, the
+/// async client ignores the policy and sends every request once, instead of firing the
+/// retries back-to-back. The blocking client always retries.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4;
+/// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::retry::RetryPolicy;
+///
+/// let client = TradingApiSdkV4::builder()
+///     .api_key("your_api_key")
+///     .api_secret("your_api_secret")
+///     .retry_policy(RetryPolicy::new().max_attempts(5).initial_backoff(Duration::from_millis(500)))
+///     .build()
+///     .expect("valid configuration");
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retry_on_statuses: Vec<StatusCode>,
+    retry_on_codes: Vec<ApiErrorCode>,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(10),
+            jitter: true,
+            retry_on_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_on_codes: vec![ApiErrorCode::InvalidNonce, ApiErrorCode::UnknownError, ApiErrorCode::Maintenance],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the defaults: 3 attempts, 250ms initial backoff doubling up to 10s, with jitter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the total number of attempts, including the first one. `1` disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry. It doubles with every further retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Enables or disables random jitter, which spreads the delay over `[backoff / 2, backoff]`.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the HTTP statuses that count as transient.
+    pub fn retry_on_statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.retry_on_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Sets the API error codes that count as transient.
+    pub fn retry_on_codes(mut self, codes: impl IntoIterator<Item = ApiErrorCode>) -> Self {
+        self.retry_on_codes = codes.into_iter().collect();
+        self
+    }
+
+    /// Allows retrying POST requests on any transient failure.
+    ///
+    /// Only enable this if executing a request twice is acceptable: a timed out
+    /// `createOrder` may have created the order even though no response arrived.
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Decides whether a failed attempt is retried.
+    ///
+    /// `attempt` is the number of the failed attempt, starting at 1.
+    pub(crate) fn should_retry(&self, error: &Error, http_method: &str, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        if http_method == "POST" && !self.retry_non_idempotent {
+            return self.is_transient(error) && Self::was_rejected_before_processing(error);
+        }
+        self.is_transient(error)
+    }
+
+    /// Returns true if the error is a transient failure according to this policy.
+    fn is_transient(&self, error: &Error) -> bool {
+        match error {
+            Error::Reqwest(e) => e.is_timeout() || e.is_connect(),
+            Error::Api { status, .. } => {
+                self.retry_on_statuses.contains(status)
+                    || error.api_codes().iter().any(|code| self.retry_on_codes.contains(code))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns true if the error proves the API did not process the request,
+    /// so even a non-idempotent request can safely be sent again.
+    fn was_rejected_before_processing(error: &Error) -> bool {
+        match error {
+            Error::Reqwest(e) => e.is_connect(),
+            Error::Api { status, .. } => *status == StatusCode::TOO_MANY_REQUESTS || error.is_nonce_error(),
+            _ => false,
+        }
+    }
+
+    /// Returns the delay before the next attempt, after attempt number `attempt` failed.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self.initial_backoff.saturating_mul(1 << exponent).min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+        // Equal jitter: half of the backoff is fixed, the other half random
+        let half = backoff / 2;
+        let random = RandomState::new().build_hasher().finish();
+        let jitter_nanos = random % (half.as_nanos() as u64).max(1);
+        half + Duration::from_nanos(jitter_nanos)
+    }
+}
+
+/// Whether the async client can wait between attempts, see [`sleep`].
+pub(crate) const HAS_TIMER: bool = cfg!(feature = "tokio");
+
+/// Waits before the next attempt.
+///
+/// Without the `tokio` feature (e.g. on wasm) there is no timer available and this returns
+/// immediately, so callers check [`HAS_TIMER`] before relying on it.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(feature = "tokio")]
+    tokio::time::sleep(duration).await;
+    #[cfg(not(feature = "tokio"))]
+    let _ = duration;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitcoin_de_trading_api_sdk_v4::constants::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(1000))
+            .jitter(false);
+        let backoffs: Vec<u64> = (1..=6).map(|attempt| policy.backoff(attempt).as_millis() as u64).collect();
+        assert_eq!(backoffs, [100, 200, 400, 800, 1000, 1000]);
+
+        // Large attempt numbers neither overflow nor exceed the maximum
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(1000));
+        assert_eq!(RetryPolicy::new().jitter(false).backoff(0), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn jitter_stays_within_the_upper_half_of_the_backoff() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(1000));
+        for attempt in 1..=6 {
+            let backoff = RetryPolicy { jitter: false, ..policy.clone() }.backoff(attempt);
+            let delays: Vec<Duration> = (0..50).map(|_| policy.backoff(attempt)).collect();
+            assert!(delays.iter().all(|delay| *delay >= backoff / 2 && *delay < backoff), "{:?}", delays);
+            assert!(delays.iter().any(|delay| *delay != delays[0]), "jitter is not random: {:?}", delays);
+        }
+
+        let zero = RetryPolicy::new().initial_backoff(Duration::ZERO);
+        assert_eq!(zero.backoff(1), Duration::ZERO);
+    }
+
+    fn api_error(status: u16, code: i32) -> Error {
+        let body = format!(r#"{{"errors":[{{"message":"error","code":{},"field":null}}],"credits":10}}"#, code);
+        Error::api_error(StatusCode::from_u16(status).unwrap(), body)
+    }
+
+    #[test]
+    fn only_failures_before_processing_retry_post_requests() {
+        let policy = RetryPolicy::new();
+        let unavailable = api_error(503, ERROR_CODE_MAINTENANCE);
+        assert!(policy.should_retry(&unavailable, "GET", 1));
+        assert!(policy.should_retry(&unavailable, "DELETE", 1));
+        assert!(!policy.should_retry(&unavailable, "POST", 1));
+        assert!(RetryPolicy::new().retry_non_idempotent(true).should_retry(&unavailable, "POST", 1));
+
+        assert!(policy.should_retry(&api_error(429, 0), "POST", 1));
+        assert!(policy.should_retry(&api_error(401, ERROR_CODE_INVALID_NONCE), "POST", 1));
+
+        assert!(!policy.should_retry(&api_error(401, ERROR_CODE_WRONG_SIGNATURE), "GET", 1));
+        assert!(!policy.should_retry(&api_error(400, 0), "GET", 1));
+        assert!(!policy.should_retry(&Error::Other("not transient".to_string()), "GET", 1));
+    }
+
+    #[test]
+    fn max_attempts_limits_retries() {
+        let error = api_error(503, 0);
+        let policy = RetryPolicy::new().max_attempts(3);
+        assert!(policy.should_retry(&error, "GET", 1));
+        assert!(policy.should_retry(&error, "GET", 2));
+        assert!(!policy.should_retry(&error, "GET", 3));
+        assert!(!RetryPolicy::new().max_attempts(0).should_retry(&error, "GET", 1));
+    }
+}
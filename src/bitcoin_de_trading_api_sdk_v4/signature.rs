@@ -0,0 +1,197 @@
+// bitcoin_de_trading_api_sdk_v4/signature.rs
+//! Request signing as specified by the Trading API.
+//!
+//! The `X-API-SIGNATURE` header is the lowercase hex HMAC-SHA256, keyed with the API secret, of
+//!
+//! ```text
+//! METHOD#url#api_key#nonce#md5(sorted, url-encoded POST body parameters)
+//! ```
+//!
+//! * The URL includes the query string for GET and DELETE requests, exactly as sent.
+//! * POST body parameters are sorted by name (array entries such as `seat_of_bank[n]` by
+//!   index) and form-urlencoded as UTF-8, with spaces as `+`. Without body parameters,
+//!   the MD5 of the empty string (`d41d8cd98f00b204e9800998ecf8427e`) is used.
+//!
+//! # Test vectors
+//!
+//! All vectors use the API key `my_api_key` and the secret `my_api_secret`. They were
+//! computed independently of this crate, so they also pin down parameter sorting and
+//! URL-encoding.
+//!
+//! ```
+//! use bitcoin_de::bitcoin_de_trading_api_sdk_v4::signature::{sign_request, verify_signature};
+//!
+//! let sign = |method, url, nonce, params: &[(&str, &str)]| {
+//!     sign_request(method, url, "my_api_key", "my_api_secret", nonce, params).unwrap()
+//! };
+//!
+//! // GET with a query string, which is part of the signed URL
+//! assert_eq!(
+//!     sign("GET", "https://api.bitcoin.de/v4/btceur/orderbook?type=buy&amount_min=0.5&price_max=25000.5", "1700000000000000", &[]),
+//!     "00637288a2ff45aed9cac252696cda2f597ab83e04398d29bb1105e21a041739"
+//! );
+//!
+//! // POST with body parameters in arbitrary order, signed as
+//! // md5("max_amount_currency_to_trade=0.5&price=25000.5&type=buy")
+//! assert_eq!(
+//!     sign("POST", "https://api.bitcoin.de/v4/btceur/orders", "1700000000000001",
+//!          &[("type", "buy"), ("max_amount_currency_to_trade", "0.5"), ("price", "25000.5")]),
+//!     "3ea141f8081e4b9c50d20c96cdeb273bb092fd500dc573db41b6bc84082f9b9a"
+//! );
+//!
+//! // POST with an empty body
+//! assert_eq!(
+//!     sign("POST", "https://api.bitcoin.de/v4/btceur/trades/2EDYNS/mark_trade_as_paid", "1700000000000002", &[]),
+//!     "7fbf9c3b93afc55b120e99ab5ab5cfe70ef2ec12db5a8874353c22f3685063b1"
+//! );
+//!
+//! // DELETE without query string
+//! assert_eq!(
+//!     sign("DELETE", "https://api.bitcoin.de/v4/btceur/orders/A1B2C3", "1700000000000003", &[]),
+//!     "ffa72f740533e4ac6e2a9dd07db975a1f095c00c2829133ada6780fe971506a7"
+//! );
+//!
+//! // Non-ASCII and reserved characters, signed as md5("address=bc1qexample&amount=0.1&comment=Gr%C3%BC%C3%9Fe+%26+K%C3%BCsse+%E2%9C%93")
+//! assert_eq!(
+//!     sign("POST", "https://api.bitcoin.de/v4/btc/withdrawals", "1700000000000004",
+//!          &[("comment", "Grüße & Küsse ✓"), ("amount", "0.1"), ("address", "bc1qexample")]),
+//!     "e870f6f933e30af119e0679c5971180acf0a21e65e25bf6dcbcd3c7c48ae0787"
+//! );
+//!
+//! // Array parameters sort by index: seat_of_bank[9] before seat_of_bank[10]
+//! assert_eq!(
+//!     sign("POST", "https://api.bitcoin.de/v4/btceur/orders", "1700000000000005",
+//!          &[("type", "buy"), ("seat_of_bank[10]", "FR"), ("price", "25000.5"),
+//!            ("seat_of_bank[9]", "AT"), ("max_amount_currency_to_trade", "0.5")]),
+//!     "9ca102dfcd6656292ba4c3a19861e6be3552b5f1932744d9ee9d4a2fdb4e4e1f"
+//! );
+//!
+//! assert!(verify_signature(
+//!     "DELETE", "https://api.bitcoin.de/v4/btceur/orders/A1B2C3", "my_api_key", "my_api_secret",
+//!     "1700000000000003", &[], "ffa72f740533e4ac6e2a9dd07db975a1f095c00c2829133ada6780fe971506a7",
+//! ));
+//! assert!(!verify_signature(
+//!     "DELETE", "https://api.bitcoin.de/v4/btceur/orders/A1B2C4", "my_api_key", "my_api_secret",
+//!     "1700000000000003", &[], "ffa72f740533e4ac6e2a9dd07db975a1f095c00c2829133ada6780fe971506a7",
+//! ));
+//! ```
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// MD5 of the empty string, used for requests without body parameters.
+const EMPTY_BODY_MD5: &str = "d41d8cd98f00b204e9800998ecf8427e";
+
+/// Computes the `X-API-SIGNATURE` value for a request.
+///
+/// Used by the clients and the mock server, so all sides share the exact same
+/// parameter sorting, URL-encoding and hashing.
+///
+/// # Parameters
+///
+/// * `http_method` - The HTTP method of the request (GET, POST, DELETE)
+/// * `url` - The full URL (including query string for GET/DELETE, without for POST)
+/// * `api_key` - The API key sent in the `X-API-KEY` header
+/// * `api_secret` - The API secret used as HMAC key
+/// * `nonce` - The nonce sent in the `X-API-NONCE` header
+/// * `body_parameters` - The POST body parameters in any order; empty for GET/DELETE
+///
+/// # Errors
+///
+/// * `Error::UrlEncoded` - if the body parameters cannot be encoded.
+pub fn sign_request(
+    http_method: &str,
+    url: &str,
+    api_key: &str,
+    api_secret: &str,
+    nonce: &str,
+    body_parameters: &[(&str, &str)],
+) -> Result<String, Error> {
+    let mac = signature_mac(http_method, url, api_key, api_secret, nonce, body_parameters)?;
+    // Hex encode the signature (lowercase)
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Checks an `X-API-SIGNATURE` value against the request it was sent with, e.g. in a
+/// server or mock accepting signed requests.
+///
+/// Takes the same parameters as [`sign_request`] plus the received `signature` (hex,
+/// case-insensitive). The comparison runs in constant time. Returns false for malformed
+/// signatures or parameters that cannot be encoded.
+pub fn verify_signature(
+    http_method: &str,
+    url: &str,
+    api_key: &str,
+    api_secret: &str,
+    nonce: &str,
+    body_parameters: &[(&str, &str)],
+    signature: &str,
+) -> bool {
+    let Ok(signature) = hex::decode(signature) else {
+        return false;
+    };
+    signature_mac(http_method, url, api_key, api_secret, nonce, body_parameters)
+        .is_ok_and(|mac| mac.verify_slice(&signature).is_ok())
+}
+
+/// Feeds the signed data of a request into an HMAC keyed with the API secret.
+fn signature_mac(
+    http_method: &str,
+    url: &str,
+    api_key: &str,
+    api_secret: &str,
+    nonce: &str,
+    body_parameters: &[(&str, &str)],
+) -> Result<HmacSha256, Error> {
+    // 1. Prepare POST parameter MD5 hash (only if body_parameters exist)
+    let post_parameter_md5_hashed_string = if body_parameters.is_empty() {
+        // For GET, DELETE, or POST with empty body
+        EMPTY_BODY_MD5.to_string()
+    } else {
+        // Sort parameters alphabetically by key, keeping array entries (seat_of_bank[n]) in index order
+        let mut sorted_params = body_parameters.to_vec();
+        sort_body_parameters(&mut sorted_params);
+
+        // URL-encode the sorted parameters
+        let url_encoded_query_string = serde_urlencoded::to_string(&sorted_params)?;
+
+        // Calculate MD5 hash
+        let digest = md5::compute(url_encoded_query_string.as_bytes());
+        hex::encode(digest.0)
+    };
+
+    // 2. Concatenate HMAC data
+    let hmac_data = format!(
+        "{}#{}#{}#{}#{}",
+        http_method.to_uppercase(),
+        url,
+        api_key,
+        nonce,
+        post_parameter_md5_hashed_string
+    );
+
+    // 3. Calculate HMAC-SHA256 signature
+    let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes())?;
+    mac.update(hmac_data.as_bytes());
+    Ok(mac)
+}
+
+/// Sorts body parameters into the order they are hashed in for the signature.
+///
+/// The clients send the body in this order too, so the transmitted body is byte for byte
+/// the one whose MD5 was signed.
+pub(crate) fn sort_body_parameters<K: AsRef<str>, V>(body_parameters: &mut [(K, V)]) {
+    body_parameters.sort_by(|(a, _), (b, _)| signature_sort_key(a.as_ref()).cmp(&signature_sort_key(b.as_ref())));
+}
+
+/// Sort key for body parameters: the parameter name, then the numeric index of array
+/// entries, so `seat_of_bank[10]` sorts after `seat_of_bank[9]`.
+fn signature_sort_key(key: &str) -> (&str, Option<usize>) {
+    match key.split_once('[') {
+        Some((name, rest)) => (name, rest.trim_end_matches(']').parse().ok()),
+        None => (key, None),
+    }
+}
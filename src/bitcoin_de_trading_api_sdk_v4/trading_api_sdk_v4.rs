@@ -1,21 +1,36 @@
 // bitcoin_de_trading_api_sdk_v4/trading_api_sdk_v4.rs
-use hmac::{Hmac, Mac};
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use reqwest::header::{HeaderMap, HeaderValue};
-use sha2::Sha256;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use rust_decimal::Decimal;
 use std::sync::Arc;
+use std::time::Instant;
+use futures_util::stream::{Stream, TryStreamExt};
+use tracing::Instrument;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use url::Url;
 use serde::de::DeserializeOwned;
 use serde_json::from_str;
 use serde_urlencoded;
 
+use crate::bitcoin_de_trading_api_sdk_v4::builder::TradingApiSdkV4Builder;
+use crate::bitcoin_de_trading_api_sdk_v4::cassette::Cassette;
 use crate::bitcoin_de_trading_api_sdk_v4::config::ApiCredentials;
 use crate::bitcoin_de_trading_api_sdk_v4::constants::API_BASE_URI;
-use crate::bitcoin_de_trading_api_sdk_v4::method_settings::{MethodSetting, METHOD_SETTINGS};
+use crate::bitcoin_de_trading_api_sdk_v4::credits::{self, CreditTracker};
+use crate::bitcoin_de_trading_api_sdk_v4::method_settings::{method_credits, MethodSetting, METHOD_SETTINGS};
 use crate::bitcoin_de_trading_api_sdk_v4::errors::Error;
-use crate::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+use crate::bitcoin_de_trading_api_sdk_v4::enums::{Currency, TradingPair};
+use crate::bitcoin_de_trading_api_sdk_v4::market_info::Settlement;
+use crate::bitcoin_de_trading_api_sdk_v4::nonce::{AtomicNonceProvider, NonceProvider};
+use crate::bitcoin_de_trading_api_sdk_v4::pagination;
+use crate::bitcoin_de_trading_api_sdk_v4::retry::{self, RetryPolicy};
+use crate::bitcoin_de_trading_api_sdk_v4::signature::{sign_request, sort_body_parameters};
+use crate::bitcoin_de_trading_api_sdk_v4::requests::{
+    bool_param, positive_decimal_param, request_parameters, CreateOrderRequest, ExecuteTradeRequest, MyOrdersFilter,
+    OrderbookQuery, RequestParameters,
+};
 // Use the relative path for the responses module from within this file
 use crate::bitcoin_de_trading_api_sdk_v4::responses::*;
 
@@ -35,10 +50,23 @@ use crate::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
 /// * `method_settings` - A static reference to a map of API method configurations, defining
 ///   endpoints and their requirements.
 /// * `client` - A reusable HTTP client for making requests to the API.
+/// * `base_url` - The base URL all API paths are appended to (without trailing slash).
+/// * `retry_policy` - Optional policy for retrying transient failures.
+/// * `nonce_provider` - The source of strictly increasing `X-API-NONCE` values.
+/// * `credit_tracker` - The last reported credit balance and the optional credit floor.
+/// * `cassette` - Optional cassette the responses are recorded to or replayed from.
+///
+/// Use [`TradingApiSdkV4::new`] for the defaults, or [`TradingApiSdkV4::builder`] to customize
+/// the base URL, the underlying `reqwest::Client`, timeouts, user agent and proxy.
 pub struct TradingApiSdkV4 {
-    api_credentials: ApiCredentials,
-    method_settings: &'static HashMap<&'static str, MethodSetting>,
-    client: Arc<Client>, // Re-use the client
+    pub(crate) api_credentials: ApiCredentials,
+    pub(crate) method_settings: &'static HashMap<&'static str, MethodSetting>,
+    pub(crate) client: Arc<Client>, // Re-use the client
+    pub(crate) base_url: String, // e.g. "https://api.bitcoin.de/v4", no trailing slash
+    pub(crate) retry_policy: Option<RetryPolicy>, // None: every request is sent once
+    pub(crate) nonce_provider: Arc<dyn NonceProvider>, // Shared by all requests of this client
+    pub(crate) credit_tracker: CreditTracker, // Updated from the `credits` of every response
+    pub(crate) cassette: Option<Cassette>, // None: no recording, every request is sent
 }
 
 impl TradingApiSdkV4 {
@@ -65,56 +93,61 @@ impl TradingApiSdkV4 {
     /// ```
     pub fn new(api_key: String, api_secret: String) -> Self {
         TradingApiSdkV4 {
-            api_credentials: ApiCredentials { api_key, api_secret },
+            api_credentials: ApiCredentials::new(api_key, api_secret),
             method_settings: &METHOD_SETTINGS,
             client: Client::new().into(), // Create client once
+            base_url: API_BASE_URI.trim_end_matches('/').to_string(),
+            retry_policy: None,
+            nonce_provider: Arc::new(AtomicNonceProvider::new()),
+            credit_tracker: CreditTracker::default(),
+            cassette: None,
         }
     }
 
-    /// Constructs a complete URL path by replacing placeholders in the method's path segments with actual values.
-    ///
-    /// This function processes each segment of the API endpoint path, replacing placeholders (segments
-    /// starting with ':') with corresponding values from the provided parameters map. It then combines
-    /// these processed segments with the API base URI to form a complete URL path.
+    /// Returns a builder for a customized client.
     ///
-    /// # Parameters
+    /// The builder allows overriding the base URL (e.g. to point the SDK at a local
+    /// stand-in server), passing a pre-configured `reqwest::Client`, or configuring
+    /// timeouts, the user agent and a proxy for the internally created client.
     ///
-    /// * `method_setting` - The method configuration containing path segments and other API endpoint details.
-    /// * `path_params` - A mutable map of parameter names to values. Parameters used in the path are removed
-    ///   from this map during processing to track which ones have been consumed.
+    /// # Example
     ///
-    /// # Returns
+    /// ```
+    /// use std::time::Duration;
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4;
     ///
-    /// * `Result<String, Error>` - On success, returns the complete URL path with all placeholders
-    ///   replaced. On failure (e.g., missing required path parameter), returns an error.
-    fn build_url_path(
-        method_setting: &MethodSetting,
-        path_params: &mut HashMap<&'static str, String>, // Mutable to remove used params
-    ) -> Result<String, Error> {
-        let mut processed_segments: Vec<String> = Vec::new();
-        // Use the defined path_segments from the method setting
-        for segment in method_setting.path_segments {
-            if segment.starts_with(':') {
-                let param_name = &segment[1..];
-                if let Some(value) = path_params.remove(param_name) {
-                    // TODO: Consider URL-encoding path segments if they can contain special characters
-                    processed_segments.push(value); // Use the provided value
-                } else {
-                    // If the placeholder wasn't found in params, it's an error
-                    return Err(Error::MissingPathParameter(param_name));
-                }
-            } else {
-                // It's a literal segment
-                processed_segments.push(segment.to_string());
-            }
-        }
+    /// let client = TradingApiSdkV4::builder()
+    ///     .api_key("your_api_key")
+    ///     .api_secret("your_api_secret")
+    ///     .base_url("http://127.0.0.1:8080/v4")
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent("my-trading-bot/1.0")
+    ///     .build()
+    ///     .expect("valid configuration");
+    /// assert_eq!(client.base_url(), "http://127.0.0.1:8080/v4");
+    /// ```
+    pub fn builder() -> TradingApiSdkV4Builder {
+        TradingApiSdkV4Builder::new()
+    }
 
-        // Ensure base URI doesn't end with '/' before joining
-        let base = API_BASE_URI.trim_end_matches('/');
-        // Join the base and the processed segments
-        let final_path = format!("{}/{}", base, processed_segments.join("/"));
+    /// Returns the base URL this client sends its requests to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
 
-        Ok(final_path)
+    /// Returns the credit balance reported with the last response, successful or not.
+    ///
+    /// `None` until the first response has been received.
+    pub fn credits(&self) -> Option<i32> {
+        self.credit_tracker.reported()
+    }
+
+    /// Returns the estimated current credit balance.
+    ///
+    /// Starts from the last reported balance, subtracts the documented cost of the requests
+    /// sent since and adds the credits regenerated in the meantime (see `CreditFloor`).
+    pub fn estimated_credits(&self) -> Option<i32> {
+        self.credit_tracker.estimated()
     }
 
     /// Executes an API request to the Bitcoin.de Trading API.
@@ -135,6 +168,13 @@ impl TradingApiSdkV4 {
     ///
     /// * `Result<T, Error>` - On success, returns the deserialized response body as type T.
     ///   On failure, returns an error that may include API error details if available.
+    ///
+    /// # Tracing
+    ///
+    /// Each call runs in a `bitcoin_de.request` info span with the fields `api.method`,
+    /// `http.method`, `http.route` (the path template, e.g. `/orders/:trading_pair`),
+    /// `http.status_code`, `latency_ms`, `credits` and `retries`, with a debug event per
+    /// attempt and a warning per retry. Headers, signatures and credentials are never recorded.
     pub async fn do_request<T: DeserializeOwned>(
         &self,
         method_name: &'static str,
@@ -143,222 +183,77 @@ impl TradingApiSdkV4 {
     where
         T: DeserializeOwned,
     {
-        let method_setting = self.method_settings.get(method_name).ok_or_else(|| {
-            Error::MethodNotFound(method_name) // Use the new Error variant
-        })?;
-
-        // println!("\n--- Request for: {} ---", method_name);
-        // println!("Method Setting: {:?}", method_setting);
-        // println!("Input Parameters: {:?}", parameters);
-
-        let mut params = parameters.unwrap_or_default();
+        self.do_request_with(method_name, request_parameters(parameters)).await
+    }
 
-        // 1. Separate Path vs. Query/Body Parameters
-        let mut path_params_map = HashMap::new();
-        // Identify path params based on method_setting.path_segments
-        for segment in method_setting.path_segments {
-            if segment.starts_with(':') {
-                let param_name = &segment[1..];
-                // Try to get the value. We don't remove it yet,
-                // build_url_path will handle removal and error checking.
-                if let Some(value) = params.get(param_name) {
-                    path_params_map.insert(param_name, value.clone());
+    /// Like [`do_request`](Self::do_request), for the parameters of the typed requests.
+    pub(crate) async fn do_request_with<T: DeserializeOwned>(&self, method_name: &'static str, parameters: RequestParameters) -> Result<T, Error> {
+        let request = PreparedRequest::new(self.method_settings, &self.base_url, method_name, parameters)?;
+        let span = request.span.clone();
+
+        // Send, retrying transient failures if a retry policy is configured and there is a
+        // timer to wait between attempts. Every attempt is charged, so each one has to pass
+        // the credit floor.
+        async move {
+            let mut attempt = 1;
+            loop {
+                self.credit_tracker.acquire(request.credits).await?;
+                let started = credits::now();
+                let result = self.send_signed_request(&request).await;
+                request.trace_attempt(&result, attempt, started);
+                match (result, &self.retry_policy) {
+                    (Err(error), Some(policy)) if retry::HAS_TIMER && policy.should_retry(&error, request.http_method, attempt) => {
+                        let backoff = policy.backoff(attempt);
+                        tracing::warn!(attempt, backoff_ms = backoff.as_millis() as u64, %error, "retrying request");
+                        retry::sleep(backoff).await;
+                        attempt += 1;
+                    }
+                    (result, _) => return result,
                 }
-                // If the param is missing here, build_url_path will error later if required.
             }
         }
-        for (key, _) in &path_params_map {
-            params.remove(key);
-        }
-        let remaining_params = params; // These are now query or body params
-
-        let base_url_path = TradingApiSdkV4::build_url_path(method_setting, &mut path_params_map)?; // path_params_map is mutated here
-        // println!("Base URL Path: {}", base_url_path);
-
-
-        // 2. Prepare URL and Parameters based on HTTP Method
-        let mut url_for_request = Url::parse(&base_url_path)?;
-        let url_for_signature: String;
-        let params_for_signature: Option<&HashMap<&'static str, String>>;
-        let params_for_body: Option<&HashMap<&'static str, String>>;
-        // params_for_query is implicitly handled by url_for_request
-
+        .instrument(span)
+        .await
+    }
 
-        match method_setting.http_method {
-            "GET" | "DELETE" => {
-                // Append remaining_params as query parameters for both request and signature URL
-                if !remaining_params.is_empty() {
-                    let query_string = serde_urlencoded::to_string(&remaining_params)?;
-                    url_for_request.set_query(Some(&query_string));
-                }
-                url_for_signature = url_for_request.to_string(); // Signature uses full URL with query
-                params_for_signature = None; // GET/DELETE use empty string MD5
-                params_for_body = None;
-                // params_for_query = None; // Already in url_for_request
-                // println!("GET/DELETE - Final URL for Request: {}", url_for_request);
-                // println!("GET/DELETE - URL for Signature: {}", url_for_signature);
-            }
-            "POST" => {
-                url_for_signature = base_url_path.clone(); // Signature uses base path
-                params_for_signature = if remaining_params.is_empty() { None } else { Some(&remaining_params) };
-                params_for_body = if remaining_params.is_empty() { None } else { Some(&remaining_params) };
-                // params_for_query = None;
-                // println!("POST - URL for Request: {}", url_for_request);
-                // println!("POST - URL for Signature: {}", url_for_signature);
-                // println!("POST - Params for Signature/Body: {:?}", params_for_signature);
-            }
-            _ => {
-                // This case should theoretically be unreachable if method_settings are correct
-                return Err(Error::Other(format!("Unsupported HTTP method: {}", method_setting.http_method)));
+    /// Signs and sends a single attempt of a request with a fresh nonce.
+    async fn send_signed_request<T: DeserializeOwned>(&self, request: &PreparedRequest) -> Result<T, Error> {
+        // 0. Serve a recorded response when replaying a cassette, without touching the network
+        if let Some(cassette) = &self.cassette {
+            if let Some((status_code, response_text)) = cassette.replay(request)? {
+                return handle_response(status_code, &response_text, &self.credit_tracker, &request.span);
             }
         }
 
+        // 1. Sign the request and create the headers
+        let headers = request.signed_headers(&self.api_credentials, self.nonce_provider.as_ref())?;
 
-        // 3. Generate Nonce
-        let nonce = SystemTime::now()
-            .duration_since(UNIX_EPOCH)?
-            .as_micros()
-            .to_string();
-        // println!("Nonce: {}", nonce);
-
-        // 4. Generate Signature
-        let signature = self.generate_signature(
-            method_setting.http_method,
-            &url_for_signature, // Use the correctly determined URL for signature
-            &nonce,
-            params_for_signature, // Pass only relevant params for signature
-        )?;
-        // println!("Signature: {}", signature);
-
-        // 5. Create Headers
-        let mut headers = HeaderMap::new();
-        headers.insert("X-API-KEY", HeaderValue::from_str(&self.api_credentials.api_key)?);
-        headers.insert("X-API-NONCE", HeaderValue::from_str(&nonce)?);
-        headers.insert("X-API-SIGNATURE", HeaderValue::from_str(&signature)?);
-        // Ensure Content-Type for POST requests with a body
-        if method_setting.http_method == "POST" && params_for_body.is_some() {
-            headers.insert("Content-Type", HeaderValue::from_static("application/x-www-form-urlencoded"));
-        }
-        // println!("Request Headers: {:?}", headers);
-
-
-        // 6. Build and Send HTTP Request
-        let mut request_builder = match method_setting.http_method {
-            "GET" => self.client.get(url_for_request),
-            "POST" => self.client.post(url_for_request),
-            "DELETE" => self.client.delete(url_for_request),
-            _ => unreachable!(), // Already handled
+        // 2. Build and Send HTTP Request
+        let mut request_builder = match request.http_method {
+            "GET" => self.client.get(request.url_for_request.clone()),
+            "POST" => self.client.post(request.url_for_request.clone()),
+            "DELETE" => self.client.delete(request.url_for_request.clone()),
+            _ => return Err(Error::Other(format!("Unsupported HTTP method: {}", request.http_method))),
         };
 
         request_builder = request_builder.headers(headers);
 
         // Add body for POST requests
-        if let Some(body_params) = params_for_body {
-            // Use .form() for application/x-www-form-urlencoded
+        if let Some(body_params) = &request.body {
+            // Use .form() for application/x-www-form-urlencoded, in the order the body was signed in
             request_builder = request_builder.form(body_params);
-            // println!("Request Body (form): {:?}", body_params);
         }
-        // Note: Query parameters for GET/DELETE are already part of the url_for_request URL
-
-        // println!("Sending Request: {:?}", request_builder); // Debug before sending
+        // Note: Query parameters for GET/DELETE are already part of the request URL
 
         let response = request_builder.send().await?;
         let status_code = response.status();
-        // println!("Response Status: {}", status_code);
 
-        // 7. Handle Response
+        // 3. Handle Response
         let response_text = response.text().await?; // Read body regardless of status for potential error details
-
-        if !status_code.is_success() {
-            eprintln!("API Error Response Body: {}", response_text);
-            // Use the helper function to create the API error variant
-            Err(Error::api_error(status_code, response_text))
-        } else {
-            // println!("API Success Response Body: {}", response_text);
-            // Deserialize the successful response body into type T
-            let deserialized_response = from_str(&response_text)?; // Deserialize here
-            Ok(deserialized_response) // Return the deserialized struct
+        if let Some(cassette) = &self.cassette {
+            cassette.record(request, status_code, &response_text);
         }
-    }
-
-    /// Generates a cryptographic signature for authenticating API requests to Bitcoin.de.
-    ///
-    /// This function implements the Bitcoin.de Trading API v4 signature generation process:
-    /// 1. Calculates an MD5 hash of the request body parameters (for POST requests)
-    /// 2. Constructs a signature string from the HTTP method, URL, API key, nonce, and MD5 hash
-    /// 3. Computes an HMAC-SHA256 signature using the API secret as the key
-    /// 4. Returns the hex-encoded signature for use in the X-API-SIGNATURE header
-    ///
-    /// # Parameters
-    ///
-    /// * `http_method` - The HTTP method of the request (GET, POST, DELETE)
-    /// * `url_for_signature` - The complete URL path used for signature generation
-    /// * `nonce` - A unique timestamp-based value to prevent replay attacks
-    /// * `body_parameters` - Optional request body parameters for POST requests; None for GET/DELETE
-    ///
-    /// # Returns
-    ///
-    /// * `Result<String, Error>` - On success, returns the hex-encoded HMAC-SHA256 signature.
-    ///   On failure (e.g., HMAC initialization error), returns an error.
-    fn generate_signature(
-        &self,
-        http_method: &str,
-        url_for_signature: &str, // Renamed for clarity
-        nonce: &str,
-        body_parameters: Option<&HashMap<&'static str, String>>, // Renamed for clarity (only for POST)
-    ) -> Result<String, Error> {
-        let api_key = &self.api_credentials.api_key;
-        let api_secret = &self.api_credentials.api_secret;
-
-        // 1. Prepare POST parameter MD5 hash (only if body_parameters exist)
-        let post_parameter_md5_hashed_string = match body_parameters {
-            Some(params) if !params.is_empty() => {
-                // Sort parameters alphabetically by key
-                let mut sorted_params: Vec<(&&str, &String)> = params.iter().collect();
-                sorted_params.sort_by_key(|(key, _)| *key);
-                // println!("Sorted Body Parameters for MD5: {:?}", sorted_params);
-
-                // URL-encode the sorted parameters
-                let url_encoded_query_string = serde_urlencoded::to_string(&sorted_params)?;
-                // println!("URL Encoded Body String for MD5: {}", url_encoded_query_string);
-
-                // Calculate MD5 hash
-                let digest = md5::compute(url_encoded_query_string.as_bytes());
-                let md5_hex = hex::encode(digest.0);
-                // println!("Body MD5 Hash (Hex): {}", md5_hex);
-                md5_hex
-            }
-            _ => {
-                // For GET, DELETE, or POST with empty body
-                let empty_md5 = "d41d8cd98f00b204e9800998ecf8427e";
-                // println!("Body MD5 Hash (Empty/GET/DELETE): {}", empty_md5);
-                empty_md5.to_string()
-            }
-        };
-
-        // 2. Concatenate HMAC data
-        let hmac_data = format!(
-            "{}#{}#{}#{}#{}",
-            http_method.to_uppercase(),
-            url_for_signature, // Use the correct URL passed in
-            api_key,
-            nonce,
-            post_parameter_md5_hashed_string
-        );
-        // println!("HMAC Data String: {}", hmac_data);
-
-        // 3. Calculate HMAC-SHA256 signature
-        type HmacSha256 = Hmac<Sha256>;
-        let mut mac = HmacSha256::new_from_slice(api_secret.as_bytes())?;
-        mac.update(hmac_data.as_bytes());
-        let result = mac.finalize();
-        let signature_bytes = result.into_bytes();
-
-        // 4. Hex encode the signature (lowercase)
-        let signature = hex::encode(signature_bytes); // hex::encode produces lowercase
-        // println!("Final Signature (Hex): {}", signature);
-
-        Ok(signature)
+        handle_response(status_code, &response_text, &self.credit_tracker, &request.span)
     }
 
     // --- Public API Methods (Wrappers around do_request) ---
@@ -388,17 +283,22 @@ impl TradingApiSdkV4 {
     ///
     /// # Arguments
     ///
-    /// * `trading_pair` - The trading pair (e.g., "btceur").
-    /// * `params` - Optional additional query parameters (e.g., "type", "amount_currency_to_trade").
+    /// * `trading_pair` - The trading pair of the order book.
+    /// * `query` - The order type and optional filters (amount, price, requirements, ...).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if the query is invalid (see [`OrderbookQuery::validate`]),
+    /// or the amount or price has more decimal places than its currency supports.
     pub async fn show_orderbook(
         &self,
-        trading_pair: String,
-        params: Option<HashMap<&'static str, String>>,
+        trading_pair: TradingPair,
+        query: &OrderbookQuery,
     ) -> Result<ShowOrderbookResponse, Error> {
-        let mut all_params = params.unwrap_or_default();
+        let mut all_params = query.to_parameters(trading_pair)?;
         // Add the trading_pair as a path parameter for do_request
-        all_params.insert(SHOW_ORDERBOOK_PARAMETER_TRADING_PAIR, trading_pair);
-        self.do_request(METHOD_SHOW_ORDERBOOK, Some(all_params)).await
+        all_params.insert(SHOW_ORDERBOOK_PARAMETER_TRADING_PAIR.into(), trading_pair.to_string().to_ascii_lowercase());
+        self.do_request_with(METHOD_SHOW_ORDERBOOK, all_params).await
     }
 
     /// Retrieves details for a specific public order.
@@ -425,15 +325,22 @@ impl TradingApiSdkV4 {
     /// # Arguments
     ///
     /// * `trading_pair` - The trading pair (e.g., "btceur").
-    /// * `params` - Parameters for the order (e.g., "type", "max_amount_currency_to_trade", "price").
+    /// * `request` - The order parameters, validated before the request is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the request is invalid
+    /// or an amount or price has more decimal places than its currency supports. The minimum
+    /// order amount is checked by the API.
     pub async fn create_order(
         &self,
         trading_pair: String,
-        mut params: HashMap<&'static str, String>, // Expect required params here
+        request: &CreateOrderRequest,
     ) -> Result<CreateOrderResponse, Error> {
+        let mut params = request.to_parameters(trading_pair.parse()?)?;
         // Add the trading_pair as a path parameter for do_request
-        params.insert(CREATE_ORDER_PARAMETER_TRADING_PAIR, trading_pair);
-        self.do_request(METHOD_CREATE_ORDER, Some(params)).await
+        params.insert(CREATE_ORDER_PARAMETER_TRADING_PAIR.into(), trading_pair);
+        self.do_request_with(METHOD_CREATE_ORDER, params).await
     }
 
     /// Deletes an existing order.
@@ -463,21 +370,93 @@ impl TradingApiSdkV4 {
     /// * `trading_pair` - Optional trading pair to filter by.
     ///                    Pass `None` to get orders for all pairs.
     ///                    Use constants from `enums::TradingPair` and convert to lowercase string.
-    /// * `params` - Optional additional query parameters (e.g., "type", "state", "page").
+    /// * `filter` - Order type, state, creation date range and page to list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if the filter is inconsistent (see [`MyOrdersFilter::validate`]).
     pub async fn show_my_orders(
         &self,
         trading_pair: Option<String>,
-        params: Option<HashMap<&'static str, String>>,
+        filter: &MyOrdersFilter,
     ) -> Result<ShowMyOrdersResponse, Error> {
-        let mut all_params = params.unwrap_or_default();
+        let mut all_params = filter.to_parameters()?;
         // Add trading_pair as a path parameter if provided
         if let Some(pair) = trading_pair {
             // This parameter name needs to match the one used as the placeholder in method_settings.rs
-            all_params.insert(SHOW_MY_ORDERS_PARAMETER_TRADING_PAIR, pair);
+            all_params.insert(SHOW_MY_ORDERS_PARAMETER_TRADING_PAIR.into(), pair);
         }
-        // Note: The do_request function and method_settings need to correctly handle
-        // the optional path parameter for this endpoint.
-        self.do_request(METHOD_SHOW_MY_ORDERS, Some(all_params)).await
+        // Without a trading pair, the optional path segment is left out: GET /v4/orders
+        self.do_request_with(METHOD_SHOW_MY_ORDERS, all_params).await
+    }
+
+    /// Streams your orders of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same arguments as [`show_my_orders`](Self::show_my_orders); the `page` of the filter is ignored.
+    /// Every page is a separate `showMyOrders` request and passes the credit floor, if configured.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4;
+    /// use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::MyOrdersFilter;
+    ///
+    /// # async fn run() -> Result<(), bitcoin_de::bitcoin_de_trading_api_sdk_v4::Error> {
+    /// let client = TradingApiSdkV4::new("your_api_key".to_string(), "your_api_secret".to_string());
+    /// let orders = client.show_my_orders_stream(Some("btceur".to_string()), &MyOrdersFilter::new());
+    /// pin_mut!(orders);
+    /// while let Some(order) = orders.next().await {
+    ///     println!("{}", order?.order_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn show_my_orders_stream(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> impl Stream<Item = Result<MyOrderDetails, Error>> + '_ {
+        let filter = filter.clone();
+        pagination::paginate(move |page| {
+            let filter = MyOrdersFilter { page: Some(page as u32), ..filter.clone() };
+            let trading_pair = trading_pair.clone();
+            async move { self.show_my_orders(trading_pair, &filter).await }
+        })
+    }
+
+    /// Retrieves your orders of all pages.
+    ///
+    /// Collects [`show_my_orders_stream`](Self::show_my_orders_stream) and fails on the first failing page.
+    pub async fn show_my_orders_all(
+        &self,
+        trading_pair: Option<String>,
+        filter: &MyOrdersFilter,
+    ) -> Result<Vec<MyOrderDetails>, Error> {
+        self.show_my_orders_stream(trading_pair, filter).try_collect().await
+    }
+
+    /// Retrieves details for one of your orders.
+    /// Corresponds to the `showMyOrderDetails` API method.
+    ///
+    /// Unlike [`show_order_details`](Self::show_order_details) for public orders of other
+    /// users, the response includes the state, expiry and creation time of the order.
+    ///
+    /// # Arguments
+    ///
+    /// * `trading_pair` - The trading pair (e.g., "btceur").
+    /// * `order_id` - The ID of the order.
+    pub async fn show_my_order_details(
+        &self,
+        trading_pair: String,
+        order_id: String,
+    ) -> Result<ShowMyOrderDetailsResponse, Error> {
+        let mut params = HashMap::new();
+        // Add the trading_pair and order_id as path parameters for do_request
+        params.insert(SHOW_MY_ORDER_DETAILS_PARAMETER_TRADING_PAIR, trading_pair);
+        params.insert(SHOW_MY_ORDER_DETAILS_PARAMETER_ORDER_ID, order_id);
+        self.do_request(METHOD_SHOW_MY_ORDER_DETAILS, Some(params)).await
     }
 
     /// Retrieves the user's trades.
@@ -500,10 +479,35 @@ impl TradingApiSdkV4 {
         if let Some(pair) = trading_pair {
             all_params.insert(SHOW_MY_TRADES_PARAMETER_TRADING_PAIR, pair);
         }
-        // Note: Similar handling needed in do_request for optional path param
+        // Without a trading pair, the optional path segment is left out: GET /v4/trades
         self.do_request(METHOD_SHOW_MY_TRADES, Some(all_params)).await
     }
 
+    /// Streams your trades of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same arguments as [`show_my_trades`](Self::show_my_trades); a `page` parameter is ignored.
+    /// Every page is a separate `showMyTrades` request and passes the credit floor, if configured.
+    pub fn show_my_trades_stream(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Stream<Item = Result<MyTradeDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate(move |page| self.show_my_trades(trading_pair.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your trades of all pages.
+    ///
+    /// Collects [`show_my_trades_stream`](Self::show_my_trades_stream) and fails on the first failing page.
+    pub async fn show_my_trades_all(
+        &self,
+        trading_pair: Option<String>,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<MyTradeDetails>, Error> {
+        self.show_my_trades_stream(trading_pair, params).try_collect().await
+    }
+
     /// Retrieves details for one of your trades.
     /// Corresponds to the `showMyTradeDetails` API method.
     ///
@@ -530,17 +534,52 @@ impl TradingApiSdkV4 {
     ///
     /// * `trading_pair` - The trading pair (e.g., "btceur").
     /// * `order_id` - The ID of the order to trade against.
-    /// * `params` - Parameters for the trade execution (e.g., "type", "amount_currency_to_trade").
+    /// * `request` - The trade parameters, validated before the request is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the request is invalid,
+    /// or an amount or price has more decimal places than its currency supports.
     pub async fn execute_trade(
         &self,
         trading_pair: String,
         order_id: String,
-        mut params: HashMap<&'static str, String>, // Expect required params here
+        request: &ExecuteTradeRequest,
     ) -> Result<ExecuteTradeResponse, Error> {
+        let mut params = request.to_parameters(trading_pair.parse()?)?;
         // Add the trading_pair and order_id as path parameters for do_request
-        params.insert(EXECUTE_TRADE_PARAMETER_TRADING_PAIR, trading_pair);
-        params.insert(EXECUTE_TRADE_PARAMETER_ORDER_ID, order_id);
-        self.do_request(METHOD_EXECUTE_TRADE, Some(params)).await
+        params.insert(EXECUTE_TRADE_PARAMETER_TRADING_PAIR.into(), trading_pair);
+        params.insert(EXECUTE_TRADE_PARAMETER_ORDER_ID.into(), order_id);
+        self.do_request_with(METHOD_EXECUTE_TRADE, params).await
+    }
+
+    /// Executes a trade after checking the amount against the order's limits.
+    ///
+    /// Fetches the order via `show_order_details` first and rejects amounts outside its
+    /// `min_amount_currency_to_trade`/`max_amount_currency_to_trade` locally, instead of
+    /// spending credits on an `executeTrade` call the API would reject.
+    ///
+    /// # Arguments
+    ///
+    /// * `trading_pair` - The trading pair (e.g., "btceur").
+    /// * `order_id` - The ID of the order to trade against.
+    /// * `request` - The trade parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` if the amount is outside the order's limits, or
+    /// any error of `show_order_details` and `execute_trade`.
+    pub async fn execute_trade_checked(
+        &self,
+        trading_pair: String,
+        order_id: String,
+        request: &ExecuteTradeRequest,
+    ) -> Result<ExecuteTradeResponse, Error> {
+        // Validate the request itself before spending credits on the order lookup
+        request.validate()?;
+        let order = self.show_order_details(trading_pair.clone(), order_id.clone()).await?;
+        request.validate_against_order(&order.order_details)?;
+        self.execute_trade(trading_pair, order_id, request).await
     }
 
     /// Marks a trade as paid.
@@ -550,19 +589,31 @@ impl TradingApiSdkV4 {
     ///
     /// * `trading_pair` - The trading pair (e.g., "btceur").
     /// * `trade_id` - The ID of the trade.
-    /// * `volume_currency_to_pay_after_fee` - The EUR volume after fee.
+    /// * `volume_currency_to_pay_after_fee` - The volume after fee in the currency to pay (e.g. EUR for btceur).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the volume is not
+    /// positive or has more decimal places than the currency to pay supports (see
+    /// [`Currency::decimals`]), or the pair is crypto-to-crypto and settled with
+    /// `mark_coins_as_transferred` instead.
     pub async fn mark_trade_as_paid(
         &self,
         trading_pair: String,
         trade_id: String,
-        volume_currency_to_pay_after_fee: String, // Use String or Decimal and convert
+        volume_currency_to_pay_after_fee: Decimal,
     ) -> Result<MarkTradeAsPaidResponse, Error> {
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::BankTransfer, MARK_TRADE_AS_PAID_PARAMETER_TRADING_PAIR)?;
         let mut params = HashMap::new();
+        // Add body parameter, in the currency to pay (e.g. EUR for btceur)
+        params.insert(
+            MARK_TRADE_AS_PAID_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE,
+            positive_decimal_param(MARK_TRADE_AS_PAID_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE, volume_currency_to_pay_after_fee, pair.quote)?,
+        );
         // Add path parameters
         params.insert(MARK_TRADE_AS_PAID_PARAMETER_TRADING_PAIR, trading_pair);
         params.insert(MARK_TRADE_AS_PAID_PARAMETER_TRADE_ID, trade_id);
-        // Add body parameter
-        params.insert(MARK_TRADE_AS_PAID_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE, volume_currency_to_pay_after_fee);
         self.do_request(METHOD_MARK_TRADE_AS_PAID, Some(params)).await
     }
 
@@ -573,13 +624,34 @@ impl TradingApiSdkV4 {
     ///
     /// * `trading_pair` - The trading pair (e.g., "btceur").
     /// * `trade_id` - The ID of the trade.
-    /// * `params` - Parameters for marking as received (e.g., "volume_currency_to_pay_after_fee", "rating").
+    /// * `volume_currency_to_pay_after_fee` - The received volume after fee in the currency to pay (e.g. EUR for btceur).
+    /// * `rating` - The rating of the trading partner ("positive", "neutral", "negative").
+    /// * `is_paid_from_correct_bank_account` - Whether the payment came from the partner's registered bank account.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the volume is not
+    /// positive or has more decimal places than the currency to pay supports (see
+    /// [`Currency::decimals`]), or the pair is crypto-to-crypto and settled with
+    /// `mark_coins_as_received` instead.
     pub async fn mark_trade_as_payment_received(
         &self,
         trading_pair: String,
         trade_id: String,
-        mut params: HashMap<&'static str, String>, // Expect required params here
+        volume_currency_to_pay_after_fee: Decimal,
+        rating: String,
+        is_paid_from_correct_bank_account: bool,
     ) -> Result<MarkTradeAsPaymentReceivedResponse, Error> {
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::BankTransfer, MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_TRADING_PAIR)?;
+        let mut params = HashMap::new();
+        // Add body parameters
+        params.insert(
+            MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE,
+            positive_decimal_param(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_VOLUME_CURRENCY_TO_PAY_AFTER_FEE, volume_currency_to_pay_after_fee, pair.quote)?,
+        );
+        params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_RATING, rating);
+        params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_IS_PAID_FROM_CORRECT_BANK_ACCOUNT, bool_param(is_paid_from_correct_bank_account));
         // Add path parameters
         params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_TRADING_PAIR, trading_pair);
         params.insert(MARK_TRADE_AS_PAYMENT_RECEIVED_PARAMETER_TRADE_ID, trade_id);
@@ -630,6 +702,31 @@ impl TradingApiSdkV4 {
         self.do_request(METHOD_SHOW_ACCOUNT_LEDGER, Some(all_params)).await
     }
 
+    /// Streams your ledger entries of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same arguments as [`show_account_ledger`](Self::show_account_ledger); a `page` parameter is ignored.
+    /// Every page is a separate `showAccountLedger` request and passes the credit floor, if configured.
+    pub fn show_account_ledger_stream(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Stream<Item = Result<LedgerEntry, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate(move |page| self.show_account_ledger(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your ledger entries of all pages.
+    ///
+    /// Collects [`show_account_ledger_stream`](Self::show_account_ledger_stream) and fails on the first failing page.
+    pub async fn show_account_ledger_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<LedgerEntry>, Error> {
+        self.show_account_ledger_stream(currency, params).try_collect().await
+    }
+
     /// Retrieves the permissions associated with the API key.
     /// Corresponds to the `showPermissions` API method.
     pub async fn show_permissions(&self) -> Result<ShowPermissionsResponse, Error> {
@@ -642,12 +739,28 @@ impl TradingApiSdkV4 {
     /// # Arguments
     ///
     /// * `currency` - The currency to withdraw (e.g., "BTC").
-    /// * `params` - Parameters for the withdrawal (e.g., "amount", "address", "network_fee").
+    /// * `amount` - The amount to withdraw.
+    /// * `address` - The recipient address.
+    /// * `network_fee` - The network fee, at least the one returned by `show_withdrawal_min_network_fee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the amount or fee
+    /// is not positive or has more decimal places than the currency supports (see
+    /// [`Currency::decimals`]).
     pub async fn create_withdrawal(
         &self,
         currency: String,
-        mut params: HashMap<&'static str, String>, // Expect required params here
+        amount: Decimal,
+        address: String,
+        network_fee: Decimal,
     ) -> Result<CreateWithdrawalResponse, Error> {
+        let coin: Currency = currency.parse()?;
+        let mut params = HashMap::new();
+        // Add body parameters
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_AMOUNT, positive_decimal_param(CREATE_WITHDRAWAL_PARAMETER_AMOUNT, amount, coin)?);
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_ADDRESS, address);
+        params.insert(CREATE_WITHDRAWAL_PARAMETER_NETWORK_FEE, positive_decimal_param(CREATE_WITHDRAWAL_PARAMETER_NETWORK_FEE, network_fee, coin)?);
         // Add the currency as a path parameter for do_request
         params.insert(CREATE_WITHDRAWAL_PARAMETER_CURRENCY, currency);
         self.do_request(METHOD_CREATE_WITHDRAWAL, Some(params)).await
@@ -710,6 +823,31 @@ impl TradingApiSdkV4 {
         self.do_request(METHOD_SHOW_WITHDRAWALS, Some(all_params)).await
     }
 
+    /// Streams your withdrawals of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same arguments as [`show_withdrawals`](Self::show_withdrawals); a `page` parameter is ignored.
+    /// Every page is a separate `showWithdrawals` request and passes the credit floor, if configured.
+    pub fn show_withdrawals_stream(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Stream<Item = Result<WithdrawalDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate(move |page| self.show_withdrawals(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your withdrawals of all pages.
+    ///
+    /// Collects [`show_withdrawals_stream`](Self::show_withdrawals_stream) and fails on the first failing page.
+    pub async fn show_withdrawals_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<WithdrawalDetails>, Error> {
+        self.show_withdrawals_stream(currency, params).try_collect().await
+    }
+
     /// Retrieves the minimum network fee for a withdrawal in a specific currency.
     /// Corresponds to the `showWithdrawalMinNetworkFee` API method.
     ///
@@ -787,6 +925,31 @@ impl TradingApiSdkV4 {
         self.do_request(METHOD_SHOW_DEPOSITS, Some(all_params)).await
     }
 
+    /// Streams your deposits of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same arguments as [`show_deposits`](Self::show_deposits); a `page` parameter is ignored.
+    /// Every page is a separate `showDeposits` request and passes the credit floor, if configured.
+    pub fn show_deposits_stream(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Stream<Item = Result<DepositDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate(move |page| self.show_deposits(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your deposits of all pages.
+    ///
+    /// Collects [`show_deposits_stream`](Self::show_deposits_stream) and fails on the first failing page.
+    pub async fn show_deposits_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<DepositDetails>, Error> {
+        self.show_deposits_stream(currency, params).try_collect().await
+    }
+
     /// Creates a new outgoing address in the address pool.
     /// Corresponds to the `createOutgoingAddress` API method.
     ///
@@ -842,6 +1005,31 @@ impl TradingApiSdkV4 {
         self.do_request(METHOD_SHOW_OUTGOING_ADDRESSES, Some(all_params)).await
     }
 
+    /// Streams your outgoing addresses of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same arguments as [`show_outgoing_addresses`](Self::show_outgoing_addresses); a `page` parameter is ignored.
+    /// Every page is a separate `showOutgoingAddresses` request and passes the credit floor, if configured.
+    pub fn show_outgoing_addresses_stream(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> impl Stream<Item = Result<OutgoingAddressDetails, Error>> + '_ {
+        let params = params.unwrap_or_default();
+        pagination::paginate(move |page| self.show_outgoing_addresses(currency.clone(), Some(pagination::with_page(&params, page))))
+    }
+
+    /// Retrieves your outgoing addresses of all pages.
+    ///
+    /// Collects [`show_outgoing_addresses_stream`](Self::show_outgoing_addresses_stream) and fails on the first failing page.
+    pub async fn show_outgoing_addresses_all(
+        &self,
+        currency: String,
+        params: Option<HashMap<&'static str, String>>,
+    ) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        self.show_outgoing_addresses_stream(currency, params).try_collect().await
+    }
+
     /// Retrieves the public trade history for a specific trading pair.
     /// Corresponds to the `showPublicTradeHistory` API method.
     /// Can filter by `since_tid`.
@@ -938,11 +1126,33 @@ impl TradingApiSdkV4 {
         params.insert(LIST_ADDRESS_POOL_PARAMETER_CURRENCY, currency);
         // Add page as a query parameter if provided
         if let Some(p) = page {
-            params.insert("page", p); // Use literal "page" parameter name
+            params.insert(PARAMETER_PAGE, p);
         }
         self.do_request(METHOD_LIST_ADDRESS_POOL, Some(params)).await
     }
 
+    /// Streams your address pool entries of all pages, fetching the next page only when the
+    /// items of the previous one have been consumed.
+    ///
+    /// Takes the same `currency` as [`list_address_pool`](Self::list_address_pool).
+    /// Every page is a separate `listAddressPool` request and passes the credit floor, if configured.
+    pub fn list_address_pool_stream(
+        &self,
+        currency: String,
+    ) -> impl Stream<Item = Result<OutgoingAddressDetails, Error>> + '_ {
+        pagination::paginate(move |page| self.list_address_pool(currency.clone(), Some(page.to_string())))
+    }
+
+    /// Retrieves your address pool entries of all pages.
+    ///
+    /// Collects [`list_address_pool_stream`](Self::list_address_pool_stream) and fails on the first failing page.
+    pub async fn list_address_pool_all(
+        &self,
+        currency: String,
+    ) -> Result<Vec<OutgoingAddressDetails>, Error> {
+        self.list_address_pool_stream(currency).try_collect().await
+    }
+
     /// Marks coins as transferred for a crypto-to-crypto trade.
     /// Corresponds to the `markCoinsAsTransferred` API method.
     ///
@@ -950,19 +1160,31 @@ impl TradingApiSdkV4 {
     ///
     /// * `trading_pair` - The trading pair (e.g., "btceur").
     /// * `trade_id` - The ID of the trade.
-    /// * `amount_currency_to_trade_after_fee` - The amount after fee.
+    /// * `amount_currency_to_trade_after_fee` - The amount after fee in the currency to trade (e.g. ETH for ethbtc).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the amount is not
+    /// positive or has more decimal places than the currency to trade supports (see
+    /// [`Currency::decimals`]), or the pair is paid in fiat and settled with
+    /// `mark_trade_as_paid` instead.
     pub async fn mark_coins_as_transferred(
         &self,
         trading_pair: String,
         trade_id: String,
-        amount_currency_to_trade_after_fee: String, // Use String or Decimal and convert
+        amount_currency_to_trade_after_fee: Decimal,
     ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::CoinTransfer, MARK_COINS_AS_TRANSFERRED_PARAMETER_TRADING_PAIR)?;
         let mut params = HashMap::new();
+        // Add body parameter, in the currency to trade (e.g. ETH for ethbtc)
+        params.insert(
+            MARK_COINS_AS_TRANSFERRED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE,
+            positive_decimal_param(MARK_COINS_AS_TRANSFERRED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE, amount_currency_to_trade_after_fee, pair.base)?,
+        );
         // Add path parameters
         params.insert(MARK_COINS_AS_TRANSFERRED_PARAMETER_TRADING_PAIR, trading_pair);
         params.insert(MARK_COINS_AS_TRANSFERRED_PARAMETER_TRADE_ID, trade_id);
-        // Add body parameter
-        params.insert(MARK_COINS_AS_TRANSFERRED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE, amount_currency_to_trade_after_fee);
         self.do_request(METHOD_MARK_COINS_AS_TRANSFERRED, Some(params)).await
     }
 
@@ -973,13 +1195,31 @@ impl TradingApiSdkV4 {
     ///
     /// * `trading_pair` - The trading pair (e.g., "btceur").
     /// * `trade_id` - The ID of the trade.
-    /// * `params` - Parameters for marking as received (e.g., "amount_currency_to_trade_after_fee", "rating").
+    /// * `amount_currency_to_trade_after_fee` - The received amount after fee in the currency to trade (e.g. ETH for ethbtc).
+    /// * `rating` - The rating of the trading partner ("positive", "neutral", "negative").
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidParameter` without contacting the API if the amount is not
+    /// positive or has more decimal places than the currency to trade supports (see
+    /// [`Currency::decimals`]), or the pair is paid in fiat and settled with
+    /// `mark_trade_as_payment_received` instead.
     pub async fn mark_coins_as_received(
         &self,
         trading_pair: String,
         trade_id: String,
-        mut params: HashMap<&'static str, String>, // Expect required params here
+        amount_currency_to_trade_after_fee: Decimal,
+        rating: String,
     ) -> Result<BasicSuccessResponse, Error> { // Assuming BasicSuccessResponse
+        let pair = trading_pair.parse::<TradingPair>()?.info();
+        pair.expect_settlement(Settlement::CoinTransfer, MARK_COINS_AS_RECEIVED_PARAMETER_TRADING_PAIR)?;
+        let mut params = HashMap::new();
+        // Add body parameters
+        params.insert(
+            MARK_COINS_AS_RECEIVED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE,
+            positive_decimal_param(MARK_COINS_AS_RECEIVED_PARAMETER_AMOUNT_CURRENCY_TO_TRADE_AFTER_FEE, amount_currency_to_trade_after_fee, pair.base)?,
+        );
+        params.insert(MARK_COINS_AS_RECEIVED_PARAMETER_RATING, rating);
         // Add path parameters
         params.insert(MARK_COINS_AS_RECEIVED_PARAMETER_TRADING_PAIR, trading_pair);
         params.insert(MARK_COINS_AS_RECEIVED_PARAMETER_TRADE_ID, trade_id);
@@ -987,4 +1227,257 @@ impl TradingApiSdkV4 {
         self.do_request(METHOD_MARK_COINS_AS_RECEIVED, Some(params)).await
     }
 
-}
\ No newline at end of file
+}
+
+/// A request with its URL, signature URL and body resolved from the method settings,
+/// ready to be signed and sent. Shared by the async and the blocking client.
+pub(crate) struct PreparedRequest {
+    pub(crate) method_name: &'static str,
+    pub(crate) parameters: RequestParameters, // All parameters, as recorded in a cassette
+    pub(crate) http_method: &'static str,
+    pub(crate) url_for_request: Url, // Including the query string for GET/DELETE
+    pub(crate) url_for_signature: String,
+    pub(crate) body: Option<Vec<(Cow<'static, str>, String)>>, // POST body parameters in signature order, None if empty
+    pub(crate) credits: i32, // The documented cost of the API method
+    pub(crate) span: tracing::Span, // Covers all attempts, see `PreparedRequest::new`
+}
+
+impl PreparedRequest {
+    /// Splits the parameters into path, query and body parameters and builds the URLs.
+    ///
+    /// # Parameters
+    ///
+    /// * `method_settings` - The method configurations to look up `method_name` in.
+    /// * `base_url` - The base URL all API paths are appended to.
+    /// * `method_name` - The API method name as defined in the method settings map.
+    /// * `parameters` - Path, query (GET/DELETE) and body (POST) parameters.
+    pub(crate) fn new(
+        method_settings: &HashMap<&'static str, MethodSetting>,
+        base_url: &str,
+        method_name: &'static str,
+        parameters: RequestParameters,
+    ) -> Result<Self, Error> {
+        let method_setting = method_settings.get(method_name).ok_or_else(|| {
+            Error::MethodNotFound(method_name) // Use the new Error variant
+        })?;
+
+        let mut params = parameters.clone();
+
+        // 1. Separate Path vs. Query/Body Parameters
+        let mut path_params_map = HashMap::new();
+        // Identify path params based on method_setting.path_segments
+        for segment in method_setting.path_segments {
+            if let Some((param_name, _)) = MethodSetting::placeholder(segment) {
+                // Try to get the value. We don't remove it yet,
+                // build_url_path will handle removal and error checking.
+                if let Some(value) = params.get(param_name) {
+                    path_params_map.insert(param_name, value.clone());
+                }
+                // If the param is missing here, build_url_path will error later if required.
+            }
+        }
+        for key in path_params_map.keys() {
+            params.remove(*key);
+        }
+        let remaining_params = params; // These are now query or body params
+
+        let base_url_path = build_url_path(base_url, method_setting, &mut path_params_map)?; // path_params_map is mutated here
+
+        // 2. Prepare URL and Parameters based on HTTP Method
+        let mut url_for_request = Url::parse(&base_url_path)?;
+        let url_for_signature: String;
+        let body: Option<Vec<(Cow<'static, str>, String)>>;
+
+        match method_setting.http_method {
+            "GET" | "DELETE" => {
+                // Append remaining_params as query parameters for both request and signature URL
+                if !remaining_params.is_empty() {
+                    let query_string = serde_urlencoded::to_string(&remaining_params)?;
+                    url_for_request.set_query(Some(&query_string));
+                }
+                url_for_signature = url_for_request.to_string(); // Signature uses full URL with query
+                body = None; // GET/DELETE use empty string MD5
+            }
+            "POST" => {
+                url_for_signature = url_for_request.to_string(); // Signature uses base path, like the request
+                // Sorted once, so the signed MD5 and the sent body use the same order
+                let mut body_params: Vec<_> = remaining_params.into_iter().collect();
+                sort_body_parameters(&mut body_params);
+                body = if body_params.is_empty() { None } else { Some(body_params) };
+            }
+            _ => {
+                // This case should theoretically be unreachable if method_settings are correct
+                return Err(Error::Other(format!("Unsupported HTTP method: {}", method_setting.http_method)));
+            }
+        }
+
+        // The route is the path template rather than the resolved path, so order and trade IDs
+        // do not inflate the cardinality. The empty fields are recorded per attempt.
+        let span = tracing::info_span!(
+            "bitcoin_de.request",
+            api.method = method_name,
+            http.method = method_setting.http_method,
+            http.route = %format_args!("/{}", method_setting.path_segments.join("/")),
+            http.status_code = tracing::field::Empty,
+            latency_ms = tracing::field::Empty,
+            credits = tracing::field::Empty,
+            retries = tracing::field::Empty,
+        );
+
+        Ok(PreparedRequest {
+            method_name,
+            parameters,
+            http_method: method_setting.http_method,
+            url_for_request,
+            url_for_signature,
+            body,
+            credits: method_credits(method_name).unwrap_or(0), // Every method in METHOD_SETTINGS has a cost
+            span,
+        })
+    }
+
+    /// Records the latency and outcome of an attempt on the request span.
+    ///
+    /// `started` is `None` where `Instant` is not available (wasm32).
+    pub(crate) fn trace_attempt<T>(&self, result: &Result<T, Error>, attempt: u32, started: Option<Instant>) {
+        self.span.record("retries", attempt - 1);
+        let latency_ms = started.map(|started| started.elapsed().as_millis() as u64);
+        if let Some(latency_ms) = latency_ms {
+            self.span.record("latency_ms", latency_ms);
+        }
+        match result {
+            Ok(_) => tracing::debug!(parent: &self.span, attempt, latency_ms, "request succeeded"),
+            Err(error) => tracing::debug!(parent: &self.span, attempt, latency_ms, %error, "request failed"),
+        }
+    }
+
+    /// Signs the request with a fresh nonce and returns the authentication headers.
+    ///
+    /// Every attempt of a request has to be signed again, as a nonce is only accepted once.
+    pub(crate) fn signed_headers(
+        &self,
+        api_credentials: &ApiCredentials,
+        nonce_provider: &dyn NonceProvider,
+    ) -> Result<HeaderMap, Error> {
+        // 1. Generate Nonce (strictly increasing, see NonceProvider)
+        let nonce = nonce_provider.next_nonce()?.to_string();
+
+        // 2. Generate Signature (POST body parameters only, GET/DELETE use the query in the URL)
+        let body_parameters: Vec<(&str, &str)> = self
+            .body
+            .iter()
+            .flatten()
+            .map(|(key, value)| (key.as_ref(), value.as_str()))
+            .collect();
+        let signature = sign_request(
+            self.http_method,
+            &self.url_for_signature,
+            &api_credentials.api_key,
+            api_credentials.api_secret.expose_secret(),
+            &nonce,
+            &body_parameters,
+        )?;
+
+        // 3. Create Headers
+        let mut headers = HeaderMap::new();
+        headers.insert("X-API-KEY", HeaderValue::from_str(&api_credentials.api_key)?);
+        headers.insert("X-API-NONCE", HeaderValue::from_str(&nonce)?);
+        headers.insert("X-API-SIGNATURE", HeaderValue::from_str(&signature)?);
+        // Ensure Content-Type for POST requests with a body
+        if self.http_method == "POST" && self.body.is_some() {
+            headers.insert("Content-Type", HeaderValue::from_static("application/x-www-form-urlencoded"));
+        }
+        Ok(headers)
+    }
+}
+
+/// Constructs a complete URL path by replacing placeholders in the method's path segments with actual values.
+///
+/// This function processes each segment of the API endpoint path, replacing placeholders (segments
+/// starting with ':') with corresponding values from the provided parameters map. It then combines
+/// these processed segments with the base URL to form a complete URL path.
+///
+/// # Parameters
+///
+/// * `base_url` - The base URL the path is appended to.
+/// * `method_setting` - The method configuration containing path segments and other API endpoint details.
+/// * `path_params` - A mutable map of parameter names to values. Parameters used in the path are removed
+///   from this map during processing to track which ones have been consumed.
+///
+/// # Returns
+///
+/// * `Result<String, Error>` - On success, returns the complete URL path with all placeholders
+///   replaced by their percent-encoded values. On failure, returns `Error::MissingPathParameter`
+///   for a missing required parameter, or `Error::InvalidPathParameter` for a value that
+///   cannot be a path segment.
+fn build_url_path(
+    base_url: &str,
+    method_setting: &MethodSetting,
+    path_params: &mut HashMap<&'static str, String>, // Mutable to remove used params
+) -> Result<String, Error> {
+    let mut processed_segments: Vec<String> = Vec::new();
+    // Use the defined path_segments from the method setting
+    for segment in method_setting.path_segments {
+        if let Some((param_name, optional)) = MethodSetting::placeholder(segment) {
+            if let Some(value) = path_params.remove(param_name) {
+                processed_segments.push(encode_path_segment(param_name, &value)?);
+            } else if !optional {
+                // If a required placeholder wasn't found in params, it's an error
+                return Err(Error::MissingPathParameter(param_name));
+            }
+            // An optional placeholder without value is left out of the path
+        } else {
+            // It's a literal segment
+            processed_segments.push(segment.to_string());
+        }
+    }
+
+    // Ensure base URI doesn't end with '/' before joining
+    let base = base_url.trim_end_matches('/');
+    // Join the base and the processed segments
+    let final_path = format!("{}/{}", base, processed_segments.join("/"));
+
+    Ok(final_path)
+}
+
+/// Characters left as they are in path parameters: the unreserved characters of RFC 3986.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+/// Percent-encodes a path parameter value, so `/`, `?`, `#`, `%` and non-ASCII characters
+/// stay within the segment.
+///
+/// Empty values and the dot segments `.` and `..` are rejected, as URL normalization would
+/// drop them and change the path (also when encoded as `%2E`).
+fn encode_path_segment(param_name: &'static str, value: &str) -> Result<String, Error> {
+    if value.is_empty() || value == "." || value == ".." {
+        return Err(Error::InvalidPathParameter(param_name, value.to_string()));
+    }
+    Ok(utf8_percent_encode(value, PATH_SEGMENT).to_string())
+}
+
+/// Records the reported credits and turns a response into the deserialized body or an API error.
+///
+/// The status code and credits are also recorded on the request `span`.
+pub(crate) fn handle_response<T: DeserializeOwned>(
+    status_code: StatusCode,
+    response_text: &str,
+    credit_tracker: &CreditTracker,
+    span: &tracing::Span,
+) -> Result<T, Error> {
+    span.record("http.status_code", status_code.as_u16());
+    if let Some(credits) = credits::reported_credits(response_text) {
+        span.record("credits", credits);
+        credit_tracker.record(credits);
+    }
+
+    if !status_code.is_success() {
+        // The error body only contains the API error details, never request headers
+        tracing::debug!(parent: span, body = response_text, "API error response");
+        // Use the helper function to create the API error variant
+        Err(Error::api_error(status_code, response_text.to_string()))
+    } else {
+        // Deserialize the successful response body into type T
+        let deserialized_response = from_str(response_text)?; // Deserialize here
+        Ok(deserialized_response) // Return the deserialized struct
+    }
+}
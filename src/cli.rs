@@ -17,14 +17,14 @@ pub struct Args {
     /// Your API Key from bitcoin.de
     ///
     /// This key is used for authentication with the Bitcoin.de API.
-    /// Can be provided via command line or the API_KEY environment variable.
+    /// Can be provided via command line, the API_KEY environment variable or a file named by API_KEY_FILE.
     #[arg(long = "api-key")]
     pub api_key: Option<String>,
 
     /// Your API Secret from bitcoin.de
     ///
     /// This secret is used for signing API requests to Bitcoin.de.
-    /// Can be provided via command line or the API_SECRET environment variable.
+    /// Can be provided via command line, the API_SECRET environment variable or a file named by API_SECRET_FILE.
     #[arg(long = "api-secret")]
     pub api_secret: Option<String>,
 
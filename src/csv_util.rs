@@ -1,7 +1,7 @@
 #![cfg(feature = "cmdline")]
 use std::{fs::OpenOptions, io::{Write, BufWriter}};
 use chrono::Local;
-use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use bitcoin_de::{enums::TradingPair, TradingApiSdkV4};
 
 /// Writes cryptocurrency exchange rate data to a CSV file.
@@ -16,13 +16,13 @@ use bitcoin_de::{enums::TradingPair, TradingApiSdkV4};
 /// * `data` - A slice of tuples containing the data to write, where each tuple contains:
 ///   * timestamp: String - The timestamp when the data was collected
 ///   * trading_pair: String - The trading pair identifier (e.g., "btceur")
-///   * rate_weighted: f64 - The current weighted exchange rate
-///   * rate_weighted_3h: f64 - The 3-hour weighted exchange rate
-///   * rate_weighted_12h: f64 - The 12-hour weighted exchange rate
-///   * amount: f64 - The amount of the base currency
-///   * value_weighted: f64 - The value in quote currency using the current weighted rate
-///   * value_weighted_3h: f64 - The value in quote currency using the 3-hour weighted rate
-///   * value_weighted_12h: f64 - The value in quote currency using the 12-hour weighted rate
+///   * rate_weighted: Decimal - The current weighted exchange rate
+///   * rate_weighted_3h: Decimal - The 3-hour weighted exchange rate
+///   * rate_weighted_12h: Decimal - The 12-hour weighted exchange rate
+///   * amount: Decimal - The amount of the base currency
+///   * value_weighted: Decimal - The value in quote currency using the current weighted rate
+///   * value_weighted_3h: Decimal - The value in quote currency using the 3-hour weighted rate
+///   * value_weighted_12h: Decimal - The value in quote currency using the 12-hour weighted rate
 ///
 /// # Returns
 ///
@@ -33,13 +33,14 @@ use bitcoin_de::{enums::TradingPair, TradingApiSdkV4};
 /// ```
 /// let data = vec![
 ///     ("2023-01-01 12:00:00".to_string(), "btceur".to_string(),
-///      50000.0, 49500.0, 49000.0, 0.5, 25000.0, 24750.0, 24500.0)
+///      Decimal::new(50000, 0), Decimal::new(49500, 0), Decimal::new(49000, 0), Decimal::new(5, 1),
+///      Decimal::new(25000, 0), Decimal::new(24750, 0), Decimal::new(24500, 0))
 /// ];
 /// write_to_csv("rates.csv", &data)?;
 /// ```
 pub fn write_to_csv(
     file_path: &str,
-    data: &[(String, String, f64, f64, f64, f64, f64, f64, f64)]
+    data: &[(String, String, Decimal, Decimal, Decimal, Decimal, Decimal, Decimal, Decimal)]
 ) -> std::io::Result<()> {
     // Check if the file exists
     let file_exists = std::path::Path::new(file_path).exists();
@@ -91,8 +92,8 @@ pub fn write_to_csv(
 /// * `trading_pairs` - A vector of strings representing the trading pairs to query (e.g., "btceur", "etheur").
 /// * `csv_output` - An optional file path where the data should be written as CSV. If `None`, no CSV is created.
 /// * `amounts` - A vector of amounts in the base currency for each trading pair. If an amount is provided and
-///   greater than 0.0, calculations will be performed and API calls will be made. Otherwise, dummy values of 0
-///   will be used.
+///   greater than 0, calculations will be performed and API calls will be made. Otherwise, dummy values of 0
+///   will be used. Values are rounded to the precision of the quote currency.
 ///
 /// # Returns
 ///
@@ -103,10 +104,10 @@ pub fn write_to_csv(
 /// ```
 /// let sdk = TradingApiSdkV4::new("api_key", "api_secret");
 /// let trading_pairs = vec!["btceur", "etheur"];
-/// let amounts = vec![0.5, 1.0];
+/// let amounts = vec![Decimal::new(5, 1), Decimal::new(1, 0)];
 /// get_rates_for_csv(&sdk, trading_pairs, Some("rates.csv"), amounts).await?;
 /// ```
-pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:Vec<&str>, csv_output: Option<&str>, amounts:Vec<f64>) -> std::io::Result<()> {
+pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:Vec<&str>, csv_output: Option<&str>, amounts:Vec<Decimal>) -> std::io::Result<()> {
     // Create a vector to collect CSV data
     let mut csv_data = Vec::new();
 
@@ -114,8 +115,8 @@ pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:
     for (i, trading_pair_str) in trading_pairs.iter().enumerate() {
         let trading_pair_str = trading_pair_str.trim();
 
-        // Determine if we have a valid amount (exists and is greater than 0.0)
-        let amount_opt = if i < amounts.len() && amounts[i] > 0.0 {
+        // Determine if we have a valid amount (exists and is greater than 0)
+        let amount_opt = if i < amounts.len() && amounts[i] > Decimal::ZERO {
             Some(amounts[i])
         } else {
             None
@@ -123,8 +124,8 @@ pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:
 
         println!("\n>>> Calling showRates for {}...", trading_pair_str);
 
-        // Convert string to TradingPair enum using the from_str method
-        let trading_pair = match TradingPair::from_str(trading_pair_str) {
+        // Convert string to TradingPair enum using its FromStr implementation
+        let trading_pair = match trading_pair_str.parse::<TradingPair>() {
             Ok(pair) => pair,
             Err(e) => {
                 eprintln!("Error: {}", e);
@@ -140,11 +141,11 @@ pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:
         } else {
             // No valid amount, create a dummy result with zeros
             Ok(bitcoin_de::bitcoin_de_trading_api_sdk_v4::responses::misc::ShowRatesResponse {
-                trading_pair: trading_pair.as_str().to_string(),
+                trading_pair: trading_pair.as_str().to_ascii_lowercase(),
                 rates: bitcoin_de::bitcoin_de_trading_api_sdk_v4::responses::misc::RatesDetails {
-                    rate_weighted: rust_decimal::Decimal::new(0, 0),
-                    rate_weighted_3h: rust_decimal::Decimal::new(0, 0),
-                    rate_weighted_12h: rust_decimal::Decimal::new(0, 0),
+                    rate_weighted: Decimal::ZERO,
+                    rate_weighted_3h: Decimal::ZERO,
+                    rate_weighted_12h: Decimal::ZERO,
                 },
                 errors: Vec::new(),
                 credits: 0,
@@ -161,26 +162,27 @@ pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:
                     println!("  12h weighted rate: {}", response.rates.rate_weighted_12h);
                 }
 
-                // Get the base currency (first 3 characters of the trading pair)
-                let base_currency = trading_pair.as_str().chars().take(3).collect::<String>();
-                let quote_currency = trading_pair.as_str().chars().skip(3).take(3).collect::<String>();
+                // Get the base and quote currency of the trading pair
+                let base_currency = trading_pair.base();
+                let quote_currency = trading_pair.quote();
 
-                // Convert Decimal to f64 for calculations
-                let rate_weighted = response.rates.rate_weighted.to_f64().unwrap_or(0.0);
-                let rate_weighted_3h = response.rates.rate_weighted_3h.to_f64().unwrap_or(0.0);
-                let rate_weighted_12h = response.rates.rate_weighted_12h.to_f64().unwrap_or(0.0);
+                // Calculate with Decimal, rounding values to the precision of the quote currency (e.g. cents)
+                let rate_weighted = response.rates.rate_weighted;
+                let rate_weighted_3h = response.rates.rate_weighted_3h;
+                let rate_weighted_12h = response.rates.rate_weighted_12h;
+                let value = |amount: Decimal, rate: Decimal| trading_pair.info().round_price(amount * rate);
 
                 // Only show calculations if we have a valid amount
                 if let Some(amount) = amount_opt {
                     println!("\nCalculations for {} {}:", amount, base_currency);
                     println!("  Value (weighted rate): {} {}",
-                             amount * rate_weighted,
+                             value(amount, rate_weighted),
                              quote_currency);
                     println!("  Value (3h weighted): {} {}",
-                             amount * rate_weighted_3h,
+                             value(amount, rate_weighted_3h),
                              quote_currency);
                     println!("  Value (12h weighted): {} {}",
-                             amount * rate_weighted_12h,
+                             value(amount, rate_weighted_12h),
                              quote_currency);
                 }
 
@@ -193,12 +195,12 @@ pub async fn get_rates_for_csv(trading_api_sdk: &TradingApiSdkV4, trading_pairs:
                         if let Some(amt) = amount_opt {
                             (
                                 amt,
-                                amt * rate_weighted,
-                                amt * rate_weighted_3h,
-                                amt * rate_weighted_12h
+                                value(amt, rate_weighted),
+                                value(amt, rate_weighted_3h),
+                                value(amt, rate_weighted_12h)
                             )
                         } else {
-                            (0.0, 0.0, 0.0, 0.0)
+                            (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO, Decimal::ZERO)
                         };
 
                     // Add data to collection
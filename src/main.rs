@@ -1,10 +1,11 @@
 #![cfg(feature = "cmdline")]
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
 /// Bitcoin-de Trading API SDK v4 Client
 use bitcoin_de::bitcoin_de_trading_api_sdk_v4::TradingApiSdkV4;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::config::{env_credential, ApiCredentials, ApiSecret};
 // use clap::Parser;
-use std::env;
 use dotenv::dotenv;
 mod cli;
 mod csv_util;
@@ -48,20 +49,23 @@ async fn main() {
 
 /// Creates an API client using command-line arguments and environment variables
 fn create_api_client(args: &cli::Args) -> Result<TradingApiSdkV4, String> {
-    const API_KEY_ERROR: &str = "API_KEY must be set either via --api-key argument or API_KEY environment variable";
-    const API_SECRET_ERROR: &str = "API_SECRET must be set either via --api-secret argument or API_SECRET environment variable";
+    const API_KEY_ERROR: &str = "API_KEY must be set either via --api-key argument, API_KEY or API_KEY_FILE environment variable";
+    const API_SECRET_ERROR: &str = "API_SECRET must be set either via --api-secret argument, API_SECRET or API_SECRET_FILE environment variable";
 
-    let api_key = args.api_key
-        .clone()
-        .or_else(|| env::var("API_KEY").ok())
-        .ok_or(API_KEY_ERROR)?;
+    let api_key = match args.api_key.clone() {
+        Some(api_key) => api_key,
+        None => env_credential("API_KEY").map_err(|e| e.to_string())?.ok_or(API_KEY_ERROR)?.to_string(),
+    };
 
-    let api_secret = args.api_secret
-        .clone()
-        .or_else(|| env::var("API_SECRET").ok())
-        .ok_or(API_SECRET_ERROR)?;
+    let api_secret = match args.api_secret.clone() {
+        Some(api_secret) => ApiSecret::from(api_secret),
+        None => ApiSecret::from(env_credential("API_SECRET").map_err(|e| e.to_string())?.ok_or(API_SECRET_ERROR)?),
+    };
 
-    Ok(TradingApiSdkV4::new(api_key, api_secret))
+    TradingApiSdkV4::builder()
+        .credentials(ApiCredentials::new(api_key, api_secret))
+        .build()
+        .map_err(|e| e.to_string())
 }
 
 /// Handles the show rates CSV command
@@ -71,9 +75,9 @@ async fn handle_show_rates_csv_command(args: &cli::Args) {
     let amounts_str = args.amounts.as_ref();
     let trading_pairs: Vec<&str> = trading_pairs_str.split(',').collect();
     let csv_file = &args.csv_output;
-    let amounts: Vec<f64> = match amounts_str {
+    let amounts: Vec<Decimal> = match amounts_str {
         Some(amounts) => amounts.split(',')
-            .filter_map(|s| s.trim().parse::<f64>().ok())
+            .filter_map(|s| s.trim().parse::<Decimal>().ok())
             .collect(),
         None => vec![]
     };
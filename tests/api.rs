@@ -0,0 +1,66 @@
+// tests/api.rs
+//! Calling the client through the `BitcoinDeApi` trait object.
+#![cfg(feature = "mock")]
+
+use std::sync::Arc;
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, TradingPair};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::*;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::CreateOrderRequest;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{BitcoinDeApi, Error};
+use rust_decimal::Decimal;
+use serde_json::json;
+
+/// Strategy code that only knows the trait.
+async fn place_order_below_rate(api: &dyn BitcoinDeApi) -> Result<String, Error> {
+    let rate = api.show_rates(TradingPair::BTCEUR).await?.rates.rate_weighted;
+    let order = CreateOrderRequest::new(OrderType::Buy, Decimal::new(5, 1), rate - Decimal::ONE);
+    Ok(api.create_order("btceur".into(), &order).await?.order_id)
+}
+
+#[tokio::test]
+async fn trait_objects_delegate_to_the_client() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let api: Arc<dyn BitcoinDeApi> = Arc::new(server.client().unwrap());
+
+    let order_id = place_order_below_rate(api.as_ref()).await.unwrap();
+    // Trait objects can be shared between tasks
+    let task = tokio::spawn({
+        let (api, order_id) = (Arc::clone(&api), order_id.clone());
+        async move { api.delete_order("btceur".into(), order_id).await }
+    });
+    task.await.unwrap().unwrap();
+
+    let methods: Vec<_> = server.requests().iter().map(|request| request.method_name).collect();
+    assert_eq!(methods, [METHOD_SHOW_RATES, METHOD_CREATE_ORDER, METHOD_DELETE_ORDER]);
+    let [rates, create, delete] = &server.requests()[..] else { unreachable!() };
+    assert_eq!(rates.param("trading_pair"), Some("btceur"));
+    assert_eq!(create.param("type"), Some("buy"));
+    assert_eq!(delete.param("order_id"), Some(order_id.as_str()));
+}
+
+#[tokio::test]
+async fn provided_methods_use_the_single_requests() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let api: Box<dyn BitcoinDeApi> = Box::new(server.client().unwrap());
+    server.set_handler(METHOD_SHOW_DEPOSITS, |request| {
+        let page: i32 = request.param("page").unwrap().parse().unwrap();
+        let deposit = json!({
+            "deposit_id": page,
+            "address": "1CJfmmAkhwqKzVG8ZLDoWp5c1Ty2GA9nvr",
+            "recipient_purpose": null,
+            "amount": "0.5",
+            "state": 2,
+            "txid": "f2bb8d3a",
+            "confirmations": 6,
+            "created_at": "2018-01-17T10:36:44+01:00"
+        });
+        MockResponse::ok(json!({ "deposits": [deposit], "page": { "current": page, "last": 2 } }))
+    });
+
+    let deposits = api.show_deposits_all("btc".into(), None).await.unwrap();
+    let ids: Vec<_> = deposits.iter().map(|deposit| deposit.deposit_id).collect();
+    assert_eq!(ids, [1, 2]);
+    assert_eq!(server.requests_for(METHOD_SHOW_DEPOSITS).len(), 2);
+}
@@ -0,0 +1,52 @@
+// tests/builder.rs
+//! Configuration errors reported by the client builder before any request is sent.
+
+use std::time::Duration;
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{Error, TradingApiSdkV4};
+
+fn configuration_error<T>(result: Result<T, Error>) -> String {
+    match result {
+        Err(Error::Configuration(message)) => message,
+        Err(other) => panic!("expected a configuration error, got {:?}", other),
+        Ok(_) => panic!("expected a configuration error"),
+    }
+}
+
+#[test]
+fn credentials_are_required() {
+    let message = configuration_error(TradingApiSdkV4::builder().api_secret("secret").build());
+    assert!(message.contains("API key"), "{}", message);
+    let message = configuration_error(TradingApiSdkV4::builder().api_key("key").build());
+    assert!(message.contains("API secret"), "{}", message);
+
+    assert!(TradingApiSdkV4::builder().api_key("key").api_secret("secret").build().is_ok());
+}
+
+#[test]
+fn invalid_base_urls_are_rejected() {
+    for base_url in ["", "not a url", "api.bitcoin.de/v4"] {
+        let result = TradingApiSdkV4::builder().api_key("key").api_secret("secret").base_url(base_url).build();
+        assert!(matches!(result, Err(Error::Url(_))), "{:?}", base_url);
+    }
+}
+
+#[test]
+fn invalid_user_agents_are_rejected() {
+    let result = TradingApiSdkV4::builder().api_key("key").api_secret("secret").user_agent("bot\n").build();
+    assert!(matches!(result, Err(Error::InvalidHeaderValue(_))));
+}
+
+#[test]
+fn custom_clients_cannot_be_combined_with_http_settings() {
+    let builder = || TradingApiSdkV4::builder().api_key("key").api_secret("secret").client(reqwest::Client::new());
+
+    configuration_error(builder().timeout(Duration::from_secs(5)).build());
+    configuration_error(builder().connect_timeout(Duration::from_secs(5)).build());
+    configuration_error(builder().user_agent("my-bot/1.0").build());
+    configuration_error(builder().proxy(reqwest::Proxy::all("http://127.0.0.1:3128").unwrap()).build());
+    assert!(builder().build().is_ok());
+
+    #[cfg(feature = "blocking")]
+    configuration_error(builder().build_blocking());
+}
@@ -0,0 +1,101 @@
+// tests/cassette.rs
+//! Recording responses of the mock server to a cassette and replaying them without it.
+#![cfg(feature = "mock")]
+
+use std::path::{Path, PathBuf};
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::cassette::read_cassette;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::MockServer;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::responses::misc::ShowRatesResponse;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{Error, TradingApiSdkV4};
+use rust_decimal::Decimal;
+use tempfile::TempDir;
+
+/// A path to a cassette that does not exist yet, inside a directory removed on drop.
+fn cassette_path() -> (TempDir, PathBuf) {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("cassette.jsonl");
+    (dir, path)
+}
+
+const ADDRESS: &str = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+
+async fn record(path: &Path) {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = TradingApiSdkV4::builder()
+        .api_key("key")
+        .api_secret("secret")
+        .base_url(server.base_url())
+        .record_cassette(path)
+        .build()
+        .unwrap();
+
+    client.show_rates(TradingPair::BTCEUR).await.unwrap();
+    client.create_withdrawal("btc".into(), Decimal::new(1, 1), ADDRESS.into(), Decimal::new(2, 4)).await.unwrap();
+    client.show_my_trades_all(None, None).await.unwrap();
+    client.show_account_info().await.unwrap();
+} // The server stops here
+
+#[tokio::test]
+async fn recorded_cassette_leaves_out_addresses_and_bank_details() {
+    let (_dir, path) = cassette_path();
+    record(&path).await;
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    for secret in [ADDRESS, "DE12345678901234567890", "Max Mustermann", "HASPDEHHXXX", "d8e7f6a5"] {
+        assert!(!content.contains(secret), "{} was recorded", secret);
+    }
+
+    let entries = read_cassette(&path).unwrap();
+    let methods: Vec<&str> = entries.iter().map(|entry| entry.method_name.as_str()).collect();
+    assert_eq!(methods, ["showRates", "createWithdrawal", "showMyTrades", "showAccountInfo"]);
+    assert_eq!(entries[1].params["address"], "[REDACTED]");
+    let rates: ShowRatesResponse = entries[0].parse().unwrap();
+    assert_eq!(rates.trading_pair().unwrap(), TradingPair::BTCEUR);
+}
+
+#[tokio::test]
+async fn replay_answers_without_a_server_until_the_entries_run_out() {
+    let (_dir, path) = cassette_path();
+    record(&path).await;
+
+    let client = TradingApiSdkV4::builder()
+        .api_key("key")
+        .api_secret("secret")
+        .base_url("http://127.0.0.1:1/v4")
+        .replay_cassette(&path)
+        .build()
+        .unwrap();
+    assert_eq!(client.show_rates(TradingPair::BTCEUR).await.unwrap().trading_pair().unwrap(), TradingPair::BTCEUR);
+    assert!(client.credits().is_some());
+    // Matched by the redacted parameters, so any address replays the recorded withdrawal
+    client.create_withdrawal("btc".into(), Decimal::new(1, 1), "another address".into(), Decimal::new(2, 4)).await.unwrap();
+    let trades = client.show_my_trades_all(None, None).await.unwrap();
+    assert_eq!(trades[0].trading_partner_information.bank_name, "[REDACTED]");
+    assert_eq!(client.show_account_info().await.unwrap().data.encrypted_information.uid, "[REDACTED]");
+
+    // Every entry is served once
+    let error = client.show_rates(TradingPair::BTCEUR).await.unwrap_err();
+    assert!(matches!(error, Error::Cassette(_)), "{}", error);
+    assert!(matches!(client.show_permissions().await, Err(Error::Cassette(_))));
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_client_replays_a_cassette() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::blocking;
+
+    let (_dir, path) = cassette_path();
+    record(&path).await;
+
+    // The blocking client must not be created or used on an async worker thread
+    let replay = path.clone();
+    tokio::task::spawn_blocking(move || {
+        let client = blocking::TradingApiSdkV4::builder().api_key("key").api_secret("secret").replay_cassette(&replay).build_blocking().unwrap();
+        assert_eq!(client.show_rates(TradingPair::BTCEUR).unwrap().trading_pair().unwrap(), TradingPair::BTCEUR);
+        assert!(matches!(client.show_rates(TradingPair::BTCEUR), Err(Error::Cassette(_))));
+    })
+    .await
+    .unwrap();
+}
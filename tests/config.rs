@@ -0,0 +1,81 @@
+// tests/config.rs
+//! Loading credentials from the environment and from files, and keeping them out of logs.
+
+use std::io::Write;
+use std::path::Path;
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::config::{env_credential, ApiCredentials};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use tempfile::NamedTempFile;
+
+/// Tests run in parallel in one process, so each test uses its own variable names.
+fn var_name(test: &str) -> String {
+    format!("BITCOIN_DE_TEST_{}_{}", std::process::id(), test)
+}
+
+/// Writes `content` to a temporary file with the given Unix permissions.
+fn credential_file(content: &str, mode: u32) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(file.path(), std::fs::Permissions::from_mode(mode)).unwrap();
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+    file
+}
+
+fn set_file_var(var: &str, path: &Path) {
+    std::env::set_var(format!("{}_FILE", var), path);
+}
+
+#[cfg(unix)]
+#[test]
+fn files_readable_by_others_are_rejected() {
+    let file = credential_file("API_KEY=key\nAPI_SECRET=secret\n", 0o644);
+    assert!(matches!(ApiCredentials::from_file(file.path()), Err(Error::Configuration(_))));
+
+    let var = var_name("READABLE");
+    set_file_var(&var, file.path());
+    assert!(matches!(env_credential(&var), Err(Error::Configuration(_))));
+}
+
+#[test]
+fn file_variables_are_read_and_trimmed() {
+    let file = credential_file("  my_api_secret\n\n", 0o600);
+    let var = var_name("TRIMMED");
+    set_file_var(&var, file.path());
+    assert_eq!(env_credential(&var).unwrap().as_deref().map(String::as_str), Some("my_api_secret"));
+
+    assert!(env_credential(&var_name("UNSET")).unwrap().is_none());
+}
+
+#[test]
+fn variable_and_file_variable_are_exclusive() {
+    let file = credential_file("my_api_secret", 0o600);
+    let var = var_name("BOTH");
+    std::env::set_var(&var, "my_api_secret");
+    set_file_var(&var, file.path());
+    assert!(matches!(env_credential(&var), Err(Error::Configuration(_))));
+}
+
+#[test]
+fn credential_files_are_parsed() {
+    let file = credential_file("# comment\nAPI_KEY=\"my_api_key\"\nAPI_SECRET='my_api_secret'\n", 0o600);
+    let credentials = ApiCredentials::from_file(file.path()).unwrap();
+    assert_eq!(credentials.api_key, "my_api_key");
+    assert_eq!(credentials.api_secret.expose_secret(), "my_api_secret");
+
+    let file = credential_file("API_KEY=my_api_key\n", 0o600);
+    assert!(matches!(ApiCredentials::from_file(file.path()), Err(Error::Configuration(_))));
+}
+
+#[test]
+fn debug_output_contains_no_credentials() {
+    let credentials = ApiCredentials::new("my_api_key", "my_api_secret");
+    let debug = format!("{:?}", credentials);
+    assert!(!debug.contains("my_api_key"), "{}", debug);
+    assert!(!debug.contains("my_api_secret"), "{}", debug);
+}
@@ -0,0 +1,78 @@
+// tests/credits.rs
+//! Credit costs and the credit floor against the mock server.
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::{method_credits, METHOD_SETTINGS};
+
+#[test]
+fn every_method_has_a_credit_cost() {
+    for method_name in METHOD_SETTINGS.keys() {
+        assert!(method_credits(method_name).is_some_and(|credits| credits > 0), "{} has no cost", method_name);
+    }
+}
+
+#[cfg(feature = "mock")]
+mod mock {
+    use std::time::{Duration, Instant};
+
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::credits::CreditFloor;
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{Error, TradingApiSdkV4};
+
+    fn client_with_floor(server: &MockServer, floor: CreditFloor) -> TradingApiSdkV4 {
+        TradingApiSdkV4::builder()
+            .api_key("key")
+            .api_secret("secret")
+            .base_url(server.base_url())
+            .credit_floor(floor)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn reported_credits_are_kept_for_success_and_error_responses() {
+        let server = MockServer::start("key", "secret").await.unwrap();
+        let client = server.client().unwrap();
+        assert_eq!(client.credits(), None);
+
+        server.set_credits(17);
+        client.show_permissions().await.unwrap();
+        assert_eq!(client.credits(), Some(17));
+
+        server.set_credits(9);
+        server.enqueue("showRates", MockResponse::error(429, 22, "Insufficient credits"));
+        assert!(client.show_rates(TradingPair::BTCEUR).await.is_err());
+        assert_eq!(client.credits(), Some(9));
+    }
+
+    #[tokio::test]
+    async fn failing_floor_holds_back_requests_without_sending_them() {
+        let server = MockServer::start("key", "secret").await.unwrap();
+        let client = client_with_floor(&server, CreditFloor::fail(3).regeneration_per_second(0.0));
+
+        server.set_credits(5);
+        client.show_permissions().await.unwrap(); // No balance known before, sent
+        let error = client.show_rates(TradingPair::BTCEUR).await.unwrap_err(); // 3 credits
+        assert!(matches!(error, Error::CreditFloor { available: 5, cost: 3, floor: 3 }), "{}", error);
+        assert!(server.requests_for("showRates").is_empty());
+    }
+
+    #[tokio::test]
+    async fn waiting_floor_delays_requests_until_credits_regenerated() {
+        let server = MockServer::start("key", "secret").await.unwrap();
+        let client = client_with_floor(&server, CreditFloor::wait(3).regeneration_per_second(10.0));
+
+        server.set_credits(20);
+        client.show_permissions().await.unwrap(); // Regeneration is capped at the highest balance seen
+        server.set_credits(4);
+        client.show_permissions().await.unwrap();
+
+        let started = Instant::now();
+        client.show_rates(TradingPair::BTCEUR).await.unwrap(); // 6 required, 2 missing: 0.2 s
+        assert!(started.elapsed() >= Duration::from_millis(150), "{:?}", started.elapsed());
+
+        let impatient = client_with_floor(&server, CreditFloor::wait(3).regeneration_per_second(0.1).max_wait(Duration::from_millis(100)));
+        impatient.show_permissions().await.unwrap();
+        assert!(matches!(impatient.show_rates(TradingPair::BTCEUR).await, Err(Error::CreditFloor { .. })));
+    }
+}
@@ -0,0 +1,164 @@
+// tests/enums.rs
+//! (De)serialization of the enums generated by `generate_enum!` and `generate_int_enum!`.
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::*;
+
+#[test]
+fn int_enums_map_codes_and_unknown_codes() {
+    assert_eq!(OrderState::from_code(-2), OrderState::Expired);
+    assert_eq!(TradeState::from_code(1), TradeState::Successful);
+    assert_eq!(WithdrawalState::from(2), WithdrawalState::Cancelled);
+    assert_eq!(DepositState::from_code(1), DepositState::Pending);
+    assert_eq!(PaymentMethod::from_code(2), PaymentMethod::Express);
+
+    assert_eq!(OrderState::from_code(7), OrderState::Unknown(7));
+    assert_eq!(OrderState::Unknown(7).code(), 7);
+    assert_eq!(OrderState::Cancelled.to_string(), "-1");
+}
+
+#[test]
+fn int_enums_deserialize_unknown_codes() {
+    assert_eq!(serde_json::from_str::<TradeState>("5").unwrap(), TradeState::Unknown(5));
+    assert_eq!(serde_json::from_str::<PaymentMethod>("-9").unwrap(), PaymentMethod::Unknown(-9));
+    assert!(serde_json::from_str::<OrderState>("\"0\"").is_err());
+}
+
+#[test]
+fn int_enums_serialize_round_trip() {
+    let states = [
+        OrderState::Expired,
+        OrderState::Cancelled,
+        OrderState::Pending,
+        OrderState::Executed,
+        OrderState::Unknown(42),
+    ];
+    for state in states {
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(json, state.code().to_string());
+        assert_eq!(serde_json::from_str::<OrderState>(&json).unwrap(), state);
+    }
+    assert_eq!(serde_json::to_value(PaymentMethod::Sepa).unwrap(), serde_json::json!(1));
+    assert_eq!(serde_json::to_value(DepositState::Unknown(3)).unwrap(), serde_json::json!(3));
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn int_enums_deserialize_from_fixtures() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::METHOD_SHOW_MY_TRADES;
+    use bitcoin_de::requests::MyOrdersFilter;
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    let trades = client.show_my_trades_all(None, None).await.unwrap();
+    assert_eq!(trades[0].state, TradeState::Successful);
+    assert_eq!(trades[0].payment_method, PaymentMethod::Sepa);
+    let orders = client.show_my_orders_all(None, &MyOrdersFilter::new()).await.unwrap();
+    assert_eq!(orders[0].state, OrderState::Pending);
+    let deposits = client.show_deposits_all("btc".into(), None).await.unwrap();
+    assert_eq!(deposits[0].state, DepositState::Credited);
+    let withdrawals = client.show_withdrawals_all("btc".into(), None).await.unwrap();
+    assert_eq!(withdrawals[0].state, WithdrawalState::Pending);
+
+    // Codes the SDK does not know yet must not fail the whole response.
+    let mut trade = serde_json::to_value(&trades[0]).unwrap();
+    trade["state"] = serde_json::json!(4);
+    trade["payment_method"] = serde_json::json!(9);
+    server.set_response(
+        METHOD_SHOW_MY_TRADES,
+        MockResponse::ok(serde_json::json!({ "trades": [trade], "page": { "current": 1, "last": 1 } })),
+    );
+    let trades = client.show_my_trades(None, None).await.unwrap().trades;
+    assert_eq!(trades[0].state, TradeState::Unknown(4));
+    assert_eq!(trades[0].payment_method, PaymentMethod::Unknown(9));
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn unknown_trading_pairs_and_order_types_do_not_fail_the_response() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::METHOD_SHOW_MY_TRADES;
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    let trades = client.show_my_trades(None, None).await.unwrap().trades;
+    assert_eq!(trades[0].trading_pair, "btceur");
+    assert_eq!((trades[0].trading_pair().unwrap(), trades[0].trade_type().unwrap()), (TradingPair::BTCEUR, OrderType::Sell));
+
+    let mut trade = serde_json::to_value(&trades[0]).unwrap();
+    assert_eq!(trade["trading_pair"], "btceur"); // Serialized as received
+    trade["trading_pair"] = serde_json::json!("newcoineur");
+    trade["type"] = serde_json::json!("swap");
+    server.set_response(
+        METHOD_SHOW_MY_TRADES,
+        MockResponse::ok(serde_json::json!({ "trades": [trade], "page": { "current": 1, "last": 1 } })),
+    );
+    let trades = client.show_my_trades_all(None, None).await.unwrap();
+    assert_eq!((trades[0].trading_pair.as_str(), trades[0].trade_type.as_str()), ("newcoineur", "swap"));
+    assert!(trades[0].trading_pair().is_err());
+    assert!(trades[0].trade_type().is_err());
+
+    let rates = client.show_rates(TradingPair::ETHEUR).await.unwrap();
+    assert_eq!(serde_json::to_value(&rates).unwrap()["trading_pair"], "etheur");
+}
+
+#[test]
+fn string_enums_parse_case_insensitively() {
+    assert_eq!("btceur".parse::<TradingPair>().unwrap(), TradingPair::BTCEUR);
+    assert_eq!("BtcEur".parse::<TradingPair>().unwrap(), TradingPair::BTCEUR);
+    assert_eq!(Currency::try_from("usdt").unwrap(), Currency::USDT);
+    assert_eq!(OrderType::try_from("SELL").unwrap(), OrderType::Sell);
+    assert_eq!("Platinum".parse::<TrustLevel>().unwrap(), TrustLevel::Platinum);
+
+    assert!("btcusd".parse::<TradingPair>().is_err());
+    assert!(Currency::try_from("").is_err());
+    assert!(OrderType::try_from("buy ").is_err());
+}
+
+#[test]
+#[allow(deprecated)]
+fn trading_pairs_parse_without_importing_from_str() {
+    assert_eq!(TradingPair::from_str("ETHbtc").unwrap(), TradingPair::ETHBTC);
+    assert!(TradingPair::from_str("btcusd").is_err());
+}
+
+#[test]
+fn string_enums_serialize_in_lower_case() {
+    assert_eq!(serde_json::to_string(&TradingPair::ETHEUR).unwrap(), "\"etheur\"");
+    assert_eq!(serde_json::to_string(&Currency::USDT).unwrap(), "\"usdt\"");
+    assert_eq!(serde_json::to_string(&OrderType::Buy).unwrap(), "\"buy\"");
+    assert_eq!(serde_json::to_string(&PaymentOption::ExpressAndSepa).unwrap(), "\"3\"");
+    assert_eq!(serde_json::from_str::<Currency>("\"eth\"").unwrap(), Currency::ETH);
+    assert!(serde_json::from_str::<Currency>("\"xyz\"").is_err());
+}
+
+#[test]
+fn integer_options_deserialize_from_strings_and_integers() {
+    assert_eq!(serde_json::from_str::<PaymentOption>("1").unwrap(), PaymentOption::ExpressOnly);
+    assert_eq!(serde_json::from_str::<PaymentOption>("\"2\"").unwrap(), PaymentOption::SepaOnly);
+    assert_eq!(serde_json::from_str::<SepaOption>("0").unwrap(), SepaOption::Standard);
+    assert_eq!(serde_json::from_str::<SepaOption>("\"1\"").unwrap(), SepaOption::Instant);
+
+    assert!(serde_json::from_str::<PaymentOption>("4").is_err());
+    assert!(serde_json::from_str::<SepaOption>("-1").is_err());
+    assert!(serde_json::from_str::<SepaOption>("true").is_err());
+}
+
+#[test]
+fn string_enums_list_all_variants_in_order() {
+    assert_eq!(OrderType::ALL, &[OrderType::Buy, OrderType::Sell]);
+    assert_eq!(SepaOption::iter().collect::<Vec<_>>(), vec![SepaOption::Standard, SepaOption::Instant]);
+    assert_eq!(TradingPair::iter().count(), TradingPair::ALL.len());
+    assert_eq!(TradingPair::ALL.first(), Some(&TradingPair::BTCEUR));
+    assert_eq!(Currency::ALL.last(), Some(&Currency::USD));
+
+    // Every variant round-trips through its string representation.
+    for pair in TradingPair::iter() {
+        assert_eq!(pair.as_str().parse::<TradingPair>().unwrap(), pair);
+    }
+    for currency in Currency::iter() {
+        assert_eq!(Currency::try_from(currency.as_str()).unwrap(), currency);
+    }
+}
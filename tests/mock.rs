@@ -0,0 +1,275 @@
+// tests/mock.rs
+//! Routing, authentication and request recording of the mock server.
+#![cfg(feature = "mock")]
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::constants::*;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, TradingPair};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::error_codes::ApiErrorCode;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::nonce::AtomicNonceProvider;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::{CreateOrderRequest, MyOrdersFilter};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::retry::RetryPolicy;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::signature::sign_request;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{Error, TradingApiSdkV4};
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use std::io::{Read, Write};
+use std::net::TcpListener;
+
+/// The error code of the first error in an API error response.
+fn api_code(error: &Error) -> i32 {
+    error.api_errors().first().unwrap_or_else(|| panic!("expected an API error, got {:?}", error)).code
+}
+
+/// Sends a GET request with the given headers and returns the status and the first error code.
+async fn send_raw(url: &str, headers: &[(&str, String)]) -> (u16, Option<i32>) {
+    let mut request = reqwest::Client::new().get(url);
+    for (name, value) in headers {
+        request = request.header(*name, value);
+    }
+    let response = request.send().await.unwrap();
+    let status = response.status().as_u16();
+    let body: Value = response.json().await.unwrap();
+    (status, body["errors"][0]["code"].as_i64().map(|code| code as i32))
+}
+
+/// Signed headers for `GET url` with the given nonce and signing secret.
+fn signed_headers(url: &str, secret: &str, nonce: u64) -> Vec<(&'static str, String)> {
+    let nonce = nonce.to_string();
+    let signature = sign_request("GET", url, "key", secret, &nonce, &[]).unwrap();
+    vec![(HEADER_X_API_KEY, "key".to_string()), (HEADER_X_NONCE, nonce), (HEADER_X_API_SIGNATURE, signature)]
+}
+
+#[tokio::test]
+async fn signed_requests_are_routed_and_recorded() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    // GET with path and query parameters
+    client.show_my_orders(Some("btceur".into()), &MyOrdersFilter::new().order_type(OrderType::Buy)).await.unwrap();
+    // POST with path and body parameters
+    let order = CreateOrderRequest::new(OrderType::Sell, Decimal::new(5, 1), Decimal::new(2500050, 2));
+    client.create_order("btceur".into(), &order).await.unwrap();
+    // DELETE with path parameters only
+    client.delete_order("btceur".into(), "A1B2C3".into()).await.unwrap();
+
+    let requests = server.requests();
+    let methods: Vec<_> = requests.iter().map(|request| (request.method_name, request.http_method.as_str())).collect();
+    assert_eq!(methods, [("showMyOrders", "GET"), ("createOrder", "POST"), ("deleteOrder", "DELETE")]);
+    assert!(requests.windows(2).all(|pair| pair[0].nonce < pair[1].nonce));
+
+    let [show, create, delete] = &requests[..] else { unreachable!() };
+    assert_eq!(show.path, "/v4/btceur/orders");
+    assert_eq!(show.path_params["trading_pair"], "btceur");
+    assert_eq!(show.query, [("type".to_string(), "buy".to_string())]);
+    assert!(show.form.is_empty());
+
+    assert!(create.query.is_empty());
+    assert_eq!(create.param("trading_pair"), Some("btceur"));
+    assert_eq!(create.param("type"), Some("sell"));
+    assert_eq!(create.param("max_amount_currency_to_trade"), Some("0.5"));
+    assert_eq!(create.param("price"), Some("25000.5"));
+    assert_eq!(create.form.len(), 3);
+
+    assert_eq!(delete.path, "/v4/btceur/orders/A1B2C3");
+    assert_eq!(delete.param("order_id"), Some("A1B2C3"));
+    assert!(delete.query.is_empty() && delete.form.is_empty());
+    assert_eq!(delete.param("missing"), None);
+}
+
+#[tokio::test]
+async fn literal_segments_win_over_placeholders() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    // GET :currency/withdrawals/min_network_fee and :currency/withdrawals/:withdrawal_id both match
+    client.show_withdrawal_min_network_fee("btc".into()).await.unwrap();
+    client.show_withdrawal("btc".into(), "4711".into()).await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].method_name, "showWithdrawalMinNetworkFee");
+    assert!(!requests[0].path_params.contains_key("withdrawal_id"));
+    assert_eq!(requests[1].method_name, "showWithdrawal");
+    assert_eq!(requests[1].param("withdrawal_id"), Some("4711"));
+}
+
+#[tokio::test]
+async fn optional_placeholders_may_be_left_out() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    // GET [:trading_pair/]orders
+    client.show_my_orders(None, &MyOrdersFilter::new()).await.unwrap();
+    client.show_my_orders(Some("btceur".into()), &MyOrdersFilter::new()).await.unwrap();
+
+    let requests = server.requests_for("showMyOrders");
+    assert_eq!(requests[0].path, "/v4/orders");
+    assert_eq!(requests[0].param("trading_pair"), None);
+    assert_eq!(requests[1].path, "/v4/btceur/orders");
+    assert_eq!(requests[1].param("trading_pair"), Some("btceur"));
+}
+
+#[tokio::test]
+async fn clients_with_wrong_credentials_are_rejected() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let builder = || TradingApiSdkV4::builder().base_url(server.base_url());
+
+    let wrong_key = builder().api_key("other key").api_secret("secret").build().unwrap();
+    let error = wrong_key.show_permissions().await.unwrap_err();
+    assert_eq!(api_code(&error), ERROR_CODE_INACTIVE_API_KEY);
+    assert!(error.is_auth_error());
+
+    let wrong_secret = builder().api_key("key").api_secret("other secret").build().unwrap();
+    let error = wrong_secret.show_rates(TradingPair::BTCEUR).await.unwrap_err();
+    assert_eq!(api_code(&error), ERROR_CODE_WRONG_SIGNATURE);
+
+    // Signed POST bodies are verified too
+    let order = CreateOrderRequest::new(OrderType::Buy, Decimal::new(5, 1), Decimal::new(2500000, 2));
+    let error = wrong_secret.create_order("btceur".into(), &order).await.unwrap_err();
+    assert_eq!(api_code(&error), ERROR_CODE_WRONG_SIGNATURE);
+
+    assert!(server.requests().is_empty(), "rejected requests are not recorded");
+}
+
+#[tokio::test]
+async fn unauthenticated_requests_are_rejected() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let url = format!("{}/permissions", server.base_url());
+    let nonce = 1_700_000_000_000_000;
+
+    let (status, code) = send_raw(&url, &[]).await;
+    assert_eq!((status, code), (401, Some(ERROR_CODE_MISSING_HEADER)));
+
+    let mut headers = signed_headers(&url, "secret", nonce);
+    headers.pop(); // Without X-API-SIGNATURE
+    assert_eq!(send_raw(&url, &headers).await.1, Some(ERROR_CODE_MISSING_HEADER));
+
+    let mut headers = signed_headers(&url, "secret", nonce);
+    headers[2].1 = "not hex".to_string();
+    assert_eq!(send_raw(&url, &headers).await.1, Some(ERROR_CODE_WRONG_SIGNATURE));
+
+    // The signature covers the URL, including the query string
+    let headers = signed_headers(&url, "secret", nonce);
+    assert_eq!(send_raw(&format!("{}?page=2", url), &headers).await.1, Some(ERROR_CODE_WRONG_SIGNATURE));
+
+    let (status, code) = send_raw(&format!("{}/unknown", server.base_url()), &signed_headers(&url, "secret", nonce)).await;
+    assert_eq!((status, code), (404, Some(ERROR_CODE_UNKNOWN_API_METHOD)));
+
+    assert!(server.requests().is_empty());
+    assert_eq!(send_raw(&url, &signed_headers(&url, "secret", nonce)).await.0, 200);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn nonces_must_increase() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let url = format!("{}/permissions", server.base_url());
+    let nonce = 1_700_000_000_000_000;
+
+    assert_eq!(send_raw(&url, &signed_headers(&url, "secret", nonce)).await.0, 200);
+    // Replayed and earlier nonces are rejected even with a valid signature
+    assert_eq!(send_raw(&url, &signed_headers(&url, "secret", nonce)).await.1, Some(ERROR_CODE_INVALID_NONCE));
+    assert_eq!(send_raw(&url, &signed_headers(&url, "secret", nonce - 1)).await.1, Some(ERROR_CODE_INVALID_NONCE));
+    assert_eq!(send_raw(&url, &signed_headers(&url, "secret", nonce + 1)).await.0, 200);
+
+    // A client whose nonces lag behind the last one the server accepted
+    let ahead = TradingApiSdkV4::builder()
+        .api_key("key")
+        .api_secret("secret")
+        .base_url(server.base_url())
+        .nonce_provider(AtomicNonceProvider::starting_after(u64::MAX / 2))
+        .build()
+        .unwrap();
+    ahead.show_permissions().await.unwrap();
+    let behind = server.client().unwrap();
+    let error = behind.show_permissions().await.unwrap_err();
+    assert_eq!(api_code(&error), ERROR_CODE_INVALID_NONCE);
+    assert!(error.is_nonce_error());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn path_parameters_are_encoded_the_same_in_the_sent_and_the_signed_url() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    // DELETE :currency/address_pool/:address, the address is a path segment
+    let address = "bc1q/../x?y=1#frag%20 Grüße";
+    client.remove_from_address_pool("btc".into(), address.into()).await.unwrap(); // The mock verified the signature
+
+    let request = server.requests_for("removeFromAddressPool").pop().unwrap();
+    assert_eq!(request.path_params["address"], address);
+    assert_eq!(request.path_params["currency"], "btc");
+    assert!(request.query.is_empty(), "'?' must not start a query string");
+    assert!(request.path.starts_with("/v4/btc/address_pool/bc1q%2F..%2Fx%3Fy%3D1%23frag%2520%20Gr%C3%BC%C3%9Fe"), "{}", request.path);
+}
+
+#[tokio::test]
+async fn empty_and_dot_path_parameters_are_rejected() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    for address in ["", ".", ".."] {
+        match client.remove_from_address_pool("btc".into(), address.into()).await {
+            Err(Error::InvalidPathParameter(name, value)) => assert_eq!((name, value.as_str()), ("address", address)),
+            other => panic!("expected an invalid path parameter for {:?}, got {:?}", address, other),
+        }
+    }
+    match client.show_withdrawal("..".into(), "4711".into()).await {
+        Err(Error::InvalidPathParameter(name, _)) => assert_eq!(name, "currency"),
+        other => panic!("expected an invalid path parameter, got {:?}", other),
+    }
+    assert!(server.requests().is_empty());
+}
+
+#[tokio::test]
+async fn error_bodies_with_string_codes_are_parsed() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    server.set_response(
+        METHOD_SHOW_ACCOUNT_INFO,
+        MockResponse::new(422, json!({"errors": [{"message": "x", "code": "33", "field": "amount"}], "credits": 7})),
+    );
+
+    let error = server.client().unwrap().show_account_info().await.unwrap_err();
+    assert_eq!(error.api_codes(), [ApiErrorCode::from_code(33)]);
+    assert_eq!(error.credits(), Some(7));
+    assert_eq!(error.api_errors()[0].field.as_deref(), Some("amount"));
+}
+
+#[tokio::test]
+async fn error_bodies_that_are_not_json_are_kept_raw() {
+    // A proxy in front of the API answers with HTML instead of the documented JSON
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/v4", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let _ = stream.read(&mut [0; 4096]);
+            let body = "<html>Bad Gateway</html>";
+            let response = format!(
+                "HTTP/1.1 502 Bad Gateway\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+
+    let client = TradingApiSdkV4::builder()
+        .api_key("key")
+        .api_secret("secret")
+        .base_url(base_url)
+        .retry_policy(RetryPolicy::new().max_attempts(1))
+        .build()
+        .unwrap();
+    let error = client.show_account_info().await.unwrap_err();
+    match &error {
+        Error::Api { status, body, api_error_details } => {
+            assert_eq!(status.as_u16(), 502);
+            assert_eq!(body, "<html>Bad Gateway</html>");
+            assert!(api_error_details.is_none());
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+    assert!(error.api_codes().is_empty());
+    assert_eq!(error.credits(), None);
+}
@@ -0,0 +1,85 @@
+// tests/nonce.rs
+//! Uniqueness and persistence of the nonce providers.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::nonce::{AtomicNonceProvider, FileNonceProvider, NonceProvider};
+use tempfile::TempDir;
+
+const THREADS: usize = 8;
+const NONCES_PER_THREAD: usize = 5_000;
+
+/// Calls `next_nonce` concurrently from several threads and checks that every thread sees
+/// strictly increasing nonces and no nonce is handed out twice.
+fn assert_unique_and_increasing<P: NonceProvider + 'static>(provider: P) {
+    let provider = Arc::new(provider);
+    let threads: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let provider = Arc::clone(&provider);
+            std::thread::spawn(move || (0..NONCES_PER_THREAD).map(|_| provider.next_nonce().unwrap()).collect::<Vec<u64>>())
+        })
+        .collect();
+
+    let mut all = Vec::with_capacity(THREADS * NONCES_PER_THREAD);
+    for thread in threads {
+        let nonces = thread.join().unwrap();
+        assert!(nonces.windows(2).all(|pair| pair[0] < pair[1]), "nonces of a thread are not strictly increasing");
+        all.extend(nonces);
+    }
+    all.sort_unstable();
+    all.dedup();
+    assert_eq!(all.len(), THREADS * NONCES_PER_THREAD, "a nonce was handed out twice");
+}
+
+/// A path to a nonce file that does not exist yet, inside a directory removed on drop.
+fn nonce_path() -> (TempDir, PathBuf) {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("nonce");
+    (dir, path)
+}
+
+fn persisted(path: &Path) -> u64 {
+    std::fs::read_to_string(path).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn atomic_provider_is_unique_under_concurrency() {
+    assert_unique_and_increasing(AtomicNonceProvider::new());
+    // Far ahead of the clock, every nonce comes from the `last + 1` path
+    assert_unique_and_increasing(AtomicNonceProvider::starting_after(u64::MAX / 2));
+}
+
+#[test]
+fn file_provider_is_unique_under_concurrency() {
+    let (_dir, path) = nonce_path();
+    assert_unique_and_increasing(FileNonceProvider::with_reservation(&path, 1_000).unwrap());
+}
+
+#[test]
+fn file_provider_continues_above_the_reserved_block_after_a_restart() {
+    let (_dir, path) = nonce_path();
+    // A mark far ahead of the clock, as left behind before the clock was set back
+    let mark = 50_000_000_000_000_000;
+    std::fs::write(&path, mark.to_string()).unwrap();
+
+    let provider = FileNonceProvider::with_reservation(&path, 1_000).unwrap();
+    let issued: Vec<u64> = (0..10).map(|_| provider.next_nonce().unwrap()).collect();
+    assert_eq!(issued[0], mark + 1);
+    let reserved = persisted(&path);
+    assert_eq!(reserved, mark + 1 + 1_000); // One write for the whole block
+    assert!(issued.iter().all(|nonce| *nonce <= reserved));
+    drop(provider); // The process stops halfway through the reserved block
+
+    let restarted = FileNonceProvider::with_reservation(&path, 1_000).unwrap();
+    let next = restarted.next_nonce().unwrap();
+    assert_eq!(next, reserved + 1, "a nonce from the reserved block could be reissued");
+    assert!(persisted(&path) > next);
+}
+
+#[test]
+fn file_provider_rejects_a_corrupt_file() {
+    let (_dir, path) = nonce_path();
+    std::fs::write(&path, "not a nonce").unwrap();
+    assert!(FileNonceProvider::open(&path).is_err());
+}
@@ -0,0 +1,109 @@
+// tests/pagination.rs
+//! Walking through paged list methods with the async stream and the blocking iterator.
+#![cfg(feature = "mock")]
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::constants::ERROR_CODE_UNKNOWN_ERROR;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::METHOD_SHOW_DEPOSITS;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockRequest, MockResponse, MockServer};
+use futures_util::{pin_mut, StreamExt};
+use serde_json::json;
+
+const LAST_PAGE: i32 = 3;
+
+fn requested_page(request: &MockRequest) -> i32 {
+    request.param("page").expect("page parameter").parse().unwrap()
+}
+
+/// Answers `showDeposits` with two deposits per page, numbered `page * 10 + n`, and
+/// `last: 3`. With `failing_page`, that page fails with a server error instead.
+fn serve_pages(server: &MockServer, failing_page: Option<i32>) {
+    server.set_handler(METHOD_SHOW_DEPOSITS, move |request| {
+        let page = requested_page(request);
+        if Some(page) == failing_page {
+            return MockResponse::error(500, ERROR_CODE_UNKNOWN_ERROR, "Unknown error");
+        }
+        let deposits: Vec<_> = (1..=2)
+            .map(|n| {
+                json!({
+                    "deposit_id": page * 10 + n,
+                    "address": "1CJfmmAkhwqKzVG8ZLDoWp5c1Ty2GA9nvr",
+                    "recipient_purpose": null,
+                    "amount": "0.5",
+                    "state": 2,
+                    "txid": "f2bb8d3a",
+                    "confirmations": 6,
+                    "created_at": "2018-01-17T10:36:44+01:00"
+                })
+            })
+            .collect();
+        MockResponse::ok(json!({ "deposits": deposits, "page": { "current": page, "last": LAST_PAGE } }))
+    });
+}
+
+fn requested_pages(server: &MockServer) -> Vec<i32> {
+    server.requests_for(METHOD_SHOW_DEPOSITS).iter().map(requested_page).collect()
+}
+
+#[tokio::test]
+async fn stream_fetches_every_page_once_and_lazily() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+    serve_pages(&server, None);
+
+    let stream = client.show_deposits_stream("btc".into(), None);
+    pin_mut!(stream);
+    assert_eq!(stream.next().await.unwrap().unwrap().deposit_id, 11);
+    assert_eq!(requested_pages(&server), [1]); // The next page is only fetched when needed
+
+    let mut ids = vec![11];
+    while let Some(deposit) = stream.next().await {
+        ids.push(deposit.unwrap().deposit_id);
+    }
+    assert_eq!(ids, [11, 12, 21, 22, 31, 32]);
+    assert_eq!(requested_pages(&server), [1, 2, 3]);
+}
+
+#[tokio::test]
+async fn stream_ends_after_an_error() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+    serve_pages(&server, Some(2));
+
+    let results: Vec<_> = client.show_deposits_stream("btc".into(), None).collect().await;
+    assert_eq!(results.len(), 3);
+    assert!(results[..2].iter().all(Result::is_ok));
+    assert!(results[2].is_err());
+    assert_eq!(requested_pages(&server), [1, 2]);
+
+    // Collecting all pages fails with the error of the last page
+    serve_pages(&server, Some(3));
+    assert!(client.show_deposits_all("btc".into(), None).await.is_err());
+    assert_eq!(requested_pages(&server)[2..], [1, 2, 3]); // Recorded requests are kept
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking_iterator_fetches_every_page_once_and_ends_after_an_error() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::blocking::TradingApiSdkV4;
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    serve_pages(&server, None);
+
+    // The blocking client must not be created or used on an async worker thread
+    let base_url = server.base_url();
+    let client = tokio::task::spawn_blocking(move || {
+        let client = TradingApiSdkV4::builder().api_key("key").api_secret("secret").base_url(base_url).build_blocking().unwrap();
+        let ids: Vec<i64> = client.show_deposits_iter("btc".into(), None).map(|deposit| deposit.unwrap().deposit_id).collect();
+        assert_eq!(ids, [11, 12, 21, 22, 31, 32]);
+        client
+    })
+    .await
+    .unwrap();
+    assert_eq!(requested_pages(&server), [1, 2, 3]);
+
+    serve_pages(&server, Some(2));
+    let results = tokio::task::spawn_blocking(move || client.show_deposits_iter("btc".into(), None).collect::<Vec<_>>()).await.unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(results[2].is_err());
+    assert_eq!(requested_pages(&server)[3..], [1, 2]); // Recorded requests are kept
+}
@@ -0,0 +1,212 @@
+// tests/requests.rs
+//! Local validation of the typed request parameters.
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::OrderType;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::errors::Error;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::{CountryCode, CreateOrderRequest, OrderbookQuery};
+use rust_decimal::Decimal;
+
+fn order() -> CreateOrderRequest {
+    CreateOrderRequest::new(OrderType::Buy, Decimal::new(5, 1), Decimal::new(2500000, 2))
+}
+
+/// `count` distinct country codes (up to 676).
+fn countries(count: usize) -> Vec<CountryCode> {
+    (b'A'..=b'Z')
+        .flat_map(|first| (b'A'..=b'Z').map(move |second| String::from_utf8(vec![first, second]).unwrap().parse().unwrap()))
+        .take(count)
+        .collect()
+}
+
+fn codes<const N: usize>(codes: [&str; N]) -> [CountryCode; N] {
+    codes.map(|code| code.parse().unwrap())
+}
+
+fn assert_seat_of_bank_error(result: Result<(), Error>, message: &str) {
+    match result {
+        Err(Error::InvalidParameter(name, reason)) => {
+            assert_eq!(name, "seat_of_bank");
+            assert!(reason.contains(message), "unexpected reason: {}", reason);
+        }
+        other => panic!("expected a seat_of_bank error, got {:?}", other),
+    }
+}
+
+#[test]
+fn country_codes_are_two_upper_case_letters() {
+    assert_eq!("DE".parse::<CountryCode>().unwrap().as_str(), "DE");
+    assert_eq!(String::from(CountryCode::try_from("CH").unwrap()), "CH");
+    for invalid in ["de", "D", "DEU", "D1", "", "ÄT"] {
+        assert!(invalid.parse::<CountryCode>().is_err(), "{} was accepted", invalid);
+    }
+}
+
+#[test]
+fn seat_of_bank_is_validated_the_same_for_orders_and_the_orderbook() {
+    let code = CountryCode::try_from("AT").unwrap();
+    assert!(order().seat_of_bank([code]).validate().is_ok());
+    assert!(OrderbookQuery::new(OrderType::Sell).seat_of_bank([code]).validate().is_ok());
+
+    assert_seat_of_bank_error(order().seat_of_bank(codes(["DE", "AT", "DE"])).validate(), "'DE' is listed twice");
+    assert_seat_of_bank_error(OrderbookQuery::new(OrderType::Buy).seat_of_bank(codes(["NL", "NL"])).validate(), "'NL' is listed twice");
+}
+
+#[test]
+fn any_number_of_distinct_countries_is_accepted() {
+    assert!(order().seat_of_bank(countries(676)).validate().is_ok());
+    assert!(OrderbookQuery::new(OrderType::Buy).seat_of_bank(countries(100)).validate().is_ok());
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn seat_of_bank_is_sent_as_indexed_parameters() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::TradingPair;
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::MockServer;
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+
+    client.create_order("btceur".into(), &order().seat_of_bank(countries(40))).await.unwrap();
+    let request = server.requests_for("createOrder").pop().unwrap();
+    assert_eq!(request.param("seat_of_bank[0]"), Some("AA"));
+    assert_eq!(request.param("seat_of_bank[39]"), Some("BN"));
+    assert_eq!(request.form.iter().filter(|(key, _)| key.starts_with("seat_of_bank")).count(), 40);
+    // The body is sent in the order it was signed in: by name, array entries by index
+    let keys: Vec<&str> = request.form.iter().map(|(key, _)| key.as_str()).collect();
+    let seat_of_bank: Vec<String> = (0..40).map(|index| format!("seat_of_bank[{}]", index)).collect();
+    let mut expected = vec!["max_amount_currency_to_trade", "price"];
+    expected.extend(seat_of_bank.iter().map(String::as_str));
+    expected.push("type");
+    assert_eq!(keys, expected);
+
+    let query = OrderbookQuery::new(OrderType::Buy).seat_of_bank(codes(["DE", "FR"]));
+    client.show_orderbook(TradingPair::BTCEUR, &query).await.unwrap();
+    let request = server.requests_for("showOrderbook").pop().unwrap();
+    assert_eq!(request.param("seat_of_bank[1]"), Some("FR"));
+
+    // Invalid requests never reach the server.
+    assert!(client.create_order("btceur".into(), &order().seat_of_bank(codes(["DE", "DE"]))).await.is_err());
+    assert_eq!(server.requests_for("createOrder").len(), 1);
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn non_positive_and_too_precise_amounts_are_not_sent() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::MockServer;
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+    let fee = Decimal::new(2, 4);
+
+    assert!(client.create_withdrawal("btc".into(), Decimal::ZERO, "bc1q".into(), fee).await.is_err());
+    assert!(client.create_withdrawal("btc".into(), Decimal::new(1, 1), "bc1q".into(), -fee).await.is_err());
+    assert!(client.create_withdrawal("btc".into(), Decimal::new(1, 9), "bc1q".into(), fee).await.is_err());
+    assert!(client.mark_trade_as_paid("btceur".into(), "2EDYNS".into(), Decimal::new(-1000, 2)).await.is_err());
+    assert!(client.mark_trade_as_paid("btceur".into(), "2EDYNS".into(), Decimal::new(10001, 3)).await.is_err());
+    assert!(client.mark_coins_as_transferred("ethbtc".into(), "2EDYNS".into(), Decimal::ZERO).await.is_err());
+    assert!(client.mark_coins_as_received("ethbtc".into(), "2EDYNS".into(), Decimal::new(-5, 1), "positive".into()).await.is_err());
+    assert!(server.requests().is_empty());
+
+    client.create_withdrawal("btc".into(), Decimal::new(150000000, 8), "bc1q".into(), fee).await.unwrap();
+    assert_eq!(server.requests_for("createWithdrawal")[0].param("amount"), Some("1.5"));
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn trades_outside_the_order_limits_are_not_sent() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::{METHOD_EXECUTE_TRADE, METHOD_SHOW_ORDER_DETAILS};
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::ExecuteTradeRequest;
+    use serde_json::json;
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+    server.set_response(
+        METHOD_SHOW_ORDER_DETAILS,
+        MockResponse::ok(json!({ "order": {
+            "order_id": "A1B2D3",
+            "is_external_wallet_order": false,
+            "trading_pair": "btceur",
+            "type": "sell",
+            "max_amount_currency_to_trade": "0.8",
+            "min_amount_currency_to_trade": "0.2",
+            "price": "25000",
+            "max_volume_currency_to_pay": "20000",
+            "min_volume_currency_to_pay": "5000",
+            "order_requirements_fullfilled": true,
+            "sepa_option": 0,
+            "trading_partner_information": {
+                "username": "bla", "is_kyc_full": true, "trust_level": "gold", "depositor": null, "iban": null,
+                "bank_name": "Sparkasse", "bic": "HASPDEHHXXX", "seat_of_bank": "DE", "amount_trades": 52, "rating": 99
+            },
+            "order_requirements": { "min_trust_level": "bronze", "only_kyc_full": false, "seat_of_bank": null, "payment_option": 1 }
+        }})),
+    );
+
+    for amount in [Decimal::new(19, 2), Decimal::new(81, 2)] {
+        let request = ExecuteTradeRequest::new(OrderType::Buy, amount);
+        match client.execute_trade_checked("btceur".into(), "A1B2D3".into(), &request).await {
+            Err(Error::InvalidParameter(name, _)) => assert_eq!(name, "amount_currency_to_trade"),
+            other => panic!("expected {} to be rejected, got {:?}", amount, other),
+        }
+    }
+    assert!(server.requests_for(METHOD_EXECUTE_TRADE).is_empty());
+    assert_eq!(server.requests_for(METHOD_SHOW_ORDER_DETAILS).len(), 2);
+
+    // The limits are inclusive
+    for amount in [Decimal::new(2, 1), Decimal::new(5, 1), Decimal::new(8, 1)] {
+        client.execute_trade_checked("btceur".into(), "A1B2D3".into(), &ExecuteTradeRequest::new(OrderType::Buy, amount)).await.unwrap();
+    }
+    let trades = server.requests_for(METHOD_EXECUTE_TRADE);
+    assert_eq!(trades.len(), 3);
+    assert_eq!(trades[1].param("amount_currency_to_trade"), Some("0.5"));
+    assert_eq!(trades[1].param("order_id"), Some("A1B2D3"));
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn my_order_details_are_requested_by_pair_and_id() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderState, TradingPair};
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::method_settings::constants::METHOD_SHOW_MY_ORDER_DETAILS;
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::MockServer;
+    use chrono::{TimeZone, Utc};
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+    let response = client.show_my_order_details("btceur".into(), "X9Y8Z7".into()).await.unwrap();
+
+    let requests = server.requests_for(METHOD_SHOW_MY_ORDER_DETAILS);
+    assert_eq!(requests.len(), 1);
+    assert_eq!((requests[0].http_method.as_str(), requests[0].path.as_str()), ("GET", "/v4/btceur/orders/X9Y8Z7"));
+    assert_eq!(requests[0].path_params["trading_pair"], "btceur");
+    assert_eq!(requests[0].path_params["order_id"], "X9Y8Z7");
+    assert!(requests[0].query.is_empty());
+
+    let order = response.order_details;
+    assert_eq!((order.order_id.as_str(), order.trading_pair().unwrap()), ("X9Y8Z7", TradingPair::BTCEUR));
+    assert_eq!(order.state, OrderState::Pending);
+    assert_eq!(order.created_at, Utc.with_ymd_and_hms(2015, 1, 10, 13, 0, 0).unwrap());
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn my_orders_filter_sends_state_and_dates_with_second_precision() {
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::OrderState;
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::MockServer;
+    use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::MyOrdersFilter;
+    use chrono::{Duration, TimeZone, Utc};
+
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = server.client().unwrap();
+    let date_start = Utc.with_ymd_and_hms(2015, 1, 1, 8, 30, 0).unwrap() + Duration::milliseconds(250);
+    let date_end = Utc.with_ymd_and_hms(2015, 2, 1, 18, 0, 59).unwrap();
+    let filter = MyOrdersFilter::new().state(OrderState::Cancelled).date_start(date_start).date_end(date_end);
+    client.show_my_orders(None, &filter).await.unwrap();
+
+    let request = &server.requests_for("showMyOrders")[0];
+    assert_eq!(request.path, "/v4/orders");
+    assert_eq!(request.param("state"), Some("-1"));
+    assert_eq!(request.param("date_start"), Some("2015-01-01T08:30:00+00:00"));
+    assert_eq!(request.param("date_end"), Some("2015-02-01T18:00:59+00:00"));
+    assert_eq!(request.param("type"), None);
+}
@@ -0,0 +1,96 @@
+// tests/retry.rs
+//! Retries of transient failures against scripted mock responses.
+#![cfg(feature = "mock")]
+
+use std::time::Duration;
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::constants::*;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::enums::{OrderType, TradingPair};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockRequest, MockResponse, MockServer};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::requests::CreateOrderRequest;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::retry::RetryPolicy;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::{Error, TradingApiSdkV4, TradingApiSdkV4Builder};
+use rust_decimal::Decimal;
+
+fn builder(server: &MockServer) -> TradingApiSdkV4Builder {
+    TradingApiSdkV4::builder()
+        .api_key("key")
+        .api_secret("secret")
+        .base_url(server.base_url())
+        .retry_policy(RetryPolicy::new().initial_backoff(Duration::from_millis(10)))
+}
+
+fn order() -> CreateOrderRequest {
+    CreateOrderRequest::new(OrderType::Buy, Decimal::new(5, 1), Decimal::new(2500000, 2))
+}
+
+#[tokio::test]
+async fn get_requests_are_retried_on_server_errors_and_rate_limiting() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = builder(&server).build().unwrap();
+
+    server.enqueue(METHOD_SHOW_RATES, MockResponse::error(503, ERROR_CODE_MAINTENANCE, "Maintenance"));
+    server.enqueue(METHOD_SHOW_RATES, MockResponse::error(429, 0, "Too many requests"));
+    client.show_rates(TradingPair::BTCEUR).await.unwrap();
+
+    let requests = server.requests_for(METHOD_SHOW_RATES);
+    assert_eq!(requests.len(), 3);
+    assert!(requests.windows(2).all(|pair| pair[0].nonce < pair[1].nonce), "every attempt needs a fresh nonce");
+}
+
+#[tokio::test]
+async fn retries_stop_after_max_attempts() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = builder(&server).build().unwrap();
+
+    for _ in 0..5 {
+        server.enqueue(METHOD_SHOW_PERMISSIONS, MockResponse::error(500, ERROR_CODE_UNKNOWN_ERROR, "Unknown error"));
+    }
+    assert!(client.show_permissions().await.is_err());
+    assert_eq!(server.requests_for(METHOD_SHOW_PERMISSIONS).len(), 3);
+}
+
+#[tokio::test]
+async fn post_requests_are_not_retried_on_server_errors() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = builder(&server).build().unwrap();
+
+    server.enqueue(METHOD_CREATE_ORDER, MockResponse::error(503, ERROR_CODE_MAINTENANCE, "Maintenance"));
+    let error = client.create_order("btceur".into(), &order()).await.unwrap_err();
+    assert!(matches!(error, Error::Api { status, .. } if status == 503));
+    assert_eq!(server.requests_for(METHOD_CREATE_ORDER).len(), 1);
+}
+
+#[tokio::test]
+async fn post_requests_are_retried_when_rejected_before_processing() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = builder(&server).build().unwrap();
+
+    server.enqueue(METHOD_CREATE_ORDER, MockResponse::error(429, 0, "Too many requests"));
+    server.enqueue(METHOD_CREATE_ORDER, MockResponse::error(401, ERROR_CODE_INVALID_NONCE, "Invalid nonce"));
+    client.create_order("btceur".into(), &order()).await.unwrap();
+    assert_eq!(server.requests_for(METHOD_CREATE_ORDER).len(), 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn post_requests_are_not_retried_on_read_timeouts() {
+    let server = MockServer::start("key", "secret").await.unwrap();
+    let client = builder(&server).timeout(Duration::from_millis(200)).build().unwrap();
+    let slow = |_: &MockRequest| {
+        std::thread::sleep(Duration::from_millis(500));
+        MockResponse::ok(serde_json::json!({ "order_id": "A1234BC" }))
+    };
+    server.set_handler(METHOD_CREATE_ORDER, slow);
+
+    // The order may have been created even though no response arrived
+    match client.create_order("btceur".into(), &order()).await {
+        Err(Error::Reqwest(error)) => assert!(error.is_timeout(), "{}", error),
+        other => panic!("expected a timeout, got {:?}", other),
+    }
+    assert_eq!(server.requests_for(METHOD_CREATE_ORDER).len(), 1);
+
+    // GET requests are safe to repeat
+    server.set_handler(METHOD_SHOW_PERMISSIONS, slow);
+    assert!(client.show_permissions().await.is_err());
+    assert_eq!(server.requests_for(METHOD_SHOW_PERMISSIONS).len(), 3);
+}
@@ -0,0 +1,113 @@
+// tests/spans.rs
+//! Fields of the request spans, and that credentials never end up in them.
+#![cfg(feature = "mock")]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::constants::*;
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::mock::{MockResponse, MockServer};
+use bitcoin_de::bitcoin_de_trading_api_sdk_v4::signature::sign_request;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{Layer, Registry};
+
+/// A field recorded on a span or event: (span or event name, field name, value).
+type Recorded = (String, String, String);
+
+/// Collects every field of every span and event.
+#[derive(Clone, Default)]
+struct CaptureLayer(Arc<Mutex<Vec<Recorded>>>);
+
+struct FieldVisitor<'a> {
+    name: &'a str,
+    recorded: &'a Mutex<Vec<Recorded>>,
+}
+
+impl FieldVisitor<'_> {
+    fn push(&self, field: &Field, value: String) {
+        self.recorded.lock().unwrap().push((self.name.to_string(), field.name().to_string(), value));
+    }
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format!("{:?}", value));
+    }
+}
+
+impl CaptureLayer {
+    fn visitor<'a>(&'a self, name: &'a str) -> FieldVisitor<'a> {
+        FieldVisitor { name, recorded: &self.0 }
+    }
+
+    /// The values recorded for a field of the request span.
+    fn span_field(&self, field: &str) -> Vec<String> {
+        let recorded = self.0.lock().unwrap();
+        recorded
+            .iter()
+            .filter(|(name, recorded_field, _)| name == "bitcoin_de.request" && recorded_field == field)
+            .map(|(_, _, value)| value.clone())
+            .collect()
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for CaptureLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        attrs.record(&mut self.visitor(attrs.metadata().name()));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            values.record(&mut self.visitor(span.name()));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        event.record(&mut self.visitor(event.metadata().name()));
+    }
+}
+
+#[tokio::test]
+async fn request_spans_carry_method_status_and_credits_but_no_credentials() {
+    let capture = CaptureLayer::default();
+    let _guard = tracing::subscriber::set_default(Registry::default().with(capture.clone()));
+
+    let server = MockServer::start("my_api_key", "my_api_secret").await.unwrap();
+    let client = server.client().unwrap();
+    server.set_credits(17);
+    client.show_account_info().await.unwrap();
+    server.enqueue(METHOD_SHOW_PERMISSIONS, MockResponse::error(422, ERROR_CODE_UNKNOWN_ERROR, "Unknown error"));
+    client.show_permissions().await.unwrap_err();
+
+    assert_eq!(capture.span_field("api.method"), ["showAccountInfo", "showPermissions"]);
+    assert_eq!(capture.span_field("http.status_code"), ["200", "422"]);
+    assert_eq!(capture.span_field("credits"), ["17", "17"]);
+
+    // Neither the credentials nor the signatures of the requests appear anywhere
+    let origin = server.base_url().trim_end_matches("/v4").to_string();
+    let mut secrets = vec!["my_api_key".to_string(), "my_api_secret".to_string()];
+    for request in server.requests() {
+        let url = format!("{}{}", origin, request.path);
+        let nonce = request.nonce.to_string();
+        secrets.push(sign_request(&request.http_method, &url, "my_api_key", "my_api_secret", &nonce, &[]).unwrap());
+    }
+    assert_eq!(secrets.len(), 4);
+    let recorded = capture.0.lock().unwrap();
+    assert!(!recorded.is_empty());
+    for (name, field, value) in recorded.iter() {
+        for header in [HEADER_X_API_KEY, HEADER_X_NONCE, HEADER_X_API_SIGNATURE] {
+            assert!(!field.eq_ignore_ascii_case(header) && !value.contains(header), "{}: {}={}", name, field, value);
+        }
+        for secret in &secrets {
+            assert!(!value.contains(secret.as_str()), "{}: {}={}", name, field, value);
+        }
+    }
+}